@@ -4,22 +4,42 @@ mod assign;
 mod associate;
 mod camel_case;
 mod capitalize;
+mod cartesian_product;
 mod char_length;
 mod chunk;
+mod chunk_by;
+mod chunk_evenly;
 mod chunk_string;
+mod chunk_while;
 mod clamp;
+mod closest_matches;
 mod combination;
+mod combination_rank;
+mod combinations;
+mod combinations_with_repetition;
 mod common_ceil_log2;
 mod common_is_collection_float;
 mod common_is_floats;
 mod common_random_u64;
 mod common_random_usize;
 mod common_random_usize_with_seed;
+mod common_seeded_rng;
 mod compact;
+mod contains_all;
+mod contains_all_by;
+mod contains_any;
+mod contains_any_by;
+mod convert_case;
 mod count;
 mod count_by;
 mod count_values;
 mod count_values_by;
+mod damerau_levenshtein;
+mod deburr;
+mod derangements;
+mod difference;
+mod difference_by;
+mod display_width;
 mod drop;
 mod drop_by_index;
 mod drop_right;
@@ -30,6 +50,8 @@ mod earliest;
 mod earliest_by;
 mod ellipsis;
 mod entries;
+mod escape_html;
+mod escape_regex;
 mod fill;
 mod filter;
 mod filter_map;
@@ -49,27 +71,43 @@ mod first_or;
 mod first_or_empty;
 mod flat_map;
 mod flatten;
+mod fold_ascii;
 mod foreach;
 mod foreach_while;
 mod from_entries;
 mod from_pairs;
+mod geometric_mean;
 mod group_by;
+mod harmonic_mean;
 mod has_key;
 mod index_of;
 mod interleave;
 mod interpolate;
+mod interquartile_range;
+mod interquartile_range_by;
+mod intersect;
+mod intersect_by;
 mod invert;
 mod is_sorted;
 mod is_sorted_by_key;
+mod is_sorted_by_keys;
+mod is_subset;
+mod is_subset_by;
+mod iter;
+mod jaro_winkler;
 mod kebab_case;
 mod key_by;
 mod keys;
+mod kurtosis;
+mod kurtosis_by;
 mod last;
 mod last_index_of;
 mod last_or;
 mod last_or_empty;
 mod latest;
 mod latest_by;
+mod lcs_distance;
+mod levenshtein;
 mod map;
 mod map_entries;
 mod map_keys;
@@ -79,24 +117,43 @@ mod max;
 mod max_by;
 mod mean;
 mod mean_by;
+mod mean_checked;
 mod median;
 mod min;
 mod min_by;
+mod mode;
+mod mode_by;
+mod natural_cmp;
+mod natural_sort;
 mod nearest_power_of_two;
 mod nth;
+mod nth_combination;
+mod nth_permutation;
 mod omit_by;
 mod omit_by_keys;
 mod omit_by_values;
+mod online_stats;
+mod order_by;
+mod pad;
+mod pad_end;
+mod pad_start;
+mod parallel;
 mod partition_by;
 mod pascal_case;
 mod percentile;
 mod permutation;
+mod permutation_rank;
+mod permutations;
+mod permutations_with_repetition;
 mod pick_by;
 mod pick_by_keys;
 mod pick_by_values;
+mod power_set;
 mod product;
 mod product_by;
+mod quantiles;
 mod random_string;
+mod random_string_with_rng;
 mod range;
 mod range_from;
 mod range_with_steps;
@@ -110,26 +167,80 @@ mod replace;
 mod replace_all;
 mod reverse;
 mod sample;
+mod sample_std_dev;
+mod sample_std_dev_by;
+mod sample_variance;
+mod sample_variance_by;
+mod sample_with_rng;
 mod samples;
+mod samples_with_rng;
+mod shell_quote;
 mod shuffle;
+mod shuffle_with_rng;
+mod skewness;
+mod skewness_by;
 mod slice;
 mod slice_to_map;
+mod slugify;
 mod snake_case;
+mod sort_by_key_cached;
 mod splice;
+mod std_dev;
+mod std_dev_by;
 mod subset;
 mod substring;
 mod sum;
 mod sum_by;
 mod support;
+mod symmetric_difference;
+mod symmetric_difference_by;
+mod template;
 mod times;
 mod to_pairs;
+mod trim_chars;
+mod trim_end_chars;
+mod trim_start_chars;
+mod truncate;
+mod try_chunk;
+mod try_chunk_evenly;
+mod try_chunk_string;
+mod try_clamp;
+mod try_latest;
+mod try_latest_by;
+mod try_nearest_power_of_two;
+mod try_percentile;
+mod try_random_string;
+mod try_random_string_with_rng;
+mod try_range_with_steps;
+mod try_sample;
+mod try_sample_with_rng;
+mod try_windows;
+mod try_windows_partial;
+mod unescape_html;
+mod union;
+mod union_by;
 mod uniq;
 mod uniq_by;
+mod uniq_by_hashed;
+mod uniq_hashed;
 mod uniq_keys;
 mod uniq_values;
 mod value_or;
+mod value_range;
+mod value_range_by;
 mod values;
+mod variance;
+mod variance_by;
+mod weighted_mean;
+mod windows;
+mod windows_partial;
+mod without;
+mod without_by;
+mod word_spans;
+mod word_wrap;
 mod words;
+mod words_with;
+mod write_case;
 
 fn custom_criterion() -> Criterion {
     Criterion::default()
@@ -145,20 +256,40 @@ fn all_benches(c: &mut Criterion) {
     common_random_usize_with_seed::benchmark_common_random_usize_with_seed(c);
     common_ceil_log2::benchmark_common_ceil_log2(c);
     common_random_u64::benchmark_common_random_u64(c);
+    common_seeded_rng::benchmark_common_seeded_rng(c);
     assign::benchmark_assign(c);
     associate::benchmark_associate(c);
     camel_case::benchmark_camel_case(c);
     capitalize::benchmark_capitalize(c);
+    cartesian_product::benchmark_cartesian_product(c);
     char_length::benchmark_char_length(c);
     chunk::benchmark_chunk(c);
+    chunk_by::benchmark_chunk_by(c);
+    chunk_evenly::benchmark_chunk_evenly(c);
     chunk_string::benchmark_chunk_string(c);
+    chunk_while::benchmark_chunk_while(c);
     clamp::benchmark_clamp(c);
+    closest_matches::benchmark_closest_matches(c);
     combination::benchmark_combination(c);
+    combination_rank::benchmark_combination_rank(c);
+    combinations::benchmark_combinations(c);
+    combinations_with_repetition::benchmark_combinations_with_repetition(c);
     compact::benchmark_compact(c);
+    contains_all::benchmark_contains_all(c);
+    contains_all_by::benchmark_contains_all_by(c);
+    contains_any::benchmark_contains_any(c);
+    contains_any_by::benchmark_contains_any_by(c);
+    convert_case::benchmark_convert_case(c);
     count::benchmark_count(c);
     count_by::benchmark_count_by(c);
     count_values::benchmark_count_values(c);
     count_values_by::benchmark_count_values_by(c);
+    damerau_levenshtein::benchmark_damerau_levenshtein(c);
+    deburr::benchmark_deburr(c);
+    derangements::benchmark_derangements(c);
+    difference::benchmark_difference(c);
+    difference_by::benchmark_difference_by(c);
+    display_width::benchmark_display_width(c);
     drop::benchmark_drop(c);
     drop_by_index::benchmark_drop_by_index(c);
     drop_right::benchmark_drop_right(c);
@@ -169,6 +300,8 @@ fn all_benches(c: &mut Criterion) {
     earliest_by::benchmark_earliest_by(c);
     ellipsis::benchmark_ellipsis(c);
     entries::benchmark_entries(c);
+    escape_html::benchmark_escape_html(c);
+    escape_regex::benchmark_escape_regex(c);
     fill::benchmark_fill(c);
     filter::benchmark_filter(c);
     filter_map::benchmark_filter_map(c);
@@ -188,27 +321,43 @@ fn all_benches(c: &mut Criterion) {
     first_or_empty::benchmark_first_or_empty(c);
     flat_map::benchmark_flat_map(c);
     flatten::benchmark_flatten(c);
+    fold_ascii::benchmark_fold_ascii(c);
     foreach::benchmark_foreach(c);
     foreach_while::benchmark_foreach_while(c);
     from_entries::benchmark_from_entries(c);
     from_pairs::benchmark_from_pairs(c);
+    geometric_mean::benchmark_geometric_mean(c);
     group_by::benchmark_group_by(c);
+    harmonic_mean::benchmark_harmonic_mean(c);
     has_key::benchmark_has_key(c);
     index_of::benchmark_index_of(c);
     interleave::benchmark_interleave(c);
     interpolate::benchmark_interpolate(c);
+    interquartile_range::benchmark_interquartile_range(c);
+    interquartile_range_by::benchmark_interquartile_range_by(c);
+    intersect::benchmark_intersect(c);
+    intersect_by::benchmark_intersect_by(c);
     invert::benchmark_invert(c);
     is_sorted::benchmark_is_sorted(c);
     is_sorted_by_key::benchmark_is_sorted_by_key(c);
+    is_sorted_by_keys::benchmark_is_sorted_by_keys(c);
+    is_subset::benchmark_is_subset(c);
+    is_subset_by::benchmark_is_subset_by(c);
+    iter::benchmark_iter(c);
+    jaro_winkler::benchmark_jaro_winkler(c);
     kebab_case::benchmark_kebab_case(c);
     key_by::benchmark_key_by(c);
     keys::benchmark_keys(c);
+    kurtosis::benchmark_kurtosis(c);
+    kurtosis_by::benchmark_kurtosis_by(c);
     last::benchmark_last(c);
     last_index_of::benchmark_last_index_of(c);
     last_or::benchmark_last_or(c);
     last_or_empty::benchmark_last_or_empty(c);
     latest::benchmark_latest(c);
     latest_by::benchmark_latest_by(c);
+    lcs_distance::benchmark_lcs_distance(c);
+    levenshtein::benchmark_levenshtein(c);
     map::benchmark_map(c);
     map_entries::benchmark_map_entries(c);
     map_keys::benchmark_map_keys(c);
@@ -218,24 +367,43 @@ fn all_benches(c: &mut Criterion) {
     max_by::benchmark_max_by(c);
     mean::benchmark_mean(c);
     mean_by::benchmark_mean_by(c);
+    mean_checked::benchmark_mean_checked(c);
     median::benchmark_median(c);
     min::benchmark_min(c);
     min_by::benchmark_min_by(c);
+    mode::benchmark_mode(c);
+    mode_by::benchmark_mode_by(c);
+    natural_cmp::benchmark_natural_cmp(c);
+    natural_sort::benchmark_natural_sort(c);
     nearest_power_of_two::benchmark_nearest_power_of_two(c);
     nth::benchmark_nth(c);
+    nth_combination::benchmark_nth_combination(c);
+    nth_permutation::benchmark_nth_permutation(c);
     omit_by::benchmark_omit_by(c);
     omit_by_keys::benchmark_omit_by_keys(c);
     omit_by_values::benchmark_omit_by_values(c);
+    online_stats::benchmark_online_stats(c);
+    order_by::benchmark_order_by(c);
+    pad::benchmark_pad(c);
+    pad_end::benchmark_pad_end(c);
+    pad_start::benchmark_pad_start(c);
+    parallel::benchmark_parallel(c);
     partition_by::benchmark_partition_by(c);
     pascal_case::benchmark_pascal_case(c);
     percentile::benchmark_percentile(c);
     permutation::benchmark_permutation(c);
+    permutation_rank::benchmark_permutation_rank(c);
+    permutations::benchmark_permutations(c);
+    permutations_with_repetition::benchmark_permutations_with_repetition(c);
     pick_by::benchmark_pick_by(c);
     pick_by_keys::benchmark_pick_by_keys(c);
     pick_by_values::benchmark_pick_by_values(c);
+    power_set::benchmark_power_set(c);
     product::benchmark_product(c);
     product_by::benchmark_product_by(c);
+    quantiles::benchmark_quantiles(c);
     random_string::benchmark_random_string(c);
+    random_string_with_rng::benchmark_random_string_with_rng(c);
     range::benchmark_range(c);
     range_from::benchmark_range_from(c);
     range_with_steps::benchmark_range_with_steps(c);
@@ -249,25 +417,79 @@ fn all_benches(c: &mut Criterion) {
     replace_all::benchmark_replace_all(c);
     reverse::benchmark_reverse(c);
     sample::benchmark_sample(c);
+    sample_std_dev::benchmark_sample_std_dev(c);
+    sample_std_dev_by::benchmark_sample_std_dev_by(c);
+    sample_variance::benchmark_sample_variance(c);
+    sample_variance_by::benchmark_sample_variance_by(c);
+    sample_with_rng::benchmark_sample_with_rng(c);
     samples::benchmark_samples(c);
+    samples_with_rng::benchmark_samples_with_rng(c);
+    shell_quote::benchmark_shell_quote(c);
     shuffle::benchmark_shuffle(c);
+    shuffle_with_rng::benchmark_shuffle_with_rng(c);
+    skewness::benchmark_skewness(c);
+    skewness_by::benchmark_skewness_by(c);
     slice::benchmark_slice(c);
     slice_to_map::benchmark_slice_to_map(c);
+    slugify::benchmark_slugify(c);
     snake_case::benchmark_snake_case(c);
+    sort_by_key_cached::benchmark_sort_by_key_cached(c);
     splice::benchmark_splice(c);
+    std_dev::benchmark_std_dev(c);
+    std_dev_by::benchmark_std_dev_by(c);
     subset::benchmark_subset(c);
     substring::benchmark_substring(c);
     sum::benchmark_sum(c);
     sum_by::benchmark_sum_by(c);
+    symmetric_difference::benchmark_symmetric_difference(c);
+    symmetric_difference_by::benchmark_symmetric_difference_by(c);
+    template::benchmark_template(c);
     times::benchmark_times(c);
     to_pairs::benchmark_to_pairs(c);
+    trim_chars::benchmark_trim_chars(c);
+    trim_end_chars::benchmark_trim_end_chars(c);
+    trim_start_chars::benchmark_trim_start_chars(c);
+    truncate::benchmark_truncate(c);
+    try_chunk::benchmark_try_chunk(c);
+    try_chunk_evenly::benchmark_try_chunk_evenly(c);
+    try_chunk_string::benchmark_try_chunk_string(c);
+    try_clamp::benchmark_try_clamp(c);
+    try_latest::benchmark_try_latest(c);
+    try_latest_by::benchmark_try_latest_by(c);
+    try_nearest_power_of_two::benchmark_try_nearest_power_of_two(c);
+    try_percentile::benchmark_try_percentile(c);
+    try_random_string::benchmark_try_random_string(c);
+    try_random_string_with_rng::benchmark_try_random_string_with_rng(c);
+    try_range_with_steps::benchmark_try_range_with_steps(c);
+    try_sample::benchmark_try_sample(c);
+    try_sample_with_rng::benchmark_try_sample_with_rng(c);
+    try_windows::benchmark_try_windows(c);
+    try_windows_partial::benchmark_try_windows_partial(c);
+    unescape_html::benchmark_unescape_html(c);
+    union::benchmark_union(c);
+    union_by::benchmark_union_by(c);
     uniq::benchmark_uniq(c);
     uniq_by::benchmark_uniq_by(c);
+    uniq_by_hashed::benchmark_uniq_by_hashed(c);
+    uniq_hashed::benchmark_uniq_hashed(c);
     uniq_keys::benchmark_uniq_keys(c);
     uniq_values::benchmark_uniq_values(c);
     value_or::benchmark_value_or(c);
+    value_range::benchmark_value_range(c);
+    value_range_by::benchmark_value_range_by(c);
     values::benchmark_values(c);
+    variance::benchmark_variance(c);
+    variance_by::benchmark_variance_by(c);
+    weighted_mean::benchmark_weighted_mean(c);
+    windows::benchmark_windows(c);
+    windows_partial::benchmark_windows_partial(c);
+    without::benchmark_without(c);
+    without_by::benchmark_without_by(c);
+    word_spans::benchmark_word_spans(c);
+    word_wrap::benchmark_word_wrap(c);
     words::benchmark_words(c);
+    words_with::benchmark_words_with(c);
+    write_case::benchmark_write_case(c);
 }
 
 criterion_group! {
@@ -0,0 +1,22 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_cartesian_product(c: &mut Criterion) {
+    let set: Vec<i32> = (0..16).collect();
+    let sets = [set.as_slice(), set.as_slice(), set.as_slice()];
+    c.bench_function("cartesian_product/16x16x16/collect", |b| {
+        b.iter(|| ld::cartesian_product(black_box(&sets)).count())
+    });
+
+    c.bench_function("cartesian_product/16x16x16/next_into", |b| {
+        b.iter(|| {
+            let mut product = ld::cartesian_product(black_box(&sets));
+            let mut buffer = Vec::with_capacity(sets.len());
+            let mut sum = 0;
+            while product.next_into(&mut buffer) {
+                sum += buffer[0];
+            }
+            sum
+        })
+    });
+}
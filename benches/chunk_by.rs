@@ -0,0 +1,20 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_chunk_by(c: &mut Criterion) {
+    let people = support::people(4_096);
+    c.bench_function("chunk_by/people/age", |b| {
+        b.iter(|| {
+            ld::chunk_by(
+                black_box(&people),
+                black_box(|person: &support::Person| person.age / 10),
+            )
+        })
+    });
+
+    let ints = support::int_vec(4_096);
+    c.bench_function("chunk_by/int_vec/sign", |b| {
+        b.iter(|| ld::chunk_by(black_box(&ints), black_box(|x: &i32| x.signum())))
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_chunk_evenly(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("chunk_evenly/int_vec/7", |b| {
+        b.iter(|| ld::chunk_evenly(black_box(&ints), black_box(7)))
+    });
+
+    let people = support::people(4_096);
+    c.bench_function("chunk_evenly/people/64", |b| {
+        b.iter(|| ld::chunk_evenly(black_box(&people), black_box(64)))
+    });
+}
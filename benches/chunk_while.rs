@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_chunk_while(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("chunk_while/int_vec/ascending", |b| {
+        b.iter(|| ld::chunk_while(black_box(&ints), black_box(|a: &i32, b: &i32| a <= b)))
+    });
+
+    let shuffled = support::int_vec_shuffled(4_096);
+    c.bench_function("chunk_while/int_vec_shuffled/ascending", |b| {
+        b.iter(|| ld::chunk_while(black_box(&shuffled), black_box(|a: &i32, b: &i32| a <= b)))
+    });
+}
@@ -0,0 +1,28 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_closest_matches(c: &mut Criterion) {
+    let identifiers = support::identifiers(1000);
+    let candidates: Vec<&str> = identifiers.iter().map(String::as_str).collect();
+    c.bench_function("closest_matches/1000", |b| {
+        b.iter(|| ld::closest_matches(black_box("parseHtmlRespnse"), black_box(&candidates), 5))
+    });
+
+    let options = ld::MatchOptions {
+        metric: ld::StringMetric::JaroWinkler,
+        case_insensitive: true,
+        min_similarity: 0.8,
+        ..ld::MatchOptions::default()
+    };
+    c.bench_function("closest_matches/1000_jaro_winkler", |b| {
+        b.iter(|| {
+            ld::closest_matches_with(
+                black_box("parseHtmlRespnse"),
+                black_box(&candidates),
+                5,
+                black_box(&options),
+            )
+        })
+    });
+}
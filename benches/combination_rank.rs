@@ -0,0 +1,9 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_combination_rank(c: &mut Criterion) {
+    let indices = ld::nth_combination(60, 5, 4_000_000).unwrap();
+    c.bench_function("combination_rank/60-choose-5", |b| {
+        b.iter(|| ld::combination_rank(black_box(60), black_box(&indices)))
+    });
+}
@@ -0,0 +1,32 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_combinations(c: &mut Criterion) {
+    c.bench_function("combinations/18-choose-3/collect", |b| {
+        b.iter(|| ld::combinations(black_box(18), black_box(3)).count())
+    });
+
+    c.bench_function("combinations/60-choose-5/next_indices", |b| {
+        b.iter(|| {
+            let mut combinations = ld::combinations(black_box(60), black_box(5));
+            let mut sum = 0;
+            while let Some(indices) = combinations.next_indices() {
+                sum += indices[0];
+            }
+            sum
+        })
+    });
+
+    let items: Vec<i32> = (0..18).collect();
+    c.bench_function("combinations/18-choose-3/next_into", |b| {
+        b.iter(|| {
+            let mut combinations = ld::combinations(items.len(), black_box(3));
+            let mut buffer = Vec::with_capacity(3);
+            let mut sum = 0;
+            while combinations.next_into(black_box(&items), &mut buffer) {
+                sum += buffer[0];
+            }
+            sum
+        })
+    });
+}
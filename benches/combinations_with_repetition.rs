@@ -0,0 +1,23 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_combinations_with_repetition(c: &mut Criterion) {
+    c.bench_function(
+        "combinations_with_repetition/12-multichoose-4/collect",
+        |b| b.iter(|| ld::combinations_with_repetition(black_box(12), black_box(4)).count()),
+    );
+
+    c.bench_function(
+        "combinations_with_repetition/12-multichoose-4/next_indices",
+        |b| {
+            b.iter(|| {
+                let mut iter = ld::combinations_with_repetition(black_box(12), black_box(4));
+                let mut sum = 0;
+                while let Some(indices) = iter.next_indices() {
+                    sum += indices[0];
+                }
+                sum
+            })
+        },
+    );
+}
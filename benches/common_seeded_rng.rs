@@ -0,0 +1,15 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+use lowdash::common::Rng;
+
+pub fn benchmark_common_seeded_rng(c: &mut Criterion) {
+    let mut rng = ld::common::SeededRng::new(42);
+    c.bench_function("common_seeded_rng/next_u64", |b| {
+        b.iter(|| black_box(rng.next_u64()))
+    });
+
+    let mut rng = ld::common::SeededRng::new(42);
+    c.bench_function("common_seeded_rng/next_usize", |b| {
+        b.iter(|| black_box(rng.next_usize(black_box(1_000))))
+    });
+}
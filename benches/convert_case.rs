@@ -0,0 +1,25 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_convert_case(c: &mut Criterion) {
+    let input = support::mixed_identifier();
+    for (label, case) in [
+        ("constant", ld::Case::Constant),
+        ("title", ld::Case::Title),
+        ("sentence", ld::Case::Sentence),
+        ("dot", ld::Case::Dot),
+        ("path", ld::Case::Path),
+        ("train", ld::Case::Train),
+        ("start", ld::Case::Start),
+    ] {
+        c.bench_function(&format!("convert_case/{}_mixed_identifier", label), |b| {
+            b.iter(|| ld::convert_case(black_box(input), black_box(case)))
+        });
+    }
+
+    let sentence = support::long_sentence();
+    c.bench_function("convert_case/title_long_sentence", |b| {
+        b.iter(|| ld::convert_case(black_box(sentence), black_box(ld::Case::Title)))
+    });
+}
@@ -0,0 +1,14 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_damerau_levenshtein(c: &mut Criterion) {
+    c.bench_function("damerau_levenshtein/short", |b| {
+        b.iter(|| ld::damerau_levenshtein(black_box("kitten"), black_box("sitting")))
+    });
+
+    let a = "The quick brown fox jumps over the lazy dog".repeat(4);
+    let other = "The quikc brown fax jumped over a lazy dgo".repeat(4);
+    c.bench_function("damerau_levenshtein/long", |b| {
+        b.iter(|| ld::damerau_levenshtein(black_box(&a), black_box(&other)))
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_deburr(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("deburr/ascii", |b| {
+        b.iter(|| ld::deburr(black_box(sentence)))
+    });
+
+    let accented = "Crème Brûlée à la Façade, Ångström Straße Łódź — Tiếng Việt ".repeat(8);
+    c.bench_function("deburr/accented", |b| {
+        b.iter(|| ld::deburr(black_box(&accented)))
+    });
+}
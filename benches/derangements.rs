@@ -0,0 +1,19 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_derangements(c: &mut Criterion) {
+    c.bench_function("derangements/8/collect", |b| {
+        b.iter(|| ld::derangements(black_box(8)).count())
+    });
+
+    c.bench_function("derangements/8/next_indices", |b| {
+        b.iter(|| {
+            let mut iter = ld::derangements(black_box(8));
+            let mut sum = 0;
+            while let Some(indices) = iter.next_indices() {
+                sum += indices[0];
+            }
+            sum
+        })
+    });
+}
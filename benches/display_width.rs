@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_display_width(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("display_width/long_sentence", |b| {
+        b.iter(|| ld::display_width(black_box(sentence)))
+    });
+
+    let wide = "こんにちは世界、東京タワー👨\u{200D}👩\u{200D}👧🇯🇵".repeat(8);
+    c.bench_function("display_width/wide_and_emoji", |b| {
+        b.iter(|| ld::display_width(black_box(&wide)))
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_escape_html(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("escape_html/nothing_to_escape", |b| {
+        b.iter(|| ld::escape_html(black_box(sentence)))
+    });
+
+    let input = "<a href=\"/search?q=1&page=2\">Tom's results</a> ".repeat(16);
+    c.bench_function("escape_html/escaped", |b| {
+        b.iter(|| ld::escape_html(black_box(&input)))
+    });
+}
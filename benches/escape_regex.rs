@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_escape_regex(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("escape_regex/nothing_to_escape", |b| {
+        b.iter(|| ld::escape_regex(black_box(sentence)))
+    });
+
+    let input = "price: $5.00 (approx.) [USD] + tax? ".repeat(16);
+    c.bench_function("escape_regex/escaped", |b| {
+        b.iter(|| ld::escape_regex(black_box(&input)))
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_fold_ascii(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("fold_ascii/ascii", |b| {
+        b.iter(|| ld::fold_ascii(black_box(sentence)))
+    });
+
+    let accented = "Crème Brûlée à la Façade, Ångström Straße Łódź — Tiếng Việt ".repeat(8);
+    c.bench_function("fold_ascii/accented", |b| {
+        b.iter(|| ld::fold_ascii(black_box(&accented)))
+    });
+}
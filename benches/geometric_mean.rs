@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_geometric_mean(c: &mut Criterion) {
+    let floats = support::float_vec(4_096);
+    c.bench_function("geometric_mean/float_vec", |b| {
+        b.iter(|| ld::geometric_mean(black_box(&floats)))
+    });
+
+    let ints = support::int_vec(4_096);
+    c.bench_function("geometric_mean/int_vec", |b| {
+        b.iter(|| ld::geometric_mean(black_box(&ints)))
+    });
+}
@@ -0,0 +1,28 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_intersect_by(c: &mut Criterion) {
+    let people = support::people(4_096);
+    let shuffled = support::people_shuffled(4_096);
+    c.bench_function("intersect_by/people/id", |b| {
+        b.iter(|| {
+            ld::intersect_by(
+                black_box(&people),
+                black_box(&shuffled),
+                black_box(|person: &support::Person| person.id),
+            )
+        })
+    });
+
+    let same_age = support::people_same_age(4_096);
+    c.bench_function("intersect_by/people/age", |b| {
+        b.iter(|| {
+            ld::intersect_by(
+                black_box(&people),
+                black_box(&same_age),
+                black_box(|person: &support::Person| person.age),
+            )
+        })
+    });
+}
@@ -0,0 +1,13 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_is_sorted_by_keys(c: &mut Criterion) {
+    let by_age = |a: &support::Person, b: &support::Person| a.age.cmp(&b.age);
+    let by_name = |a: &support::Person, b: &support::Person| a.name.cmp(&b.name);
+    let directions = [ld::SortDirection::Desc, ld::SortDirection::Asc];
+    let people = ld::order_by(&support::people(4_096), &[&by_age, &by_name], &directions);
+    c.bench_function("is_sorted_by_keys/people/sorted", |b| {
+        b.iter(|| ld::is_sorted_by_keys(black_box(&people), &[&by_age, &by_name], &directions))
+    });
+}
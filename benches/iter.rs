@@ -0,0 +1,46 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+use lowdash::LowdashIterExt;
+
+pub fn benchmark_iter(c: &mut Criterion) {
+    let ints = support::defaulty_int_vec(4_096);
+    c.bench_function("iter/eager_pipeline", |b| {
+        b.iter(|| {
+            let compacted = ld::compact(black_box(&ints));
+            let unique = ld::uniq_by(&compacted, |x| x % 16);
+            ld::chunk(&unique, 4)
+        })
+    });
+
+    c.bench_function("iter/lazy_pipeline", |b| {
+        b.iter(|| {
+            black_box(&ints)
+                .iter()
+                .copied()
+                .compact()
+                .uniq_by(|x| x % 16)
+                .chunk(4)
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let people = support::people(4_096);
+    c.bench_function("iter/find_duplicates_by", |b| {
+        b.iter(|| {
+            black_box(&people)
+                .iter()
+                .find_duplicates_by(|p| p.age)
+                .count()
+        })
+    });
+
+    c.bench_function("iter/partition_by", |b| {
+        b.iter(|| {
+            black_box(&people)
+                .iter()
+                .partition_by(|p| p.age)
+                .collect::<Vec<_>>()
+        })
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_kurtosis_by(c: &mut Criterion) {
+    let people = support::people(4_096);
+    c.bench_function("kurtosis_by/people", |b| {
+        b.iter(|| {
+            ld::kurtosis_by(
+                black_box(&people),
+                black_box(|person: &support::Person| person.age as f64),
+            )
+        })
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_mean_checked(c: &mut Criterion) {
+    let floats = support::float_vec(4_096);
+    c.bench_function("mean_checked/float_vec", |b| {
+        b.iter(|| ld::mean_checked(black_box(&floats)))
+    });
+
+    let ints = support::int_vec(4_096);
+    c.bench_function("mean_checked/int_vec", |b| {
+        b.iter(|| ld::mean_checked(black_box(&ints)))
+    });
+}
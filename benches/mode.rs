@@ -0,0 +1,13 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_mode(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("mode/int_vec", |b| b.iter(|| ld::mode(black_box(&ints))));
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("mode/float_vec", |b| {
+        b.iter(|| ld::mode(black_box(&floats)))
+    });
+}
@@ -0,0 +1,13 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_natural_cmp(c: &mut Criterion) {
+    c.bench_function("natural_cmp/file_names", |b| {
+        b.iter(|| {
+            ld::natural_cmp(
+                black_box("report-2024-file-0099.txt"),
+                black_box("report-2024-file-100.txt"),
+            )
+        })
+    });
+}
@@ -0,0 +1,13 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_natural_sort(c: &mut Criterion) {
+    let names: Vec<String> = support::people(4_096)
+        .into_iter()
+        .map(|p| format!("{}-{}", p.name, p.id))
+        .collect();
+    c.bench_function("natural_sort/names", |b| {
+        b.iter(|| ld::natural_sort(black_box(&names)))
+    });
+}
@@ -0,0 +1,12 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_nth_combination(c: &mut Criterion) {
+    c.bench_function("nth_combination/60-choose-5", |b| {
+        b.iter(|| ld::nth_combination(black_box(60), black_box(5), black_box(4_000_000)))
+    });
+
+    c.bench_function("nth_combination/200-choose-100", |b| {
+        b.iter(|| ld::nth_combination(black_box(200), black_box(100), black_box(usize::MAX)))
+    });
+}
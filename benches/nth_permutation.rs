@@ -0,0 +1,12 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_nth_permutation(c: &mut Criterion) {
+    c.bench_function("nth_permutation/12-of-12", |b| {
+        b.iter(|| ld::nth_permutation(black_box(12), black_box(12), black_box(123_456_789)))
+    });
+
+    c.bench_function("nth_permutation/64-of-64", |b| {
+        b.iter(|| ld::nth_permutation(black_box(64), black_box(64), black_box(usize::MAX)))
+    });
+}
@@ -0,0 +1,32 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_online_stats(c: &mut Criterion) {
+    let floats = support::float_vec(4_096);
+    c.bench_function("online_stats/push/float_vec", |b| {
+        b.iter(|| {
+            black_box(&floats)
+                .iter()
+                .copied()
+                .collect::<ld::OnlineStats>()
+        })
+    });
+
+    let stats: ld::OnlineStats = floats.iter().copied().collect();
+    c.bench_function("online_stats/quantile/float_vec", |b| {
+        b.iter(|| black_box(&stats).quantile(black_box(0.95)))
+    });
+
+    let halves: Vec<ld::OnlineStats> = floats
+        .chunks(2_048)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect();
+    c.bench_function("online_stats/merge/float_vec", |b| {
+        b.iter(|| {
+            let mut merged = halves[0].clone();
+            merged.merge(black_box(&halves[1]));
+            merged
+        })
+    });
+}
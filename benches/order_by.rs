@@ -0,0 +1,18 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_order_by(c: &mut Criterion) {
+    let people = support::people(4_096);
+    let by_age = |a: &support::Person, b: &support::Person| a.age.cmp(&b.age);
+    let by_name = |a: &support::Person, b: &support::Person| a.name.cmp(&b.name);
+    c.bench_function("order_by/people/age_desc_name_asc", |b| {
+        b.iter(|| {
+            ld::order_by(
+                black_box(&people),
+                &[&by_age, &by_name],
+                &[ld::SortDirection::Desc, ld::SortDirection::Asc],
+            )
+        })
+    });
+}
@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_pad(c: &mut Criterion) {
+    let input = support::short_string();
+    c.bench_function("pad/short_string", |b| {
+        b.iter(|| ld::pad(black_box(input), black_box(64), black_box("_-")))
+    });
+
+    c.bench_function("pad/wide", |b| {
+        b.iter(|| ld::pad(black_box("世界"), black_box(64), black_box("·")))
+    });
+}
@@ -0,0 +1,49 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+use lowdash::parallel;
+
+pub fn benchmark_parallel(c: &mut Criterion) {
+    let ints = support::int_vec(262_144);
+    c.bench_function("parallel/map/sequential", |b| {
+        b.iter(|| ld::map(black_box(&ints), |x, i| x.wrapping_mul(31) ^ i as i32))
+    });
+
+    c.bench_function("parallel/map/threads", |b| {
+        b.iter(|| parallel::map(black_box(&ints), |x, i| x.wrapping_mul(31) ^ i as i32, 0))
+    });
+
+    c.bench_function("parallel/filter_map/threads", |b| {
+        b.iter(|| parallel::filter_map(black_box(&ints), |x, _| (x * 2, x % 3 == 0), 0))
+    });
+
+    c.bench_function("parallel/reduce/threads", |b| {
+        b.iter(|| {
+            parallel::reduce(
+                black_box(&ints),
+                |acc, x, _| acc + *x as i64,
+                |a, b| a + b,
+                0_i64,
+                0,
+            )
+        })
+    });
+
+    let people = support::people(262_144);
+    c.bench_function("parallel/group_by/sequential", |b| {
+        b.iter(|| ld::group_by(black_box(&people), |p| p.age))
+    });
+
+    c.bench_function("parallel/group_by/threads", |b| {
+        b.iter(|| parallel::group_by(black_box(&people), |p| p.age, 0))
+    });
+
+    let duplicates = support::duplicate_int_vec(262_144);
+    c.bench_function("parallel/count_values/sequential", |b| {
+        b.iter(|| ld::count_values(black_box(&duplicates)))
+    });
+
+    c.bench_function("parallel/count_values/threads", |b| {
+        b.iter(|| parallel::count_values(black_box(&duplicates), 0))
+    });
+}
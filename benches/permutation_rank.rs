@@ -0,0 +1,9 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_permutation_rank(c: &mut Criterion) {
+    let indices = ld::nth_permutation(12, 12, 123_456_789).unwrap();
+    c.bench_function("permutation_rank/12-of-12", |b| {
+        b.iter(|| ld::permutation_rank(black_box(12), black_box(&indices)))
+    });
+}
@@ -0,0 +1,32 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_permutations(c: &mut Criterion) {
+    c.bench_function("permutations/8-of-8/collect", |b| {
+        b.iter(|| ld::permutations(black_box(8), black_box(8)).count())
+    });
+
+    c.bench_function("permutations/10-of-10/next_indices", |b| {
+        b.iter(|| {
+            let mut permutations = ld::permutations(black_box(10), black_box(10));
+            let mut sum = 0;
+            while let Some(indices) = permutations.next_indices() {
+                sum += indices[0];
+            }
+            sum
+        })
+    });
+
+    let items: Vec<i32> = (0..18).collect();
+    c.bench_function("permutations/18-of-3/next_into", |b| {
+        b.iter(|| {
+            let mut permutations = ld::permutations(items.len(), black_box(3));
+            let mut buffer = Vec::with_capacity(3);
+            let mut sum = 0;
+            while permutations.next_into(black_box(&items), &mut buffer) {
+                sum += buffer[0];
+            }
+            sum
+        })
+    });
+}
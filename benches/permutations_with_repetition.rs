@@ -0,0 +1,19 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_permutations_with_repetition(c: &mut Criterion) {
+    c.bench_function("permutations_with_repetition/8-pow-5/collect", |b| {
+        b.iter(|| ld::permutations_with_repetition(black_box(8), black_box(5)).count())
+    });
+
+    c.bench_function("permutations_with_repetition/8-pow-5/next_indices", |b| {
+        b.iter(|| {
+            let mut iter = ld::permutations_with_repetition(black_box(8), black_box(5));
+            let mut sum = 0;
+            while let Some(indices) = iter.next_indices() {
+                sum += indices[0];
+            }
+            sum
+        })
+    });
+}
@@ -0,0 +1,19 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_power_set(c: &mut Criterion) {
+    c.bench_function("power_set/12/collect", |b| {
+        b.iter(|| ld::power_set(black_box(12)).count())
+    });
+
+    c.bench_function("power_set/16/next_indices", |b| {
+        b.iter(|| {
+            let mut subsets = ld::power_set(black_box(16));
+            let mut total = 0;
+            while let Some(indices) = subsets.next_indices() {
+                total += indices.len();
+            }
+            total
+        })
+    });
+}
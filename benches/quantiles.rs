@@ -0,0 +1,36 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_quantiles(c: &mut Criterion) {
+    let floats = support::float_vec(4_096);
+    let percentiles = [1.0, 5.0, 25.0, 50.0, 75.0, 95.0, 99.0];
+    c.bench_function("quantiles/float_vec/linear", |b| {
+        b.iter(|| {
+            ld::quantiles(
+                black_box(&floats),
+                black_box(&percentiles),
+                ld::QuantileMethod::Linear,
+            )
+        })
+    });
+
+    c.bench_function("quantiles/float_vec/median_unbiased", |b| {
+        b.iter(|| {
+            ld::quantiles(
+                black_box(&floats),
+                black_box(&percentiles),
+                ld::QuantileMethod::MedianUnbiased,
+            )
+        })
+    });
+
+    c.bench_function("quantiles/float_vec/percentile_per_call", |b| {
+        b.iter(|| {
+            percentiles
+                .iter()
+                .map(|&p| ld::percentile(black_box(&floats), p))
+                .collect::<Vec<_>>()
+        })
+    });
+}
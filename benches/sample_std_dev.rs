@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_sample_std_dev(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("sample_std_dev/int_vec", |b| {
+        b.iter(|| ld::sample_std_dev(black_box(&ints)))
+    });
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("sample_std_dev/float_vec", |b| {
+        b.iter(|| ld::sample_std_dev(black_box(&floats)))
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_sample_std_dev_by(c: &mut Criterion) {
+    let people = support::people(4_096);
+    c.bench_function("sample_std_dev_by/people", |b| {
+        b.iter(|| {
+            ld::sample_std_dev_by(
+                black_box(&people),
+                black_box(|person: &support::Person| person.age as f64),
+            )
+        })
+    });
+}
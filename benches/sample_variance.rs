@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_sample_variance(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("sample_variance/int_vec", |b| {
+        b.iter(|| ld::sample_variance(black_box(&ints)))
+    });
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("sample_variance/float_vec", |b| {
+        b.iter(|| ld::sample_variance(black_box(&floats)))
+    });
+}
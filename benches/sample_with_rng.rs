@@ -0,0 +1,16 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_sample_with_rng(c: &mut Criterion) {
+    let mut rng = ld::common::SeededRng::new(42);
+    let ints = support::int_vec(4_096);
+    c.bench_function("sample_with_rng/int_vec", |b| {
+        b.iter(|| ld::sample_with_rng(black_box(&ints), &mut rng))
+    });
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("sample_with_rng/float_vec", |b| {
+        b.iter(|| ld::sample_with_rng(black_box(&floats), &mut rng))
+    });
+}
@@ -0,0 +1,20 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_samples_with_rng(c: &mut Criterion) {
+    let mut rng = ld::common::SeededRng::new(42);
+    let ints = support::int_vec(4_096);
+    c.bench_function("samples_with_rng/int_vec/64", |b| {
+        b.iter(|| ld::samples_with_rng(black_box(&ints), black_box(64), &mut rng))
+    });
+
+    c.bench_function("samples_with_rng/int_vec/1", |b| {
+        b.iter(|| ld::samples_with_rng(black_box(&ints), black_box(1), &mut rng))
+    });
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("samples_with_rng/float_vec/64", |b| {
+        b.iter(|| ld::samples_with_rng(black_box(&floats), black_box(64), &mut rng))
+    });
+}
@@ -0,0 +1,14 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_shell_quote(c: &mut Criterion) {
+    let path = "/usr/local/bin/cargo-build";
+    c.bench_function("shell_quote/safe_word", |b| {
+        b.iter(|| ld::shell_quote(black_box(path)))
+    });
+
+    let input = "it's a $(dangerous) file name; ".repeat(16);
+    c.bench_function("shell_quote/quoted", |b| {
+        b.iter(|| ld::shell_quote(black_box(&input)))
+    });
+}
@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_skewness(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("skewness/int_vec", |b| {
+        b.iter(|| ld::skewness(black_box(&ints)))
+    });
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("skewness/float_vec", |b| {
+        b.iter(|| ld::skewness(black_box(&floats)))
+    });
+}
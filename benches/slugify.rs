@@ -0,0 +1,17 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_slugify(c: &mut Criterion) {
+    let title = "Crème Brûlée: 10 Things I Learned Building a Rust Crate (2024 Edition)";
+    c.bench_function("slugify/title", |b| {
+        b.iter(|| ld::slugify(black_box(title)))
+    });
+
+    let options = ld::SlugOptions {
+        separator: "_".to_string(),
+        max_length: Some(32),
+    };
+    c.bench_function("slugify/title_max_length", |b| {
+        b.iter(|| ld::slugify_with(black_box(title), black_box(&options)))
+    });
+}
@@ -0,0 +1,19 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_sort_by_key_cached(c: &mut Criterion) {
+    let people = support::people(4_096);
+    let mut group = c.benchmark_group("sort_by_key_cached");
+    group.bench_function("people/uppercase_name/cached", |b| {
+        b.iter(|| ld::sort_by_key_cached(black_box(&people), |p| p.name.to_uppercase()))
+    });
+    group.bench_function("people/uppercase_name/uncached", |b| {
+        b.iter(|| {
+            let mut sorted = black_box(&people).to_vec();
+            sorted.sort_by_key(|p| p.name.to_uppercase());
+            sorted
+        })
+    });
+    group.finish();
+}
@@ -215,6 +215,19 @@ pub fn mixed_identifier() -> &'static str {
     "HTTPResponse_v2-userProfile 99Bottles_of_Soda"
 }
 
+pub fn identifiers(len: usize) -> Vec<String> {
+    const PATTERNS: [&str; 5] = [
+        "parseHttpResponse",
+        "user_account_id",
+        "XMLHttpRequest",
+        "max-retry-count",
+        "HTTP2ServerConfig",
+    ];
+    (0..len)
+        .map(|i| format!("{}{}", PATTERNS[i % PATTERNS.len()], i))
+        .collect()
+}
+
 pub fn long_sentence() -> &'static str {
     "Alpha beta-gamma_delta HTTPResponse42 keeps rolling across a long sentence for benchmarking text utilities"
 }
@@ -0,0 +1,17 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_symmetric_difference(c: &mut Criterion) {
+    let left: Vec<i32> = (0..4_096).collect();
+    let right: Vec<i32> = (2_048..6_144).collect();
+    c.bench_function("symmetric_difference/int_vec/overlapping", |b| {
+        b.iter(|| ld::symmetric_difference(black_box(&left), black_box(&right)))
+    });
+
+    let duplicates = support::duplicate_int_vec(4_096);
+    let shuffled = support::int_vec_shuffled(4_096);
+    c.bench_function("symmetric_difference/int_vec/duplicates", |b| {
+        b.iter(|| ld::symmetric_difference(black_box(&duplicates), black_box(&shuffled)))
+    });
+}
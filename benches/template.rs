@@ -0,0 +1,23 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+use std::collections::HashMap;
+
+pub fn benchmark_template(c: &mut Criterion) {
+    let source = "Dear {{name}}, your order ${order} ships on {{date|tomorrow}}.\n".repeat(20);
+    let mut values = HashMap::new();
+    values.insert("name".to_string(), "Ada Lovelace".to_string());
+    values.insert("order".to_string(), "#1842".to_string());
+
+    c.bench_function("template/compile_and_render", |b| {
+        b.iter(|| ld::template(black_box(&source), black_box(&values)))
+    });
+
+    let compiled = ld::Template::compile(&source).unwrap();
+    c.bench_function("template/render_compiled", |b| {
+        b.iter(|| black_box(&compiled).render(black_box(&values)))
+    });
+
+    c.bench_function("template/render_with_closure", |b| {
+        b.iter(|| black_box(&compiled).render_with(|key| values.get(key)))
+    });
+}
@@ -0,0 +1,9 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_trim_chars(c: &mut Criterion) {
+    let input = format!("{}{}{}", "-_".repeat(32), "hello world", "_-".repeat(32));
+    c.bench_function("trim_chars/padded", |b| {
+        b.iter(|| ld::trim_chars(black_box(&input), black_box(&['-', '_'])))
+    });
+}
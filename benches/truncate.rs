@@ -0,0 +1,24 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_truncate(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("truncate/long_sentence", |b| {
+        b.iter(|| ld::truncate(black_box(sentence), black_box(40)))
+    });
+
+    let options = ld::TruncateOptions {
+        position: ld::TruncatePosition::Middle,
+        word_boundary: true,
+        ..ld::TruncateOptions::default()
+    };
+    c.bench_function("truncate/long_sentence_middle_words", |b| {
+        b.iter(|| ld::truncate_with(black_box(sentence), black_box(40), black_box(&options)))
+    });
+
+    let wide = "こんにちは世界、東京タワー".repeat(8);
+    c.bench_function("truncate/wide", |b| {
+        b.iter(|| ld::truncate(black_box(&wide), black_box(40)))
+    });
+}
@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_chunk_evenly(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("try_chunk_evenly/int_vec/7", |b| {
+        b.iter(|| ld::try_chunk_evenly(black_box(&ints), black_box(7)))
+    });
+
+    c.bench_function("try_chunk_evenly/int_vec/0", |b| {
+        b.iter(|| ld::try_chunk_evenly(black_box(&ints), black_box(0)))
+    });
+}
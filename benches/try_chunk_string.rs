@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_chunk_string(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("try_chunk_string/long_sentence/12", |b| {
+        b.iter(|| ld::try_chunk_string(black_box(sentence), black_box(12)))
+    });
+
+    c.bench_function("try_chunk_string/long_sentence/0", |b| {
+        b.iter(|| ld::try_chunk_string(black_box(sentence), black_box(0)))
+    });
+}
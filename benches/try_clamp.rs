@@ -0,0 +1,16 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_clamp(c: &mut Criterion) {
+    c.bench_function("try_clamp/i32/within", |b| {
+        b.iter(|| ld::try_clamp(black_box(123_i32), black_box(-10_i32), black_box(90_i32)))
+    });
+
+    c.bench_function("try_clamp/i32/inverted", |b| {
+        b.iter(|| ld::try_clamp(black_box(5_i32), black_box(90_i32), black_box(-10_i32)))
+    });
+
+    c.bench_function("try_clamp/f64/within", |b| {
+        b.iter(|| ld::try_clamp(black_box(2.5_f64), black_box(0.0_f64), black_box(10.0_f64)))
+    });
+}
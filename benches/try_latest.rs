@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_latest(c: &mut Criterion) {
+    let times = support::time_vec(4_096);
+    c.bench_function("try_latest/time_vec", |b| {
+        b.iter(|| ld::try_latest(black_box(&times)))
+    });
+
+    c.bench_function("try_latest/empty", |b| {
+        b.iter(|| ld::try_latest(black_box(&[])))
+    });
+}
@@ -0,0 +1,25 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_latest_by(c: &mut Criterion) {
+    let timed_records_shuffled = support::timed_records_shuffled(4_096);
+    c.bench_function("try_latest_by/timed_records/shuffled", |b| {
+        b.iter(|| {
+            ld::try_latest_by(
+                black_box(&timed_records_shuffled),
+                black_box(|record: &support::TimedRecord| record.timestamp),
+            )
+        })
+    });
+
+    let copy_records_shuffled = support::copy_timed_records_shuffled(4_096);
+    c.bench_function("try_latest_by/copy_timed_records/shuffled", |b| {
+        b.iter(|| {
+            ld::try_latest_by(
+                black_box(&copy_records_shuffled),
+                black_box(|record: &support::CopyTimedRecord| record.timestamp),
+            )
+        })
+    });
+}
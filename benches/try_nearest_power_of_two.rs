@@ -0,0 +1,12 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_nearest_power_of_two(c: &mut Criterion) {
+    c.bench_function("try_nearest_power_of_two/65537", |b| {
+        b.iter(|| ld::try_nearest_power_of_two(black_box(65_537)))
+    });
+
+    c.bench_function("try_nearest_power_of_two/too_large", |b| {
+        b.iter(|| ld::try_nearest_power_of_two(black_box((1 << 30) + 1)))
+    });
+}
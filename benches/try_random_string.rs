@@ -0,0 +1,12 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_random_string(c: &mut Criterion) {
+    c.bench_function("try_random_string/64", |b| {
+        b.iter(|| ld::try_random_string(black_box(64), black_box(ld::common::ALPHANUMERIC_CHARSET)))
+    });
+
+    c.bench_function("try_random_string/8", |b| {
+        b.iter(|| ld::try_random_string(black_box(8), black_box(ld::common::ALPHANUMERIC_CHARSET)))
+    });
+}
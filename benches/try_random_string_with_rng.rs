@@ -0,0 +1,15 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_random_string_with_rng(c: &mut Criterion) {
+    let mut rng = ld::common::SeededRng::new(42);
+    c.bench_function("try_random_string_with_rng/64", |b| {
+        b.iter(|| {
+            ld::try_random_string_with_rng(
+                black_box(64),
+                black_box(ld::common::ALPHANUMERIC_CHARSET),
+                &mut rng,
+            )
+        })
+    });
+}
@@ -0,0 +1,14 @@
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_range_with_steps(c: &mut Criterion) {
+    c.bench_function("try_range_with_steps/0-10000-3", |b| {
+        b.iter(|| {
+            ld::try_range_with_steps(black_box(0_i32), black_box(10_000_i32), black_box(3_i32))
+        })
+    });
+
+    c.bench_function("try_range_with_steps/0-100-0", |b| {
+        b.iter(|| ld::try_range_with_steps(black_box(0_i32), black_box(100_i32), black_box(0_i32)))
+    });
+}
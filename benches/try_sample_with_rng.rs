@@ -0,0 +1,11 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_sample_with_rng(c: &mut Criterion) {
+    let mut rng = ld::common::SeededRng::new(42);
+    let ints = support::int_vec(4_096);
+    c.bench_function("try_sample_with_rng/int_vec", |b| {
+        b.iter(|| ld::try_sample_with_rng(black_box(&ints), &mut rng))
+    });
+}
@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_try_windows_partial(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("try_windows_partial/int_vec/64-step-32", |b| {
+        b.iter(|| ld::try_windows_partial(black_box(&ints), black_box(64), black_box(32)))
+    });
+
+    c.bench_function("try_windows_partial/int_vec/0", |b| {
+        b.iter(|| ld::try_windows_partial(black_box(&ints), black_box(0), black_box(1)))
+    });
+}
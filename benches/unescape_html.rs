@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_unescape_html(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("unescape_html/no_entities", |b| {
+        b.iter(|| ld::unescape_html(black_box(sentence)))
+    });
+
+    let input = "&lt;a href=&quot;/search?q=1&amp;page=2&quot;&gt;Tom&#39;s&lt;/a&gt; ".repeat(16);
+    c.bench_function("unescape_html/entities", |b| {
+        b.iter(|| ld::unescape_html(black_box(&input)))
+    });
+}
@@ -1,5 +1,5 @@
 use crate::support;
-use criterion::{black_box, Criterion};
+use criterion::{black_box, BenchmarkId, Criterion};
 use lowdash as ld;
 
 pub fn benchmark_uniq(c: &mut Criterion) {
@@ -15,4 +15,20 @@ pub fn benchmark_uniq(c: &mut Criterion) {
     c.bench_function("uniq/float_vec", |b| {
         b.iter(|| ld::uniq(black_box(&floats)))
     });
+
+    // Distinct elements are the worst case for the linear search in `uniq`: the time quadruples
+    // each time the input doubles, while `uniq_hashed` only doubles.
+    let mut group = c.benchmark_group("uniq/complexity");
+    for len in [1_024, 2_048, 4_096, 8_192] {
+        let distinct: Vec<i32> = (0..len).collect();
+        group.bench_with_input(BenchmarkId::new("uniq", len), &distinct, |b, input| {
+            b.iter(|| ld::uniq(black_box(input)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("uniq_hashed", len),
+            &distinct,
+            |b, input| b.iter(|| ld::uniq_hashed(black_box(input))),
+        );
+    }
+    group.finish();
 }
@@ -1,5 +1,5 @@
 use crate::support;
-use criterion::{black_box, Criterion};
+use criterion::{black_box, BenchmarkId, Criterion};
 use lowdash as ld;
 
 pub fn benchmark_uniq_by(c: &mut Criterion) {
@@ -27,4 +27,22 @@ pub fn benchmark_uniq_by(c: &mut Criterion) {
     c.bench_function("uniq_by/int_vec", |b| {
         b.iter(|| ld::uniq_by(black_box(&ints), black_box(|x: &i32| *x % 8)))
     });
+
+    let mut group = c.benchmark_group("uniq_by/complexity");
+    for len in [1_024, 2_048, 4_096, 8_192] {
+        let people = support::people(len);
+        group.bench_with_input(BenchmarkId::new("uniq_by", len), &people, |b, input| {
+            b.iter(|| ld::uniq_by(black_box(input), |person: &support::Person| person.id))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("uniq_by_hashed", len),
+            &people,
+            |b, input| {
+                b.iter(|| {
+                    ld::uniq_by_hashed(black_box(input), |person: &support::Person| person.id)
+                })
+            },
+        );
+    }
+    group.finish();
 }
@@ -0,0 +1,20 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_uniq_hashed(c: &mut Criterion) {
+    let duplicates = support::duplicate_int_vec(4_096);
+    c.bench_function("uniq_hashed/duplicate_int_vec", |b| {
+        b.iter(|| ld::uniq_hashed(black_box(&duplicates)))
+    });
+
+    let ints = support::int_vec(4_096);
+    c.bench_function("uniq_hashed/int_vec", |b| {
+        b.iter(|| ld::uniq_hashed(black_box(&ints)))
+    });
+
+    let people = support::people_shuffled(4_096);
+    c.bench_function("uniq_hashed/people", |b| {
+        b.iter(|| ld::uniq_hashed(black_box(&people)))
+    });
+}
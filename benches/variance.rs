@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_variance(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("variance/int_vec", |b| {
+        b.iter(|| ld::variance(black_box(&ints)))
+    });
+
+    let floats = support::float_vec(4_096);
+    c.bench_function("variance/float_vec", |b| {
+        b.iter(|| ld::variance(black_box(&floats)))
+    });
+}
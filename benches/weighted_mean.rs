@@ -0,0 +1,11 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_weighted_mean(c: &mut Criterion) {
+    let floats = support::float_vec(4_096);
+    let weights: Vec<f64> = (0..4_096).map(|i| (i % 7 + 1) as f64).collect();
+    c.bench_function("weighted_mean/float_vec", |b| {
+        b.iter(|| ld::weighted_mean(black_box(&floats), black_box(&weights)))
+    });
+}
@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_windows(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("windows/int_vec/8-step-1", |b| {
+        b.iter(|| ld::windows(black_box(&ints), black_box(8), black_box(1)))
+    });
+
+    c.bench_function("windows/int_vec/64-step-32", |b| {
+        b.iter(|| ld::windows(black_box(&ints), black_box(64), black_box(32)))
+    });
+}
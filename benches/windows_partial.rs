@@ -0,0 +1,14 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_windows_partial(c: &mut Criterion) {
+    let ints = support::int_vec(4_096);
+    c.bench_function("windows_partial/int_vec/8-step-1", |b| {
+        b.iter(|| ld::windows_partial(black_box(&ints), black_box(8), black_box(1)))
+    });
+
+    c.bench_function("windows_partial/int_vec/100-step-100", |b| {
+        b.iter(|| ld::windows_partial(black_box(&ints), black_box(100), black_box(100)))
+    });
+}
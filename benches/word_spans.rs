@@ -0,0 +1,30 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_word_spans(c: &mut Criterion) {
+    let identifiers = support::identifiers(1_000);
+
+    c.bench_function("word_spans/1000_identifiers_words_baseline", |b| {
+        b.iter(|| {
+            identifiers
+                .iter()
+                .map(|identifier| ld::words(black_box(identifier)).len())
+                .sum::<usize>()
+        })
+    });
+
+    c.bench_function("word_spans/1000_identifiers", |b| {
+        b.iter(|| {
+            identifiers
+                .iter()
+                .map(|identifier| ld::word_spans(black_box(identifier)).count())
+                .sum::<usize>()
+        })
+    });
+
+    let sentence = support::long_sentence();
+    c.bench_function("word_spans/long_sentence", |b| {
+        b.iter(|| ld::word_spans(black_box(sentence)).count())
+    });
+}
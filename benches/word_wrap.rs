@@ -0,0 +1,15 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_word_wrap(c: &mut Criterion) {
+    let sentence = support::long_sentence();
+    c.bench_function("word_wrap/long_sentence", |b| {
+        b.iter(|| ld::word_wrap(black_box(sentence), black_box(20)))
+    });
+
+    let text = "A state-of-the-art, well\u{AD}known para\u{AD}graph.\n\n".repeat(20);
+    c.bench_function("word_wrap/hyphenated", |b| {
+        b.iter(|| ld::word_wrap(black_box(&text), black_box(12)))
+    });
+}
@@ -0,0 +1,25 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_words_with(c: &mut Criterion) {
+    let defaults = ld::WordOptions::default();
+    let input = support::mixed_identifier();
+    c.bench_function("words_with/mixed_identifier_default", |b| {
+        b.iter(|| ld::words_with(black_box(input), black_box(&defaults)))
+    });
+
+    let options = ld::WordOptions {
+        acronyms: vec!["HTTP".to_string(), "OAuth".to_string()],
+        digits: ld::DigitGrouping::Attached,
+        ..ld::WordOptions::default()
+    };
+    c.bench_function("words_with/mixed_identifier_acronyms", |b| {
+        b.iter(|| ld::words_with(black_box(input), black_box(&options)))
+    });
+
+    let sentence = support::long_sentence();
+    c.bench_function("words_with/long_sentence", |b| {
+        b.iter(|| ld::words_with(black_box(sentence), black_box(&options)))
+    });
+}
@@ -0,0 +1,46 @@
+use crate::support;
+use criterion::{black_box, Criterion};
+use lowdash as ld;
+
+pub fn benchmark_write_case(c: &mut Criterion) {
+    let identifiers = support::identifiers(1_000);
+
+    c.bench_function("write_case/1000_identifiers_words_join_baseline", |b| {
+        b.iter(|| {
+            identifiers
+                .iter()
+                .map(|identifier| {
+                    ld::words(black_box(identifier))
+                        .iter()
+                        .map(|word| word.to_lowercase())
+                        .collect::<Vec<_>>()
+                        .join("_")
+                        .len()
+                })
+                .sum::<usize>()
+        })
+    });
+
+    c.bench_function("write_case/1000_identifiers_snake_case", |b| {
+        b.iter(|| {
+            identifiers
+                .iter()
+                .map(|identifier| ld::snake_case(black_box(identifier)).len())
+                .sum::<usize>()
+        })
+    });
+
+    c.bench_function("write_case/1000_identifiers_reused_buffer", |b| {
+        let mut buffer = String::with_capacity(64);
+        b.iter(|| {
+            identifiers
+                .iter()
+                .map(|identifier| {
+                    buffer.clear();
+                    ld::write_case(&mut buffer, black_box(identifier), ld::Case::Snake).unwrap();
+                    buffer.len()
+                })
+                .sum::<usize>()
+        })
+    });
+}
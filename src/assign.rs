@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use crate::MapLike;
 
 /// Merges multiple maps into a single map.
 /// If the same key exists in multiple maps, the value from the last map is used.
@@ -7,7 +7,7 @@ use std::collections::HashMap;
 /// * `maps` - A variadic number of maps to merge.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - The merged map.
+/// * `M` - The merged map, of the same type as the inputs.
 ///
 /// # Examples
 /// ```
@@ -23,23 +23,22 @@ use std::collections::HashMap;
 /// assert_eq!(merged.get("a"), Some(&1));
 /// assert_eq!(merged.get("b"), Some(&2));
 /// ```
-pub fn assign<K, V>(maps: &[HashMap<K, V>]) -> HashMap<K, V>
+pub fn assign<M, K, V>(maps: &[M]) -> M
 where
-    K: Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
 {
-    let mut out = HashMap::new();
-    for map in maps {
-        for (k, v) in map {
-            out.insert(k.clone(), v.clone());
-        }
-    }
-    out
+    MapLike::from_iter(
+        maps.iter()
+            .flat_map(|map| map.iter().map(|(k, v)| (k.clone(), v.clone()))),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_assign() {
@@ -70,4 +69,21 @@ mod tests {
         let merged: HashMap<&str, i32> = assign(&[]);
         assert!(merged.is_empty());
     }
+
+    #[test]
+    fn test_assign_entry_vec() {
+        use crate::Entry;
+
+        let first = vec![Entry { key: "a", value: 1 }, Entry { key: "b", value: 2 }];
+        let second = vec![Entry { key: "b", value: 3 }, Entry { key: "c", value: 4 }];
+        let merged = assign(&[first, second]);
+        assert_eq!(
+            merged,
+            vec![
+                Entry { key: "a", value: 1 },
+                Entry { key: "b", value: 3 },
+                Entry { key: "c", value: 4 },
+            ]
+        );
+    }
 }
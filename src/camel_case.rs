@@ -1,10 +1,11 @@
-use crate::pascal_case;
+use crate::{camel_case_with, WordOptions};
 
 /// Converts a string to camelCase.
 ///
-/// Splits the input string into words based on spaces, hyphens, and underscores,
-/// then converts the first word to lowercase and capitalizes the first letter of each subsequent word,
-/// and joins them together.
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then converts the first word to lowercase, capitalizes each subsequent
+/// word, and joins them together.
+/// Use [`camel_case_with`] to configure the splitting.
 ///
 /// # Arguments
 /// * `str_input` - The input string to convert
@@ -22,16 +23,7 @@ use crate::pascal_case;
 /// assert_eq!(camel_case("FooBarBazHello"), "fooBarBazHello");
 /// ```
 pub fn camel_case(str_input: &str) -> String {
-    if str_input.is_empty() {
-        return String::new();
-    }
-
-    let pascal = pascal_case(str_input);
-    let mut chars = pascal.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
-    }
+    camel_case_with(str_input, &WordOptions::for_case())
 }
 
 #[cfg(test)]
@@ -85,12 +77,12 @@ mod tests {
 
     #[test]
     fn test_with_numbers() {
-        assert_eq!(camel_case("hello2world"), "hello2world");
+        assert_eq!(camel_case("hello2world"), "hello2World");
     }
 
     #[test]
     fn test_with_special_characters() {
-        assert_eq!(camel_case("hello!world"), "hello!world");
+        assert_eq!(camel_case("hello!world"), "helloWorld");
     }
 
     #[test]
@@ -104,4 +96,15 @@ mod tests {
         assert_eq!(camel_case("---"), "");
         assert_eq!(camel_case("___"), "");
     }
+
+    #[test]
+    fn test_acronyms() {
+        assert_eq!(camel_case("HTTPRequest"), "httpRequest");
+        assert_eq!(camel_case("XMLHttpRequest"), "xmlHttpRequest");
+    }
+
+    #[test]
+    fn test_apostrophes_removed() {
+        assert_eq!(camel_case("don't stop"), "dontStop");
+    }
 }
@@ -0,0 +1,81 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to camelCase, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then converts the first word to
+/// lowercase, capitalizes each subsequent word, and joins them together.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in camelCase
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{camel_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(camel_case_with("XMLHttpRequest", &WordOptions::default()), "xmlHttpRequest");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(camel_case_with("HTTP2Server", &attached), "http2Server");
+/// ```
+pub fn camel_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Camel, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        let options = WordOptions::default();
+        assert_eq!(camel_case_with("HTTP2Server", &options), "http2Server");
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(camel_case_with("HTTP2Server", &options), "http2Server");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        let options = WordOptions {
+            acronyms: vec!["OAuth".to_string()],
+            ..WordOptions::default()
+        };
+        assert_eq!(camel_case_with("getOAuthToken", &options), "getOauthToken");
+    }
+
+    #[test]
+    fn test_apostrophes_kept() {
+        let options = WordOptions::default();
+        assert_eq!(camel_case_with("don't stop", &options), "don'tStop");
+    }
+
+    #[test]
+    fn test_custom_separators() {
+        let options = WordOptions {
+            separators: Some(vec![' ']),
+            ..WordOptions::default()
+        };
+        assert_eq!(camel_case_with("hello world!", &options), "helloWorld!");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(camel_case_with("", &WordOptions::default()), "");
+        assert_eq!(camel_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
@@ -0,0 +1,238 @@
+use std::iter::FusedIterator;
+
+/// Lazily generates the n-ary cartesian product of several slices: every way of picking one item
+/// from each slice, in order.
+///
+/// Tuples come in lexicographic order of positions, the last slice varying fastest. The product of
+/// no slices is a single empty tuple, and the product is empty if any slice is empty.
+///
+/// # Arguments
+/// * `sets` - The slices to combine, one per position of each tuple.
+///
+/// # Returns
+/// * `CartesianProduct<T>` - An iterator over the tuples, as `Vec<T>`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::cartesian_product;
+///
+/// let os: &[&str] = &["linux", "macos"];
+/// let toolchain: &[&str] = &["stable", "nightly"];
+/// let matrix: Vec<Vec<&str>> = cartesian_product(&[os, toolchain]).collect();
+/// assert_eq!(matrix, vec![
+///     vec!["linux", "stable"],
+///     vec!["linux", "nightly"],
+///     vec!["macos", "stable"],
+///     vec!["macos", "nightly"],
+/// ]);
+///
+/// let digits: &[u8] = &[0, 1];
+/// let sets = [digits, digits, digits];
+/// let mut product = cartesian_product(&sets);
+/// assert_eq!(product.len(), Some(8));
+/// let mut buffer = Vec::new();
+/// let mut sums = Vec::new();
+/// while product.next_into(&mut buffer) {
+///     sums.push(buffer.iter().sum::<u8>());
+/// }
+/// assert_eq!(sums, vec![0, 1, 1, 2, 1, 2, 2, 3]);
+/// ```
+pub fn cartesian_product<'a, T: Clone>(sets: &'a [&'a [T]]) -> CartesianProduct<'a, T> {
+    CartesianProduct::new(sets)
+}
+
+/// Iterator returned by [`cartesian_product`], yielding one item from each slice per tuple.
+#[derive(Clone, Debug)]
+pub struct CartesianProduct<'a, T> {
+    sets: &'a [&'a [T]],
+    positions: Vec<usize>,
+    started: bool,
+    done: bool,
+    remaining: Option<usize>,
+}
+
+impl<'a, T: Clone> CartesianProduct<'a, T> {
+    /// Creates an iterator over the cartesian product of `sets`.
+    pub fn new(sets: &'a [&'a [T]]) -> Self {
+        CartesianProduct {
+            sets,
+            positions: vec![0; sets.len()],
+            started: false,
+            done: sets.iter().any(|set| set.is_empty()),
+            remaining: sets
+                .iter()
+                .try_fold(1usize, |count, set| count.checked_mul(set.len())),
+        }
+    }
+
+    /// The number of tuples not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every tuple has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.done
+    }
+
+    /// Advances to the next tuple and returns the position picked in each slice, borrowed from the
+    /// iterator's own buffer so that no allocation happens per tuple.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.advance() {
+            return None;
+        }
+        Some(&self.positions)
+    }
+
+    /// Advances to the next tuple and replaces the contents of `buffer` with its items.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every tuple has been yielded.
+    pub fn next_into(&mut self, buffer: &mut Vec<T>) -> bool {
+        if !self.advance() {
+            return false;
+        }
+        buffer.clear();
+        buffer.extend(self.current());
+        true
+    }
+
+    /// The items at the current positions.
+    fn current(&self) -> impl Iterator<Item = T> + '_ {
+        self.sets
+            .iter()
+            .zip(&self.positions)
+            .map(|(set, &position)| set[position].clone())
+    }
+
+    /// Moves `positions` to the next tuple, returning `false` when there is none.
+    fn advance(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        if self.started {
+            let sets = self.sets;
+            match self
+                .positions
+                .iter()
+                .zip(sets)
+                .rposition(|(&position, set)| position + 1 < set.len())
+            {
+                Some(i) => {
+                    self.positions[i] += 1;
+                    self.positions[i + 1..].fill(0);
+                }
+                None => {
+                    self.done = true;
+                    return false;
+                }
+            }
+        }
+        self.started = true;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        if self.remaining == Some(0) {
+            self.done = true;
+        }
+        true
+    }
+}
+
+impl<T: Clone> Iterator for CartesianProduct<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.advance() {
+            return None;
+        }
+        Some(self.current().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: Clone> FusedIterator for CartesianProduct<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cartesian_product_three_sets() {
+        let a: &[i32] = &[1, 2];
+        let b: &[i32] = &[10];
+        let c: &[i32] = &[100, 200, 300];
+        let tuples: Vec<Vec<i32>> = cartesian_product(&[a, b, c]).collect();
+        assert_eq!(
+            tuples,
+            vec![
+                vec![1, 10, 100],
+                vec![1, 10, 200],
+                vec![1, 10, 300],
+                vec![2, 10, 100],
+                vec![2, 10, 200],
+                vec![2, 10, 300],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cartesian_product_edge_cases() {
+        let none: &[&[i32]] = &[];
+        assert_eq!(
+            cartesian_product(none).collect::<Vec<_>>(),
+            vec![Vec::<i32>::new()]
+        );
+
+        let empty: &[i32] = &[];
+        let full: &[i32] = &[1, 2];
+        assert_eq!(cartesian_product(&[full, empty]).count(), 0);
+        assert_eq!(cartesian_product(&[full, empty]).len(), Some(0));
+
+        assert_eq!(
+            cartesian_product(&[full]).collect::<Vec<_>>(),
+            vec![vec![1], vec![2]]
+        );
+    }
+
+    #[test]
+    fn test_cartesian_product_len() {
+        let set: &[char] = &['a', 'b', 'c'];
+        let sets = [set, set];
+        let mut iter = cartesian_product(&sets);
+        assert_eq!(iter.len(), Some(9));
+        assert_eq!(iter.size_hint(), (9, Some(9)));
+        iter.nth(3);
+        assert_eq!(iter.len(), Some(5));
+        assert_eq!(iter.by_ref().count(), 5);
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_cartesian_product_len_overflow() {
+        let set: &[u8] = &[0, 1];
+        let sets = vec![set; 70];
+        let mut iter = cartesian_product(&sets);
+        assert_eq!(iter.len(), None);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.next(), Some(vec![0; 70]));
+    }
+
+    #[test]
+    fn test_cartesian_product_next_indices() {
+        let letters: &[&str] = &["x", "y"];
+        let numbers: &[&str] = &["1", "2", "3"];
+        let sets = [letters, numbers];
+        let mut iter = cartesian_product(&sets);
+        assert_eq!(iter.next_indices(), Some(&[0, 0][..]));
+        assert_eq!(iter.next_indices(), Some(&[0, 1][..]));
+        let mut buffer = Vec::new();
+        assert!(iter.next_into(&mut buffer));
+        assert_eq!(buffer, vec!["x", "3"]);
+        assert_eq!(iter.next(), Some(vec!["y", "1"]));
+    }
+}
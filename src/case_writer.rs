@@ -0,0 +1,167 @@
+use std::fmt::{self, Write};
+
+use crate::word_spans_with::is_apostrophe;
+use crate::{word_spans_with, Apostrophes, Case, WordOptions};
+
+/// How the letters of one word are written.
+#[derive(Clone, Copy)]
+enum WordStyle {
+    Lower,
+    Upper,
+    Capitalized,
+    UpperFirst,
+}
+
+/// Writes `str_input` in the given case, splitting it with [`word_spans_with`] so no word is
+/// copied. Title case keeps the words of `small_words` in lowercase; other cases ignore them.
+pub(crate) fn write_words<W: Write>(
+    out: &mut W,
+    str_input: &str,
+    case: Case,
+    options: &WordOptions,
+    small_words: &[&str],
+) -> fmt::Result {
+    let separator = match case {
+        Case::Camel | Case::Pascal => "",
+        Case::Snake | Case::Constant => "_",
+        Case::Kebab | Case::Train => "-",
+        Case::Title | Case::Sentence | Case::Start => " ",
+        Case::Dot => ".",
+        Case::Path => "/",
+    };
+    let skip_apostrophes = options.apostrophes == Apostrophes::Remove;
+
+    let mut words = word_spans_with(str_input, options).peekable();
+    let mut first = true;
+    while let Some(word) = words.next() {
+        let style = match case {
+            Case::Camel if first => WordStyle::Lower,
+            Case::Sentence if first => WordStyle::Capitalized,
+            Case::Camel | Case::Pascal | Case::Train => WordStyle::Capitalized,
+            Case::Snake | Case::Kebab | Case::Dot | Case::Path | Case::Sentence => WordStyle::Lower,
+            Case::Constant => WordStyle::Upper,
+            Case::Title if !first && words.peek().is_some() && is_small_word(word, small_words) => {
+                WordStyle::Lower
+            }
+            Case::Title => WordStyle::Capitalized,
+            Case::Start => WordStyle::UpperFirst,
+        };
+
+        if !first {
+            out.write_str(separator)?;
+        }
+        write_word(out, word, style, skip_apostrophes)?;
+        first = false;
+    }
+
+    Ok(())
+}
+
+/// Converts `str_input` to the given case in a new `String`.
+pub(crate) fn case_string(
+    str_input: &str,
+    case: Case,
+    options: &WordOptions,
+    small_words: &[&str],
+) -> String {
+    let mut result = String::with_capacity(str_input.len());
+    write_words(&mut result, str_input, case, options, small_words)
+        .expect("writing to a String never fails");
+    result
+}
+
+/// The options the plain converter of each case uses: prose cases keep apostrophes, identifier
+/// cases drop them.
+pub(crate) fn default_case_options(case: Case) -> WordOptions {
+    match case {
+        Case::Title | Case::Sentence => WordOptions::default(),
+        _ => WordOptions::for_case(),
+    }
+}
+
+fn write_word<W: Write>(
+    out: &mut W,
+    word: &str,
+    style: WordStyle,
+    skip_apostrophes: bool,
+) -> fmt::Result {
+    let chars = word
+        .chars()
+        .filter(|&c| !(skip_apostrophes && is_apostrophe(c)));
+    for (i, c) in chars.enumerate() {
+        match (style, i) {
+            (WordStyle::Lower, _) | (WordStyle::Capitalized, 1..) => {
+                for lower in c.to_lowercase() {
+                    out.write_char(lower)?;
+                }
+            }
+            (WordStyle::Upper, _) | (WordStyle::Capitalized | WordStyle::UpperFirst, 0) => {
+                for upper in c.to_uppercase() {
+                    out.write_char(upper)?;
+                }
+            }
+            (WordStyle::UpperFirst, _) => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
+fn is_small_word(word: &str, small_words: &[&str]) -> bool {
+    small_words.iter().any(|small| {
+        small
+            .chars()
+            .flat_map(char::to_lowercase)
+            .eq(word.chars().flat_map(char::to_lowercase))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_words_appends() {
+        let mut out = String::from("prefix:");
+        write_words(
+            &mut out,
+            "fooBar",
+            Case::Snake,
+            &WordOptions::default(),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(out, "prefix:foo_bar");
+    }
+
+    #[test]
+    fn test_skips_removed_apostrophes() {
+        let options = WordOptions::for_case();
+        assert_eq!(
+            case_string("it’s here", Case::Camel, &options, &[]),
+            "itsHere"
+        );
+        assert_eq!(
+            case_string("it’s here", Case::Start, &options, &[]),
+            "Its Here"
+        );
+    }
+
+    #[test]
+    fn test_small_words_only_inside_title() {
+        let options = WordOptions::default();
+        assert_eq!(
+            case_string("of the of", Case::Title, &options, &["of", "the"]),
+            "Of the Of"
+        );
+        assert_eq!(
+            case_string("of the of", Case::Start, &options, &["of"]),
+            "Of The Of"
+        );
+    }
+
+    #[test]
+    fn test_default_case_options() {
+        assert_eq!(default_case_options(Case::Title), WordOptions::default());
+        assert_eq!(default_case_options(Case::Snake), WordOptions::for_case());
+    }
+}
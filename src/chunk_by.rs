@@ -0,0 +1,91 @@
+/// Split a collection into chunks of consecutive elements that share the same key, generated by
+/// the iteratee function.
+///
+/// A new chunk starts every time the key changes, so equal keys that are not adjacent end up in
+/// different chunks. This differs from `partition_by`, which gathers every element with the same
+/// key into one partition wherever it appears.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be divided into chunks.
+/// * `iteratee` - A function that takes an item and returns the key compared between neighbours.
+///
+/// # Returns
+/// * `Vec<Vec<T>>` - The runs of elements with equal keys, in order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::chunk_by;
+///
+/// let numbers = vec![1, 3, 2, 4, 6, 5, 7];
+/// assert_eq!(
+///     chunk_by(&numbers, |x| x % 2 == 0),
+///     vec![vec![1, 3], vec![2, 4, 6], vec![5, 7]]
+/// );
+///
+/// let log = vec!["INFO a", "INFO b", "WARN c", "INFO d"];
+/// let runs = chunk_by(&log, |line| line.split(' ').next().unwrap().to_string());
+/// assert_eq!(runs, vec![vec!["INFO a", "INFO b"], vec!["WARN c"], vec!["INFO d"]]);
+/// ```
+pub fn chunk_by<T, K, F>(collection: &[T], iteratee: F) -> Vec<Vec<T>>
+where
+    T: Clone,
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    let mut result: Vec<Vec<T>> = Vec::new();
+    let mut current_key: Option<K> = None;
+
+    for item in collection {
+        let key = iteratee(item);
+        match result.last_mut() {
+            Some(chunk) if current_key.as_ref() == Some(&key) => chunk.push(item.clone()),
+            _ => {
+                result.push(vec![item.clone()]);
+                current_key = Some(key);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::partition_by;
+
+    #[test]
+    fn test_chunk_by_consecutive_runs() {
+        let letters = vec!['a', 'a', 'b', 'a', 'c', 'c'];
+        assert_eq!(
+            chunk_by(&letters, |c| *c),
+            vec![vec!['a', 'a'], vec!['b'], vec!['a'], vec!['c', 'c']]
+        );
+    }
+
+    #[test]
+    fn test_chunk_by_differs_from_partition_by() {
+        let numbers = vec![1, 1, 2, 1];
+        assert_eq!(
+            chunk_by(&numbers, |x| *x),
+            vec![vec![1, 1], vec![2], vec![1]]
+        );
+        assert_eq!(partition_by(&numbers, |x| *x), vec![vec![1, 1, 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_chunk_by_float_keys() {
+        let readings = vec![1.0, 1.4, 2.2, 2.9, 1.1];
+        assert_eq!(
+            chunk_by(&readings, |x: &f64| x.floor()),
+            vec![vec![1.0, 1.4], vec![2.2, 2.9], vec![1.1]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_by_single_run_and_empty() {
+        assert_eq!(chunk_by(&[5, 5, 5], |x| *x), vec![vec![5, 5, 5]]);
+        let empty: Vec<i32> = vec![];
+        assert!(chunk_by(&empty, |x| *x).is_empty());
+    }
+}
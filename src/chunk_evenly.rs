@@ -0,0 +1,108 @@
+/// Divide a collection into `parts` consecutive chunks whose sizes differ by at most one,
+/// preserving the order of elements.
+///
+/// Exactly `parts` chunks are returned, which makes the result suitable for spreading work over
+/// a fixed number of workers: the first `len % parts` chunks hold one extra element, and when
+/// there are fewer elements than parts the trailing chunks are empty.
+///
+/// Only the elements are cloned, since empty chunks do not allocate, but the outer vector always
+/// holds `parts` entries.
+///
+/// **Panics:**
+/// Panics if `parts` is 0, or too large for the `parts` chunks to be allocated; use
+/// `try_chunk_evenly` to get an error instead.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be divided.
+/// * `parts` - The number of chunks to produce.
+///
+/// # Returns
+/// * `Vec<Vec<T>>` - The `parts` chunks, in order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::chunk_evenly;
+///
+/// let numbers: Vec<i32> = (1..=10).collect();
+/// assert_eq!(
+///     chunk_evenly(&numbers, 3),
+///     vec![vec![1, 2, 3, 4], vec![5, 6, 7], vec![8, 9, 10]]
+/// );
+///
+/// let jobs = vec!["a", "b"];
+/// assert_eq!(chunk_evenly(&jobs, 3), vec![vec!["a"], vec!["b"], vec![]]);
+/// ```
+pub fn chunk_evenly<T>(collection: &[T], parts: usize) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    if parts == 0 {
+        panic!("Number of parts must be greater than 0");
+    }
+
+    let mut result = Vec::with_capacity(parts);
+    push_chunks_evenly(collection, parts, &mut result);
+    result
+}
+
+/// Pushes the `parts` chunks of `collection` onto `result`, which should already have room for
+/// them.
+pub(crate) fn push_chunks_evenly<T>(collection: &[T], parts: usize, result: &mut Vec<Vec<T>>)
+where
+    T: Clone,
+{
+    let base = collection.len() / parts;
+    let extra = collection.len() % parts;
+    let mut start = 0;
+
+    for part in 0..parts {
+        let size = base + usize::from(part < extra);
+        result.push(collection[start..start + size].to_vec());
+        start += size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_evenly_exact() {
+        let numbers: Vec<i32> = (0..6).collect();
+        assert_eq!(
+            chunk_evenly(&numbers, 3),
+            vec![vec![0, 1], vec![2, 3], vec![4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_evenly_sizes_differ_by_at_most_one() {
+        let numbers: Vec<i32> = (0..103).collect();
+        let chunks = chunk_evenly(&numbers, 10);
+        assert_eq!(chunks.len(), 10);
+        let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![11, 11, 11, 10, 10, 10, 10, 10, 10, 10]);
+        assert_eq!(chunks.concat(), numbers);
+    }
+
+    #[test]
+    fn test_chunk_evenly_more_parts_than_elements() {
+        assert_eq!(
+            chunk_evenly(&[1], 3),
+            vec![vec![1], Vec::<i32>::new(), Vec::<i32>::new()]
+        );
+        let empty: Vec<i32> = vec![];
+        assert_eq!(chunk_evenly(&empty, 2), vec![Vec::<i32>::new(); 2]);
+    }
+
+    #[test]
+    fn test_chunk_evenly_single_part() {
+        assert_eq!(chunk_evenly(&[1, 2, 3], 1), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of parts must be greater than 0")]
+    fn test_chunk_evenly_zero_parts() {
+        chunk_evenly(&[1, 2, 3], 0);
+    }
+}
@@ -0,0 +1,88 @@
+/// Split a collection into chunks of consecutive elements, keeping each element in the same
+/// chunk as the one before it while the predicate holds for the pair.
+///
+/// The predicate receives each pair of neighbours in order, like the predicate of
+/// `slice::chunk_by` in the standard library, and a new chunk starts wherever it returns `false`.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be divided into chunks.
+/// * `predicate` - A function that takes two neighbouring items and returns whether they belong
+///   to the same chunk.
+///
+/// # Returns
+/// * `Vec<Vec<T>>` - The chunks, in order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::chunk_while;
+///
+/// // Runs of consecutive integers.
+/// let numbers = vec![1, 2, 3, 7, 8, 10];
+/// assert_eq!(
+///     chunk_while(&numbers, |a, b| b - a == 1),
+///     vec![vec![1, 2, 3], vec![7, 8], vec![10]]
+/// );
+///
+/// // Ascending runs.
+/// let values = vec![3, 5, 9, 2, 4, 1];
+/// assert_eq!(
+///     chunk_while(&values, |a, b| a <= b),
+///     vec![vec![3, 5, 9], vec![2, 4], vec![1]]
+/// );
+/// ```
+pub fn chunk_while<T, F>(collection: &[T], predicate: F) -> Vec<Vec<T>>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut result: Vec<Vec<T>> = Vec::new();
+    let mut previous: Option<&T> = None;
+
+    for item in collection {
+        match (result.last_mut(), previous) {
+            (Some(chunk), Some(previous)) if predicate(previous, item) => chunk.push(item.clone()),
+            _ => result.push(vec![item.clone()]),
+        }
+        previous = Some(item);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_while_gaps_in_timestamps() {
+        let timestamps = vec![100, 105, 109, 200, 204, 400];
+        assert_eq!(
+            chunk_while(&timestamps, |a, b| b - a <= 10),
+            vec![vec![100, 105, 109], vec![200, 204], vec![400]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_while_compares_neighbours_only() {
+        let numbers = vec![1, 2, 3, 4];
+        assert_eq!(
+            chunk_while(&numbers, |a, b| b - a == 1),
+            vec![vec![1, 2, 3, 4]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_while_never_true() {
+        assert_eq!(
+            chunk_while(&["a", "b"], |_, _| false),
+            vec![vec!["a"], vec!["b"]]
+        );
+    }
+
+    #[test]
+    fn test_chunk_while_empty_and_single() {
+        let empty: Vec<i32> = vec![];
+        assert!(chunk_while(&empty, |_, _| true).is_empty());
+        assert_eq!(chunk_while(&[1], |_, _| false), vec![vec![1]]);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{closest_matches_with, MatchOptions};
+
+/// Finds the candidates closest to a string, for "did you mean" suggestions.
+///
+/// Candidates are ranked by [`levenshtein`](crate::levenshtein) distance, fewest edits first, and
+/// ties keep the order of `candidates`. Use
+/// [`closest_matches_with`](crate::closest_matches_with) to pick another metric, drop distant
+/// candidates or ignore case.
+///
+/// # Arguments
+/// * `needle` - The string to match, such as mistyped user input
+/// * `candidates` - The strings to choose from
+/// * `n` - The maximum number of matches to return
+///
+/// # Returns
+/// * `Vec<&str>` - Up to `n` candidates, closest first
+///
+/// # Examples
+/// ```rust
+/// use lowdash::closest_matches;
+///
+/// let commands = ["status", "stash", "commit", "checkout"];
+/// assert_eq!(closest_matches("stats", &commands, 2), vec!["status", "stash"]);
+/// assert_eq!(closest_matches("comit", &commands, 1), vec!["commit"]);
+/// ```
+pub fn closest_matches<'a>(needle: &str, candidates: &[&'a str], n: usize) -> Vec<&'a str> {
+    closest_matches_with(needle, candidates, n, &MatchOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_first() {
+        let words = ["hello", "help", "hell", "yellow", "world"];
+        assert_eq!(
+            closest_matches("helo", &words, 3),
+            vec!["hello", "help", "hell"]
+        );
+    }
+
+    #[test]
+    fn test_keeps_every_candidate() {
+        let words = ["abc", "xyz"];
+        assert_eq!(closest_matches("abd", &words, 5), vec!["abc", "xyz"]);
+    }
+
+    #[test]
+    fn test_case_sensitive() {
+        let words = ["APPLE", "apply"];
+        assert_eq!(closest_matches("apple", &words, 1), vec!["apply"]);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{damerau_levenshtein, jaro_winkler, lcs_distance, levenshtein};
+use crate::{MatchOptions, StringMetric};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// Finds the candidates closest to a string, using the metric and thresholds of the given
+/// [`MatchOptions`].
+///
+/// Candidates are ranked best first by the chosen [`StringMetric`]: by distance for the edit
+/// metrics and by similarity for Jaro–Winkler. As with [`min_by`](crate::min_by), ties keep the
+/// order of `candidates`. Candidates outside `max_distance` or below `min_similarity` are dropped,
+/// then at most `n` are returned. Only the `n` best are sorted, so picking a few suggestions from
+/// a long list costs little more than scoring it.
+///
+/// # Arguments
+/// * `needle` - The string to match, such as mistyped user input
+/// * `candidates` - The strings to choose from
+/// * `n` - The maximum number of matches to return
+/// * `options` - The metric, thresholds and case sensitivity
+///
+/// # Returns
+/// * `Vec<&str>` - Up to `n` candidates, closest first
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{closest_matches_with, MatchOptions, StringMetric};
+///
+/// let fruits = ["apple", "apply", "maple", "banana"];
+///
+/// let options = MatchOptions {
+///     metric: StringMetric::JaroWinkler,
+///     min_similarity: 0.8,
+///     ..MatchOptions::default()
+/// };
+/// assert_eq!(closest_matches_with("appel", &fruits, 3, &options), vec!["apple", "apply"]);
+///
+/// let options = MatchOptions {
+///     case_insensitive: true,
+///     max_distance: Some(1),
+///     ..MatchOptions::default()
+/// };
+/// assert_eq!(closest_matches_with("BANANAS", &fruits, 3, &options), vec!["banana"]);
+/// ```
+pub fn closest_matches_with<'a>(
+    needle: &str,
+    candidates: &[&'a str],
+    n: usize,
+    options: &MatchOptions,
+) -> Vec<&'a str> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let needle = normalize(needle, options);
+
+    let mut scored: Vec<(usize, &'a str, Score)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &candidate)| {
+            let score = score(&needle, &normalize(candidate, options), options.metric);
+            let within_distance = match (options.max_distance, score.distance) {
+                (Some(max), Some(distance)) => distance <= max,
+                _ => true,
+            };
+            (within_distance && score.similarity >= options.min_similarity)
+                .then_some((index, candidate, score))
+        })
+        .collect();
+
+    // Select the best `n` in linear time, then sort only those.
+    if scored.len() > n {
+        scored.select_nth_unstable_by(n - 1, rank_scored);
+        scored.truncate(n);
+    }
+    scored.sort_unstable_by(rank_scored);
+
+    scored
+        .into_iter()
+        .map(|(_, candidate, _)| candidate)
+        .collect()
+}
+
+/// How close a candidate is to the needle. `distance` is `None` for metrics without an edit
+/// count.
+struct Score {
+    distance: Option<usize>,
+    similarity: f64,
+}
+
+impl Score {
+    /// Orders the closer score first.
+    fn rank(&self, other: &Score) -> Ordering {
+        match (self.distance, other.distance) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => other.similarity.total_cmp(&self.similarity),
+        }
+    }
+}
+
+/// Orders the closer candidate first, falling back to its position in `candidates`. The order is
+/// total, so equally close candidates keep their order even though neither the selection nor the
+/// sort is stable.
+fn rank_scored(a: &(usize, &str, Score), b: &(usize, &str, Score)) -> Ordering {
+    a.2.rank(&b.2).then(a.0.cmp(&b.0))
+}
+
+fn normalize<'s>(input: &'s str, options: &MatchOptions) -> Cow<'s, str> {
+    if options.case_insensitive {
+        Cow::Owned(input.to_lowercase())
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+fn score(needle: &str, candidate: &str, metric: StringMetric) -> Score {
+    let needle_len = needle.chars().count();
+    let candidate_len = candidate.chars().count();
+    let similarity = |distance: usize, length: usize| {
+        if length == 0 {
+            1.0
+        } else {
+            1.0 - distance as f64 / length as f64
+        }
+    };
+
+    match metric {
+        StringMetric::Levenshtein | StringMetric::DamerauLevenshtein => {
+            let distance = if metric == StringMetric::Levenshtein {
+                levenshtein(needle, candidate)
+            } else {
+                damerau_levenshtein(needle, candidate)
+            };
+            Score {
+                distance: Some(distance),
+                similarity: similarity(distance, needle_len.max(candidate_len)),
+            }
+        }
+        StringMetric::JaroWinkler => Score {
+            distance: None,
+            similarity: jaro_winkler(needle, candidate),
+        },
+        StringMetric::LongestCommonSubsequence => {
+            let distance = lcs_distance(needle, candidate);
+            Score {
+                distance: Some(distance),
+                similarity: similarity(distance, needle_len + candidate_len),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMANDS: [&str; 6] = [
+        "status",
+        "stash",
+        "commit",
+        "checkout",
+        "cherry-pick",
+        "clone",
+    ];
+
+    fn with_metric(metric: StringMetric) -> MatchOptions {
+        MatchOptions {
+            metric,
+            ..MatchOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_ranks_by_levenshtein() {
+        let options = MatchOptions::default();
+        assert_eq!(
+            closest_matches_with("stats", &COMMANDS, 2, &options),
+            vec!["status", "stash"]
+        );
+    }
+
+    #[test]
+    fn test_damerau_levenshtein_prefers_transpositions() {
+        let candidates = ["form", "from"];
+        assert_eq!(
+            closest_matches_with(
+                "fro",
+                &candidates,
+                1,
+                &with_metric(StringMetric::Levenshtein)
+            ),
+            vec!["from"]
+        );
+        assert_eq!(
+            closest_matches_with(
+                "fomr",
+                &candidates,
+                2,
+                &with_metric(StringMetric::DamerauLevenshtein)
+            ),
+            vec!["form", "from"]
+        );
+    }
+
+    #[test]
+    fn test_jaro_winkler_ranks_by_similarity() {
+        let options = with_metric(StringMetric::JaroWinkler);
+        assert_eq!(
+            closest_matches_with("chekout", &COMMANDS, 2, &options),
+            vec!["checkout", "cherry-pick"]
+        );
+    }
+
+    #[test]
+    fn test_longest_common_subsequence() {
+        let options = with_metric(StringMetric::LongestCommonSubsequence);
+        assert_eq!(
+            closest_matches_with("cmmit", &COMMANDS, 1, &options),
+            vec!["commit"]
+        );
+    }
+
+    #[test]
+    fn test_ties_keep_candidate_order() {
+        let options = MatchOptions::default();
+        assert_eq!(
+            closest_matches_with("ab", &["xb", "ay", "ab"], 3, &options),
+            vec!["ab", "xb", "ay"]
+        );
+    }
+
+    #[test]
+    fn test_ties_keep_candidate_order_when_truncated() {
+        let options = MatchOptions::default();
+        let candidates = ["zz", "xb", "qq", "ay", "ab", "ac", "ww"];
+        assert_eq!(
+            closest_matches_with("ab", &candidates, 3, &options),
+            vec!["ab", "xb", "ay"]
+        );
+        assert_eq!(
+            closest_matches_with("ab", &candidates, 1, &options),
+            vec!["ab"]
+        );
+    }
+
+    #[test]
+    fn test_max_distance() {
+        let options = MatchOptions {
+            max_distance: Some(1),
+            ..MatchOptions::default()
+        };
+        assert_eq!(
+            closest_matches_with("clon", &COMMANDS, 5, &options),
+            vec!["clone"]
+        );
+        assert!(closest_matches_with("push", &COMMANDS, 5, &options).is_empty());
+    }
+
+    #[test]
+    fn test_max_distance_ignored_by_jaro_winkler() {
+        let options = MatchOptions {
+            metric: StringMetric::JaroWinkler,
+            max_distance: Some(0),
+            ..MatchOptions::default()
+        };
+        assert_eq!(
+            closest_matches_with("clon", &COMMANDS, 1, &options),
+            vec!["clone"]
+        );
+    }
+
+    #[test]
+    fn test_min_similarity() {
+        let options = MatchOptions {
+            min_similarity: 0.8,
+            ..MatchOptions::default()
+        };
+        // "clone" is one edit from "clon" out of five characters, a similarity of 0.8.
+        assert_eq!(
+            closest_matches_with("clon", &COMMANDS, 5, &options),
+            vec!["clone"]
+        );
+
+        let options = MatchOptions {
+            metric: StringMetric::LongestCommonSubsequence,
+            min_similarity: 0.9,
+            ..MatchOptions::default()
+        };
+        assert!(closest_matches_with("clon", &COMMANDS, 5, &options).is_empty());
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let sensitive = MatchOptions {
+            max_distance: Some(1),
+            ..MatchOptions::default()
+        };
+        assert!(closest_matches_with("STATUS", &COMMANDS, 1, &sensitive).is_empty());
+
+        let insensitive = MatchOptions {
+            case_insensitive: true,
+            ..sensitive
+        };
+        assert_eq!(
+            closest_matches_with("STATUS", &COMMANDS, 1, &insensitive),
+            vec!["status"]
+        );
+        assert_eq!(
+            closest_matches_with("status", &["Status"], 1, &insensitive),
+            vec!["Status"]
+        );
+    }
+
+    #[test]
+    fn test_n_limits_results() {
+        let options = MatchOptions::default();
+        assert_eq!(closest_matches_with("x", &COMMANDS, 0, &options).len(), 0);
+        assert_eq!(closest_matches_with("x", &COMMANDS, 10, &options).len(), 6);
+    }
+
+    #[test]
+    fn test_empty_inputs() {
+        let options = MatchOptions::default();
+        assert!(closest_matches_with("status", &[], 3, &options).is_empty());
+        assert_eq!(
+            closest_matches_with("", &["", "a"], 2, &options),
+            vec!["", "a"]
+        );
+    }
+}
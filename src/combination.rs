@@ -1,5 +1,9 @@
+use crate::Combinations;
+
 /// Finds all combinations of k elements from a collection.
 ///
+/// Every combination is collected into memory; use `combinations` to walk them lazily instead.
+///
 /// # Arguments
 /// * `items` - A slice of items to combine
 /// * `k` - The number of elements to select in each combination
@@ -18,21 +22,10 @@
 /// assert!(result.contains(&vec![2, 3]));
 /// ```
 pub fn combination<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
-    if k == 0 {
-        return vec![vec![]];
-    }
-    if k > items.len() {
-        return vec![];
-    }
-    let mut result = Vec::new();
-    for i in 0..=items.len() - k {
-        let current = items[i].clone();
-        let rest_combinations = combination(&items[i + 1..], k - 1);
-        for mut comb in rest_combinations {
-            let mut entry = vec![current.clone()];
-            entry.append(&mut comb);
-            result.push(entry);
-        }
+    let mut combinations = Combinations::new(items.len(), k);
+    let mut result = Vec::with_capacity(combinations.len().unwrap_or(0));
+    while let Some(indices) = combinations.next_indices() {
+        result.push(indices.iter().map(|&index| items[index].clone()).collect());
     }
     result
 }
@@ -0,0 +1,91 @@
+use crate::common::checked_binomial;
+
+/// Returns the rank of a combination of indices out of `0..n`, the inverse of `nth_combination`.
+///
+/// Ranks follow the lexicographic order of `combinations` and `combination`, so the combination
+/// at position `r` of `combinations(n, k)` has rank `r`.
+///
+/// # Arguments
+/// * `n` - The number of items the combination was chosen from.
+/// * `indices` - The indices of the combination, in strictly increasing order.
+///
+/// # Returns
+/// * `Option<usize>` - The zero-based rank, or `None` if `indices` is not strictly increasing, has
+///   an index not below `n`, or has a rank that does not fit in a `usize`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{combination_rank, nth_combination};
+///
+/// assert_eq!(combination_rank(5, &[0, 1, 2]), Some(0));
+/// assert_eq!(combination_rank(5, &[1, 2, 3]), Some(6));
+/// assert_eq!(combination_rank(5, &[2, 3, 4]), Some(9));
+/// assert_eq!(combination_rank(5, &[3, 1]), None);
+///
+/// let indices = nth_combination(40, 6, 1_234_567).unwrap();
+/// assert_eq!(combination_rank(40, &indices), Some(1_234_567));
+/// ```
+pub fn combination_rank(n: usize, indices: &[usize]) -> Option<usize> {
+    let k = indices.len();
+    let mut rank: usize = 0;
+    let mut candidate = 0;
+    for (position, &index) in indices.iter().enumerate() {
+        if index < candidate || index >= n {
+            return None;
+        }
+        // Every combination that picks a smaller index here comes first.
+        for skipped in candidate..index {
+            let block = checked_binomial(n - skipped - 1, k - position - 1)?;
+            rank = rank.checked_add(block)?;
+        }
+        candidate = index + 1;
+    }
+    Some(rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combinations, nth_combination};
+
+    #[test]
+    fn test_combination_rank_matches_enumeration() {
+        for n in 0..8 {
+            for k in 0..=n {
+                for (rank, indices) in combinations(n, k).enumerate() {
+                    assert_eq!(combination_rank(n, &indices), Some(rank));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_combination_rank_invalid() {
+        assert_eq!(combination_rank(5, &[1, 1]), None);
+        assert_eq!(combination_rank(5, &[2, 1]), None);
+        assert_eq!(combination_rank(5, &[4, 5]), None);
+        assert_eq!(combination_rank(0, &[0]), None);
+    }
+
+    #[test]
+    fn test_combination_rank_empty() {
+        assert_eq!(combination_rank(0, &[]), Some(0));
+        assert_eq!(combination_rank(7, &[]), Some(0));
+    }
+
+    #[test]
+    fn test_combination_rank_round_trip() {
+        for rank in [0, 1, 999, 5_461_511] {
+            let indices = nth_combination(60, 5, rank).unwrap();
+            assert_eq!(combination_rank(60, &indices), Some(rank));
+        }
+    }
+
+    #[test]
+    fn test_combination_rank_overflow() {
+        let last: Vec<usize> = (100..200).collect();
+        assert_eq!(combination_rank(200, &last), None);
+        let second: Vec<usize> = (0..99).chain([100]).collect();
+        assert_eq!(combination_rank(200, &second), Some(1));
+    }
+}
@@ -0,0 +1,255 @@
+use crate::common::checked_binomial;
+use std::iter::FusedIterator;
+
+/// Lazily generates the combinations of `k` indices out of `0..n`, in lexicographic order.
+///
+/// Unlike `combination`, nothing is materialised up front: each call to `next` advances a single
+/// index buffer, so the combinations of a large collection can be walked in constant memory. Index
+/// the source slice with each combination to get the items, or use `Combinations::next_into` to
+/// write them into a reused buffer.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items in each combination.
+///
+/// # Returns
+/// * `Combinations` - An iterator over `Vec<usize>` index combinations. It yields one empty
+///   combination when `k` is 0, and nothing when `k > n`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::combinations;
+///
+/// let all: Vec<Vec<usize>> = combinations(4, 2).collect();
+/// assert_eq!(all, vec![
+///     vec![0, 1], vec![0, 2], vec![0, 3],
+///     vec![1, 2], vec![1, 3], vec![2, 3],
+/// ]);
+///
+/// // 60 choose 5 is counted without generating anything.
+/// let many = combinations(60, 5);
+/// assert_eq!(many.len(), Some(5_461_512));
+///
+/// let items = ["a", "b", "c"];
+/// let mut pairs = combinations(items.len(), 2);
+/// let mut buffer = Vec::new();
+/// let mut joined = Vec::new();
+/// while pairs.next_into(&items, &mut buffer) {
+///     joined.push(buffer.concat());
+/// }
+/// assert_eq!(joined, vec!["ab", "ac", "bc"]);
+/// ```
+pub fn combinations(n: usize, k: usize) -> Combinations {
+    Combinations::new(n, k)
+}
+
+/// Iterator returned by [`combinations`], yielding index combinations in lexicographic order.
+#[derive(Clone, Debug)]
+pub struct Combinations {
+    n: usize,
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+    remaining: Option<usize>,
+}
+
+impl Combinations {
+    /// Creates an iterator over the combinations of `k` indices out of `0..n`.
+    pub fn new(n: usize, k: usize) -> Self {
+        Combinations {
+            n,
+            indices: (0..k).collect(),
+            started: false,
+            done: k > n,
+            remaining: checked_binomial(n, k),
+        }
+    }
+
+    /// The number of combinations not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every combination has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.done
+    }
+
+    /// Advances to the next combination and returns its indices, borrowed from the iterator's
+    /// own buffer so that no allocation happens per combination.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.advance() {
+            return None;
+        }
+        Some(&self.indices)
+    }
+
+    /// Advances to the next combination and replaces the contents of `buffer` with the items of
+    /// `items` at its indices.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every combination has been yielded.
+    ///
+    /// # Panics
+    /// Panics if `items` is shorter than the `n` the iterator was created with.
+    pub fn next_into<T: Clone>(&mut self, items: &[T], buffer: &mut Vec<T>) -> bool {
+        if !self.advance() {
+            return false;
+        }
+        buffer.clear();
+        buffer.extend(self.indices.iter().map(|&index| items[index].clone()));
+        true
+    }
+
+    /// The indices of the combination yielded last.
+    pub(crate) fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// The number of indices in each combination.
+    pub(crate) fn k(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Moves `indices` to the next combination, returning `false` when there is none.
+    fn advance(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        if self.started {
+            let k = self.indices.len();
+            // The rightmost index that can still move right; every index after it restarts just
+            // behind it.
+            match (0..k).rev().find(|&i| self.indices[i] < self.n - k + i) {
+                Some(i) => {
+                    self.indices[i] += 1;
+                    for j in i + 1..k {
+                        self.indices[j] = self.indices[j - 1] + 1;
+                    }
+                }
+                None => {
+                    self.done = true;
+                    return false;
+                }
+            }
+        }
+        self.started = true;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        if self.remaining == Some(0) {
+            self.done = true;
+        }
+        true
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indices().map(<[usize]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for Combinations {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combination;
+
+    #[test]
+    fn test_combinations_order() {
+        let all: Vec<Vec<usize>> = combinations(5, 3).collect();
+        assert_eq!(all.len(), 10);
+        assert_eq!(all[0], vec![0, 1, 2]);
+        assert_eq!(all[1], vec![0, 1, 3]);
+        assert_eq!(all[9], vec![2, 3, 4]);
+        let mut sorted = all.clone();
+        sorted.sort();
+        assert_eq!(all, sorted);
+    }
+
+    #[test]
+    fn test_combinations_matches_combination() {
+        let items = vec!['a', 'b', 'c', 'd', 'e', 'f'];
+        for k in 0..=7 {
+            let lazy: Vec<Vec<char>> = combinations(items.len(), k)
+                .map(|indices| indices.iter().map(|&i| items[i]).collect())
+                .collect();
+            assert_eq!(lazy, combination(&items, k), "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_combinations_edge_cases() {
+        assert_eq!(
+            combinations(3, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(
+            combinations(0, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(combinations(2, 3).count(), 0);
+        assert_eq!(combinations(3, 3).collect::<Vec<_>>(), vec![vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn test_combinations_len_counts_down() {
+        let mut iter = combinations(6, 2);
+        assert_eq!(iter.len(), Some(15));
+        assert_eq!(iter.size_hint(), (15, Some(15)));
+        iter.next();
+        iter.next();
+        assert_eq!(iter.len(), Some(13));
+        assert_eq!(iter.by_ref().count(), 13);
+        assert_eq!(iter.len(), Some(0));
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+        assert_eq!(combinations(2, 3).len(), Some(0));
+    }
+
+    #[test]
+    fn test_combinations_len_overflow() {
+        let iter = combinations(200, 100);
+        assert_eq!(iter.len(), None);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_combinations_large_input_is_lazy() {
+        let mut iter = combinations(60, 5);
+        assert_eq!(iter.next(), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(iter.nth(54), Some(vec![0, 1, 2, 3, 60 - 1]));
+    }
+
+    #[test]
+    fn test_combinations_next_indices_and_into() {
+        let mut iter = combinations(3, 2);
+        assert_eq!(iter.next_indices(), Some(&[0, 1][..]));
+
+        let items = [10, 20, 30];
+        let mut buffer = vec![99; 5];
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec![10, 30]);
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec![20, 30]);
+        assert!(!iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec![20, 30]);
+        assert_eq!(iter.next_indices(), None);
+    }
+
+    #[test]
+    fn test_combinations_fused() {
+        let mut iter = combinations(2, 2);
+        assert_eq!(iter.next(), Some(vec![0, 1]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
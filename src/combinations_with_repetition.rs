@@ -0,0 +1,216 @@
+use crate::common::checked_multichoose;
+use std::iter::FusedIterator;
+
+/// Lazily generates the combinations of `k` indices out of `0..n` where an index may be chosen
+/// more than once, in lexicographic order.
+///
+/// Each combination is a multiset, given as a non-decreasing list of indices: with three flavours
+/// and two scoops, `[0, 0]` and `[0, 1]` are both yielded but `[1, 0]` is not.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items in each combination.
+///
+/// # Returns
+/// * `CombinationsWithRepetition` - An iterator over `Vec<usize>` index combinations. It yields
+///   one empty combination when `k` is 0, and nothing when `n` is 0 and `k` is not.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::combinations_with_repetition;
+///
+/// let all: Vec<Vec<usize>> = combinations_with_repetition(3, 2).collect();
+/// assert_eq!(all, vec![
+///     vec![0, 0], vec![0, 1], vec![0, 2],
+///     vec![1, 1], vec![1, 2], vec![2, 2],
+/// ]);
+///
+/// let flavours = ["vanilla", "chocolate"];
+/// let mut cones = combinations_with_repetition(flavours.len(), 2);
+/// let mut buffer = Vec::new();
+/// let mut orders = Vec::new();
+/// while cones.next_into(&flavours, &mut buffer) {
+///     orders.push(buffer.join("+"));
+/// }
+/// assert_eq!(orders, vec!["vanilla+vanilla", "vanilla+chocolate", "chocolate+chocolate"]);
+/// ```
+pub fn combinations_with_repetition(n: usize, k: usize) -> CombinationsWithRepetition {
+    CombinationsWithRepetition::new(n, k)
+}
+
+/// Iterator returned by [`combinations_with_repetition`], yielding non-decreasing index lists in
+/// lexicographic order.
+#[derive(Clone, Debug)]
+pub struct CombinationsWithRepetition {
+    n: usize,
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+    remaining: Option<usize>,
+}
+
+impl CombinationsWithRepetition {
+    /// Creates an iterator over the combinations with repetition of `k` indices out of `0..n`.
+    pub fn new(n: usize, k: usize) -> Self {
+        CombinationsWithRepetition {
+            n,
+            indices: vec![0; k],
+            started: false,
+            done: n == 0 && k > 0,
+            remaining: checked_multichoose(n, k),
+        }
+    }
+
+    /// The number of combinations not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every combination has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.done
+    }
+
+    /// Advances to the next combination and returns its indices, borrowed from the iterator's
+    /// own buffer so that no allocation happens per combination.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.advance() {
+            return None;
+        }
+        Some(&self.indices)
+    }
+
+    /// Advances to the next combination and replaces the contents of `buffer` with the items of
+    /// `items` at its indices.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every combination has been yielded.
+    ///
+    /// # Panics
+    /// Panics if `items` is shorter than the `n` the iterator was created with.
+    pub fn next_into<T: Clone>(&mut self, items: &[T], buffer: &mut Vec<T>) -> bool {
+        if !self.advance() {
+            return false;
+        }
+        buffer.clear();
+        buffer.extend(self.indices.iter().map(|&index| items[index].clone()));
+        true
+    }
+
+    /// Moves `indices` to the next combination, returning `false` when there is none.
+    fn advance(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        if self.started {
+            // The rightmost index below the largest one is bumped, and everything after it
+            // restarts at its new value to stay non-decreasing.
+            match self.indices.iter().rposition(|&index| index + 1 < self.n) {
+                Some(i) => {
+                    let value = self.indices[i] + 1;
+                    self.indices[i..].fill(value);
+                }
+                None => {
+                    self.done = true;
+                    return false;
+                }
+            }
+        }
+        self.started = true;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        if self.remaining == Some(0) {
+            self.done = true;
+        }
+        true
+    }
+}
+
+impl Iterator for CombinationsWithRepetition {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indices().map(<[usize]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for CombinationsWithRepetition {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combinations_with_repetition_order() {
+        let all: Vec<Vec<usize>> = combinations_with_repetition(2, 3).collect();
+        assert_eq!(
+            all,
+            vec![vec![0, 0, 0], vec![0, 0, 1], vec![0, 1, 1], vec![1, 1, 1]]
+        );
+    }
+
+    #[test]
+    fn test_combinations_with_repetition_are_multisets() {
+        let all: Vec<Vec<usize>> = combinations_with_repetition(4, 3).collect();
+        assert_eq!(all.len(), 20);
+        assert!(all
+            .iter()
+            .all(|indices| indices.windows(2).all(|pair| pair[0] <= pair[1])));
+        let mut sorted = all.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, all);
+    }
+
+    #[test]
+    fn test_combinations_with_repetition_edge_cases() {
+        assert_eq!(
+            combinations_with_repetition(3, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(
+            combinations_with_repetition(0, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(combinations_with_repetition(0, 2).count(), 0);
+        assert_eq!(
+            combinations_with_repetition(1, 3).collect::<Vec<_>>(),
+            vec![vec![0, 0, 0]]
+        );
+        assert_eq!(
+            combinations_with_repetition(2, 1).collect::<Vec<_>>(),
+            vec![vec![0], vec![1]]
+        );
+    }
+
+    #[test]
+    fn test_combinations_with_repetition_len() {
+        let mut iter = combinations_with_repetition(5, 3);
+        assert_eq!(iter.len(), Some(35));
+        assert_eq!(iter.size_hint(), (35, Some(35)));
+        iter.next();
+        assert_eq!(iter.len(), Some(34));
+        assert_eq!(iter.by_ref().count(), 34);
+        assert!(iter.is_empty());
+        assert_eq!(combinations_with_repetition(0, 2).len(), Some(0));
+        assert_eq!(combinations_with_repetition(100, 50).len(), None);
+    }
+
+    #[test]
+    fn test_combinations_with_repetition_next_into() {
+        let items = ["x", "y"];
+        let mut iter = combinations_with_repetition(2, 2);
+        let mut buffer = Vec::new();
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec!["x", "x"]);
+        assert_eq!(iter.next_indices(), Some(&[0, 1][..]));
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec!["y", "y"]);
+        assert!(!iter.next_into(&items, &mut buffer));
+    }
+}
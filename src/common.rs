@@ -152,6 +152,89 @@ pub fn ceil_log2(n: usize) -> usize {
     bits
 }
 
+/// Calculates the binomial coefficient C(n, k), the number of ways to choose `k` of `n` items.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items chosen.
+///
+/// # Returns
+/// * `Option<usize>` - The coefficient, `Some(0)` if `k > n`, or `None` if it overflows `usize`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::checked_binomial;
+///
+/// assert_eq!(checked_binomial(5, 2), Some(10));
+/// assert_eq!(checked_binomial(2, 5), Some(0));
+/// assert_eq!(checked_binomial(100, 50), None);
+/// ```
+pub fn checked_binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    // C(n, i) grows with i up to n / 2, so every partial product fits whenever the result does.
+    let mut result: u128 = 1;
+    for i in 0..k {
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        if result > usize::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as usize)
+}
+
+/// Calculates the factorial n!, the number of orderings of `n` items.
+///
+/// # Arguments
+/// * `n` - The number of items.
+///
+/// # Returns
+/// * `Option<usize>` - The factorial, or `None` if it overflows `usize`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::checked_factorial;
+///
+/// assert_eq!(checked_factorial(0), Some(1));
+/// assert_eq!(checked_factorial(5), Some(120));
+/// assert_eq!(checked_factorial(100), None);
+/// ```
+pub fn checked_factorial(n: usize) -> Option<usize> {
+    checked_permutations(n, n)
+}
+
+/// Calculates the number of ordered arrangements of `k` of `n` items, n! / (n - k)!.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items arranged.
+///
+/// # Returns
+/// * `Option<usize>` - The count, `Some(0)` if `k > n`, or `None` if it overflows `usize`.
+pub fn checked_permutations(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1usize, |count, factor| count.checked_mul(factor))
+}
+
+/// Calculates the number of multisets of size `k` drawn from `n` kinds of items, C(n + k - 1, k).
+///
+/// # Arguments
+/// * `n` - The number of kinds of items.
+/// * `k` - The size of each multiset.
+///
+/// # Returns
+/// * `Option<usize>` - The count, or `None` if it overflows `usize`.
+pub fn checked_multichoose(n: usize, k: usize) -> Option<usize> {
+    if n == 0 {
+        return Some(usize::from(k == 0));
+    }
+    checked_binomial(n.checked_add(k)? - 1, k)
+}
+
 /// Generates a pseudo-random `u64` number using entropy sources.
 ///
 /// Combines system time, process ID, and thread ID to generate randomness.
@@ -186,6 +269,195 @@ pub fn random_u64() -> u64 {
     mixed.wrapping_mul(6364136223846793005).wrapping_add(1)
 }
 
+/// A source of pseudo-random numbers used by the randomized utilities.
+///
+/// Implement this trait to plug a custom generator into the `_with_rng` family of functions
+/// (`shuffle_with_rng`, `sample_with_rng`, `samples_with_rng`, `random_string_with_rng`).
+/// Only `next_u64` is required; `next_usize` is derived from it.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::{Rng, SeededRng};
+///
+/// let mut rng = SeededRng::new(42);
+/// let index = rng.next_usize(10);
+/// assert!(index < 10);
+/// ```
+pub trait Rng {
+    /// Returns the next pseudo-random `u64` from the generator.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a pseudo-random index from 0 to `maximum - 1`.
+    ///
+    /// Returns 0 when `maximum` is 0, mirroring `random_usize`.
+    fn next_usize(&mut self, maximum: usize) -> usize {
+        if maximum == 0 {
+            return 0;
+        }
+
+        // Multiply-shift maps the 64 random bits onto the range without a division
+        ((self.next_u64() as u128 * maximum as u128) >> 64) as usize
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// A deterministic pseudo-random number generator based on xoshiro256**.
+///
+/// Two generators created with the same seed always produce the same sequence, on every
+/// platform and in every process, which makes it suitable for snapshot tests and replays.
+///
+/// **Note:** This generator is not suitable for cryptographic purposes.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::{Rng, SeededRng};
+///
+/// let mut a = SeededRng::new(7);
+/// let mut b = SeededRng::new(7);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededRng {
+    state: [u64; 4],
+}
+
+impl SeededRng {
+    /// Creates a new generator from a 64-bit seed.
+    ///
+    /// The seed is expanded into the full 256-bit state with SplitMix64, so any seed
+    /// (including 0) yields a valid generator.
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut state = [0u64; 4];
+        for slot in state.iter_mut() {
+            x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            *slot = z ^ (z >> 31);
+        }
+        SeededRng { state }
+    }
+}
+
+impl Rng for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+}
+
+/// A non-deterministic generator backed by `random_u64`.
+///
+/// This is the generator used by `shuffle`, `sample`, `samples` and `random_string`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntropyRng;
+
+impl Rng for EntropyRng {
+    fn next_u64(&mut self) -> u64 {
+        random_u64()
+    }
+}
+
+/// Addition that reports overflow instead of panicking or wrapping.
+///
+/// Implemented for the primitive integers through their inherent `checked_add`, and for `f32`
+/// and `f64`, where a sum that is no longer finite counts as an overflow.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::CheckedAdd;
+///
+/// assert_eq!(CheckedAdd::checked_add(250u8, 5), Some(255));
+/// assert_eq!(CheckedAdd::checked_add(250u8, 10), None);
+/// assert_eq!(CheckedAdd::checked_add(f64::MAX, f64::MAX), None);
+/// ```
+pub trait CheckedAdd: Sized {
+    /// Returns `self + rhs`, or `None` if the sum is not representable.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedAdd for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_add_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_checked_add_float {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedAdd for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    let sum = self + rhs;
+                    if sum.is_finite() {
+                        Some(sum)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_add_float!(f32, f64);
+
+/// Lossy conversion to `f64` for the primitive numeric types, as done by `as f64`.
+///
+/// The statistics functions take this instead of `Into<f64>`, which `i64`, `u64`, `isize`,
+/// `usize` and the 128-bit integers do not implement because the conversion can round. Values
+/// beyond 2^53 in magnitude are rounded to the nearest `f64`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::ToF64;
+///
+/// assert_eq!(3usize.to_f64(), 3.0);
+/// assert_eq!((-7i64).to_f64(), -7.0);
+/// assert_eq!(0.5f32.to_f64(), 0.5);
+/// ```
+pub trait ToF64: Copy {
+    /// Returns the value as an `f64`.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl ToF64 for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
 /// Lowercase letters charset.
 #[allow(dead_code)]
 pub const LOWERCASE_LETTERS_CHARSET: &[char] = &[
@@ -238,6 +510,13 @@ pub const ALL_CHARSET: &[char] = &[
     '[', ']', '{', '}', '|', ';', '\'', ':', '"', ',', '.', '/', '<', '>', '?',
 ];
 
+/// Words that `title_case` keeps in lowercase unless they start or end the title: English
+/// articles, coordinating conjunctions and short prepositions.
+pub const TITLE_CASE_SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "in", "nor", "of", "on", "or", "per", "the",
+    "to", "via", "vs",
+];
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -518,4 +797,122 @@ mod tests {
             let _ = rand_val;
         }
     }
+
+    #[test]
+    fn test_seeded_rng_is_deterministic() {
+        let mut a = SeededRng::new(42);
+        let mut b = SeededRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_seeded_rng_different_seeds() {
+        let mut a = SeededRng::new(1);
+        let mut b = SeededRng::new(2);
+        let first: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..10).map(|_| b.next_u64()).collect();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_seeded_rng_zero_seed() {
+        let mut rng = SeededRng::new(0);
+        let values: HashSet<u64> = (0..100).map(|_| rng.next_u64()).collect();
+        assert_eq!(values.len(), 100);
+    }
+
+    #[test]
+    fn test_seeded_rng_known_sequence() {
+        let mut rng = SeededRng::new(12345);
+        let first = rng.next_u64();
+        let second = rng.next_u64();
+        let mut replay = SeededRng::new(12345);
+        assert_eq!(replay.next_u64(), first);
+        assert_eq!(replay.next_u64(), second);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_rng_next_usize_range() {
+        let mut rng = SeededRng::new(99);
+        let mut seen = HashSet::new();
+        for _ in 0..1000 {
+            let index = rng.next_usize(10);
+            assert!(index < 10, "next_usize(10) returned {}", index);
+            seen.insert(index);
+        }
+        assert_eq!(seen.len(), 10);
+    }
+
+    #[test]
+    fn test_rng_next_usize_zero() {
+        let mut rng = SeededRng::new(99);
+        assert_eq!(rng.next_usize(0), 0);
+    }
+
+    #[test]
+    fn test_rng_through_mutable_reference() {
+        let mut rng = SeededRng::new(5);
+        let mut replay = SeededRng::new(5);
+
+        fn draw<R: Rng>(mut rng: R) -> u64 {
+            rng.next_u64()
+        }
+
+        assert_eq!(draw(&mut rng), replay.next_u64());
+        assert_eq!(rng.next_u64(), replay.next_u64());
+    }
+
+    #[test]
+    fn test_entropy_rng_range() {
+        let mut rng = EntropyRng;
+        for _ in 0..1000 {
+            assert!(rng.next_usize(50) < 50);
+        }
+    }
+
+    #[test]
+    fn test_checked_binomial() {
+        assert_eq!(checked_binomial(5, 0), Some(1));
+        assert_eq!(checked_binomial(5, 2), Some(10));
+        assert_eq!(checked_binomial(5, 5), Some(1));
+        assert_eq!(checked_binomial(3, 4), Some(0));
+        assert_eq!(checked_binomial(60, 5), Some(5_461_512));
+        assert_eq!(checked_binomial(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(checked_binomial(68, 34), None);
+    }
+
+    #[test]
+    fn test_checked_factorial() {
+        assert_eq!(checked_factorial(0), Some(1));
+        assert_eq!(checked_factorial(1), Some(1));
+        assert_eq!(checked_factorial(10), Some(3_628_800));
+        assert_eq!(checked_factorial(20), Some(2_432_902_008_176_640_000));
+        assert_eq!(checked_factorial(21), None);
+    }
+
+    #[test]
+    fn test_checked_permutations() {
+        assert_eq!(checked_permutations(5, 0), Some(1));
+        assert_eq!(checked_permutations(5, 2), Some(20));
+        assert_eq!(checked_permutations(5, 5), Some(120));
+        assert_eq!(checked_permutations(3, 4), Some(0));
+        assert_eq!(
+            checked_permutations(20, 20),
+            Some(2_432_902_008_176_640_000)
+        );
+        assert_eq!(checked_permutations(21, 21), None);
+    }
+
+    #[test]
+    fn test_checked_multichoose() {
+        assert_eq!(checked_multichoose(3, 2), Some(6));
+        assert_eq!(checked_multichoose(1, 10), Some(1));
+        assert_eq!(checked_multichoose(5, 0), Some(1));
+        assert_eq!(checked_multichoose(0, 0), Some(1));
+        assert_eq!(checked_multichoose(0, 3), Some(0));
+        assert_eq!(checked_multichoose(usize::MAX, 2), None);
+    }
 }
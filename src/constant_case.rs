@@ -0,0 +1,77 @@
+use crate::{constant_case_with, WordOptions};
+
+/// Converts a string to CONSTANT_CASE.
+///
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then converts each word to uppercase and joins them with underscores.
+/// Use [`constant_case_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in CONSTANT_CASE
+///
+/// # Examples
+/// ```rust
+/// use lowdash::constant_case;
+///
+/// assert_eq!(constant_case("hello world"), "HELLO_WORLD");
+/// assert_eq!(constant_case("fooBarBaz"), "FOO_BAR_BAZ");
+/// assert_eq!(constant_case("XMLHttpRequest"), "XML_HTTP_REQUEST");
+/// ```
+pub fn constant_case(str_input: &str) -> String {
+    constant_case_with(str_input, &WordOptions::for_case())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_space_separated() {
+        assert_eq!(constant_case("hello world"), "HELLO_WORLD");
+    }
+
+    #[test]
+    fn test_mixed_separators() {
+        assert_eq!(constant_case("foo-bar_baz hello"), "FOO_BAR_BAZ_HELLO");
+    }
+
+    #[test]
+    fn test_camel_and_pascal_case() {
+        assert_eq!(constant_case("fooBarBaz"), "FOO_BAR_BAZ");
+        assert_eq!(constant_case("FooBarBaz"), "FOO_BAR_BAZ");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        assert_eq!(constant_case("XMLHttpRequest"), "XML_HTTP_REQUEST");
+    }
+
+    #[test]
+    fn test_already_constant_case() {
+        assert_eq!(constant_case("HELLO_WORLD"), "HELLO_WORLD");
+    }
+
+    #[test]
+    fn test_with_numbers() {
+        assert_eq!(constant_case("version2"), "VERSION_2");
+    }
+
+    #[test]
+    fn test_apostrophes_removed() {
+        assert_eq!(constant_case("don't stop"), "DONT_STOP");
+    }
+
+    #[test]
+    fn test_unicode_characters() {
+        assert_eq!(constant_case("hello_世界"), "HELLO_世界");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(constant_case(""), "");
+        assert_eq!(constant_case("  --  "), "");
+    }
+}
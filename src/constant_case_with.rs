@@ -0,0 +1,59 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to CONSTANT_CASE, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then converts each word to
+/// uppercase and joins them with underscores.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in CONSTANT_CASE
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{constant_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(constant_case_with("HTTP2Server", &WordOptions::default()), "HTTP_2_SERVER");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(constant_case_with("HTTP2Server", &attached), "HTTP2_SERVER");
+/// ```
+pub fn constant_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Constant, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(
+            constant_case_with("HTTP2Server", &WordOptions::default()),
+            "HTTP_2_SERVER"
+        );
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(constant_case_with("HTTP2Server", &options), "HTTP2_SERVER");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(constant_case_with("", &WordOptions::default()), "");
+        assert_eq!(constant_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
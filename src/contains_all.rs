@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Checks whether a collection contains every one of the given values.
+/// Returns `true` when `values` is empty.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `values` - The values to look for.
+///
+/// # Returns
+/// * `bool` - `true` if every value is in the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::contains_all;
+///
+/// assert!(contains_all(&[1, 2, 3, 4], &[4, 2]));
+/// assert!(!contains_all(&[1, 2, 3, 4], &[4, 5]));
+/// assert!(contains_all(&[1, 2], &[]));
+/// ```
+pub fn contains_all<T>(collection: &[T], values: &[T]) -> bool
+where
+    T: Eq + Hash,
+{
+    let collection: HashSet<&T> = collection.iter().collect();
+    values.iter().all(|value| collection.contains(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_all() {
+        assert!(contains_all(&["a", "b", "c"], &["c", "a", "a"]));
+        assert!(!contains_all(&["a", "b", "c"], &["a", "d"]));
+    }
+
+    #[test]
+    fn test_contains_all_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(contains_all(&empty, &empty));
+        assert!(contains_all(&[1], &empty));
+        assert!(!contains_all(&empty, &[1]));
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Checks whether every one of the given values has its key, generated by the iteratee function,
+/// among the keys of a collection.
+/// Returns `true` when `values` is empty.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `values` - The values to look for.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `bool` - `true` if the key of every value is a key of the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::contains_all_by;
+///
+/// let tags = ["Rust", "WebAssembly", "CLI"];
+/// assert!(contains_all_by(&tags, &["rust", "cli"], |tag| tag.to_lowercase()));
+/// assert!(!contains_all_by(&tags, &["rust", "go"], |tag| tag.to_lowercase()));
+/// ```
+pub fn contains_all_by<T, U, F>(collection: &[T], values: &[T], iteratee: F) -> bool
+where
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let collection: HashSet<U> = collection.iter().map(&iteratee).collect();
+    values
+        .iter()
+        .all(|value| collection.contains(&iteratee(value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_all_by() {
+        assert!(contains_all_by(&[1, 2], &[3, 4, 5], |x| x % 2));
+        assert!(!contains_all_by(&[2, 4], &[3], |x| x % 2));
+    }
+
+    #[test]
+    fn test_contains_all_by_empty_values() {
+        let empty: Vec<i32> = vec![];
+        assert!(contains_all_by(&empty, &empty, |x| *x));
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Checks whether a collection contains at least one of the given values.
+/// Returns `false` when `values` is empty.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `values` - The values to look for.
+///
+/// # Returns
+/// * `bool` - `true` if any value is in the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::contains_any;
+///
+/// assert!(contains_any(&[1, 2, 3], &[5, 3]));
+/// assert!(!contains_any(&[1, 2, 3], &[4, 5]));
+/// assert!(!contains_any(&[1, 2, 3], &[]));
+/// ```
+pub fn contains_any<T>(collection: &[T], values: &[T]) -> bool
+where
+    T: Eq + Hash,
+{
+    let values: HashSet<&T> = values.iter().collect();
+    collection.iter().any(|item| values.contains(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_any() {
+        assert!(contains_any(&["a", "b"], &["x", "y", "b"]));
+        assert!(!contains_any(&["a", "b"], &["x", "y"]));
+    }
+
+    #[test]
+    fn test_contains_any_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(!contains_any(&empty, &empty));
+        assert!(!contains_any(&empty, &[1]));
+        assert!(!contains_any(&[1], &empty));
+    }
+}
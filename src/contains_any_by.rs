@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Checks whether at least one of the given values has its key, generated by the iteratee
+/// function, among the keys of a collection.
+/// Returns `false` when `values` is empty.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `values` - The values to look for.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `bool` - `true` if the key of any value is a key of the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::contains_any_by;
+///
+/// let extensions = ["PNG", "JPG"];
+/// assert!(contains_any_by(&extensions, &["gif", "jpg"], |ext| ext.to_lowercase()));
+/// assert!(!contains_any_by(&extensions, &["gif"], |ext| ext.to_lowercase()));
+/// ```
+pub fn contains_any_by<T, U, F>(collection: &[T], values: &[T], iteratee: F) -> bool
+where
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let values: HashSet<U> = values.iter().map(&iteratee).collect();
+    collection
+        .iter()
+        .any(|item| values.contains(&iteratee(item)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_any_by() {
+        assert!(contains_any_by(&[2, 4, 7], &[1], |x| x % 2));
+        assert!(!contains_any_by(&[2, 4, 6], &[1, 3], |x| x % 2));
+    }
+
+    #[test]
+    fn test_contains_any_by_empty_values() {
+        let empty: Vec<i32> = vec![];
+        assert!(!contains_any_by(&[1, 2], &empty, |x| *x));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    camel_case, constant_case, dot_case, kebab_case, pascal_case, path_case, sentence_case,
+    snake_case, start_case, title_case, train_case,
+};
+
+/// A target case for [`convert_case`] and [`convert_case_with`](crate::convert_case_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `camelCase`, see [`camel_case`].
+    Camel,
+    /// `PascalCase`, see [`pascal_case`].
+    Pascal,
+    /// `snake_case`, see [`snake_case`].
+    Snake,
+    /// `kebab-case`, see [`kebab_case`].
+    Kebab,
+    /// `CONSTANT_CASE`, see [`constant_case`].
+    Constant,
+    /// `Title Case`, see [`title_case`].
+    Title,
+    /// `Sentence case`, see [`sentence_case`].
+    Sentence,
+    /// `dot.case`, see [`dot_case`].
+    Dot,
+    /// `path/case`, see [`path_case`].
+    Path,
+    /// `Train-Case`, see [`train_case`].
+    Train,
+    /// `Start Case`, see [`start_case`].
+    Start,
+}
+
+/// Converts a string to the case chosen at runtime.
+///
+/// Dispatches to the conversion function of the given [`Case`], so the result is the same as
+/// calling that function directly.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `case` - The target case
+///
+/// # Returns
+/// * `String` - The converted string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{convert_case, Case};
+///
+/// assert_eq!(convert_case("hello world", Case::Constant), "HELLO_WORLD");
+/// assert_eq!(convert_case("hello world", Case::Train), "Hello-World");
+/// assert_eq!(convert_case("helloWorld", Case::Path), "hello/world");
+/// ```
+pub fn convert_case(str_input: &str, case: Case) -> String {
+    match case {
+        Case::Camel => camel_case(str_input),
+        Case::Pascal => pascal_case(str_input),
+        Case::Snake => snake_case(str_input),
+        Case::Kebab => kebab_case(str_input),
+        Case::Constant => constant_case(str_input),
+        Case::Title => title_case(str_input),
+        Case::Sentence => sentence_case(str_input),
+        Case::Dot => dot_case(str_input),
+        Case::Path => path_case(str_input),
+        Case::Train => train_case(str_input),
+        Case::Start => start_case(str_input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_cases() {
+        let input = "the quick_brownFox";
+        let cases = [
+            (Case::Camel, "theQuickBrownFox"),
+            (Case::Pascal, "TheQuickBrownFox"),
+            (Case::Snake, "the_quick_brown_fox"),
+            (Case::Kebab, "the-quick-brown-fox"),
+            (Case::Constant, "THE_QUICK_BROWN_FOX"),
+            (Case::Title, "The Quick Brown Fox"),
+            (Case::Sentence, "The quick brown fox"),
+            (Case::Dot, "the.quick.brown.fox"),
+            (Case::Path, "the/quick/brown/fox"),
+            (Case::Train, "The-Quick-Brown-Fox"),
+            (Case::Start, "The Quick Brown Fox"),
+        ];
+        for (case, expected) in cases {
+            assert_eq!(convert_case(input, case), expected, "case: {:?}", case);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_between_cases() {
+        let cases = [
+            Case::Camel,
+            Case::Pascal,
+            Case::Snake,
+            Case::Kebab,
+            Case::Constant,
+            Case::Dot,
+            Case::Path,
+            Case::Train,
+        ];
+        for from in cases {
+            let converted = convert_case("parse http response", from);
+            assert_eq!(
+                convert_case(&converted, Case::Snake),
+                "parse_http_response",
+                "from: {:?}",
+                from
+            );
+        }
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(convert_case("", Case::Title), "");
+        assert_eq!(convert_case("", Case::Constant), "");
+    }
+}
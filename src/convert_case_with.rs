@@ -0,0 +1,94 @@
+use crate::common::TITLE_CASE_SMALL_WORDS;
+use crate::{
+    camel_case_with, constant_case_with, dot_case_with, kebab_case_with, pascal_case_with,
+    path_case_with, sentence_case_with, snake_case_with, start_case_with, title_case_with,
+    train_case_with, Case, WordOptions,
+};
+
+/// Converts a string to the case chosen at runtime, splitting it into words with the given
+/// [`WordOptions`].
+///
+/// Dispatches to the `_with` conversion function of the given [`Case`]. Title case keeps the
+/// words of [`TITLE_CASE_SMALL_WORDS`] in lowercase.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `case` - The target case
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{convert_case_with, Case, DigitGrouping, WordOptions};
+///
+/// let options = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(convert_case_with("HTTP2Server", Case::Constant, &options), "HTTP2_SERVER");
+/// assert_eq!(convert_case_with("HTTP2Server", Case::Dot, &options), "http2.server");
+/// ```
+pub fn convert_case_with(str_input: &str, case: Case, options: &WordOptions) -> String {
+    match case {
+        Case::Camel => camel_case_with(str_input, options),
+        Case::Pascal => pascal_case_with(str_input, options),
+        Case::Snake => snake_case_with(str_input, options),
+        Case::Kebab => kebab_case_with(str_input, options),
+        Case::Constant => constant_case_with(str_input, options),
+        Case::Title => title_case_with(str_input, options, TITLE_CASE_SMALL_WORDS),
+        Case::Sentence => sentence_case_with(str_input, options),
+        Case::Dot => dot_case_with(str_input, options),
+        Case::Path => path_case_with(str_input, options),
+        Case::Train => train_case_with(str_input, options),
+        Case::Start => start_case_with(str_input, options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_all_cases_with_attached_digits() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        let input = "the HTTP2Server of doom";
+        let cases = [
+            (Case::Camel, "theHttp2ServerOfDoom"),
+            (Case::Pascal, "TheHttp2ServerOfDoom"),
+            (Case::Snake, "the_http2_server_of_doom"),
+            (Case::Kebab, "the-http2-server-of-doom"),
+            (Case::Constant, "THE_HTTP2_SERVER_OF_DOOM"),
+            (Case::Title, "The Http2 Server of Doom"),
+            (Case::Sentence, "The http2 server of doom"),
+            (Case::Dot, "the.http2.server.of.doom"),
+            (Case::Path, "the/http2/server/of/doom"),
+            (Case::Train, "The-Http2-Server-Of-Doom"),
+            (Case::Start, "The HTTP2 Server Of Doom"),
+        ];
+        for (case, expected) in cases {
+            assert_eq!(
+                convert_case_with(input, case, &options),
+                expected,
+                "case: {:?}",
+                case
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_convert_case_for_case_options() {
+        let options = WordOptions::for_case();
+        for case in [Case::Camel, Case::Snake, Case::Constant, Case::Start] {
+            assert_eq!(
+                convert_case_with("don't parseHTTPResponse", case, &options),
+                crate::convert_case("don't parseHTTPResponse", case)
+            );
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+/// Computes the Damerau–Levenshtein distance between two strings.
+///
+/// Like [`levenshtein`](crate::levenshtein), but swapping two adjacent characters counts as a
+/// single edit. This is the unrestricted distance, so a transposed pair can still be edited
+/// further: `"ca"` becomes `"abc"` in two edits. Characters are compared as `char`s.
+///
+/// # Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// # Returns
+/// * `usize` - The edit distance between `a` and `b`
+///
+/// # Examples
+/// ```rust
+/// use lowdash::damerau_levenshtein;
+///
+/// assert_eq!(damerau_levenshtein("teh", "the"), 1);
+/// assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+/// assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    if a.is_empty() || b.is_empty() {
+        return a.len() + b.len();
+    }
+
+    // The table has an extra leading row and column holding an upper bound, so transpositions
+    // that reach before the start of either string are never chosen.
+    let max = a.len() + b.len();
+    let width = b.len() + 2;
+    let mut d = vec![0; (a.len() + 2) * width];
+    d[0] = max;
+    for i in 0..=a.len() {
+        d[(i + 1) * width] = max;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b.len() {
+        d[j + 1] = max;
+        d[width + j + 1] = j;
+    }
+
+    // The last row in which each character of `a` was seen.
+    let mut last_row: HashMap<char, usize> = HashMap::new();
+
+    for i in 1..=a.len() {
+        let mut last_match_column = 0;
+        for j in 1..=b.len() {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_column = j;
+                0
+            } else {
+                1
+            };
+
+            let substitution = d[i * width + j] + cost;
+            let insertion = d[(i + 1) * width + j] + 1;
+            let deletion = d[i * width + j + 1] + 1;
+            let transposition = d[k * width + l] + (i - k - 1) + 1 + (j - l - 1);
+            d[(i + 1) * width + j + 1] =
+                substitution.min(insertion).min(deletion).min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+
+    d[(a.len() + 1) * width + b.len() + 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    #[test]
+    fn test_adjacent_transposition() {
+        assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+        assert_eq!(damerau_levenshtein("teh", "the"), 1);
+        assert_eq!(damerau_levenshtein("abcdef", "abdcef"), 1);
+    }
+
+    #[test]
+    fn test_edit_after_transposition() {
+        assert_eq!(damerau_levenshtein("ca", "abc"), 2);
+    }
+
+    #[test]
+    fn test_without_transpositions_matches_levenshtein() {
+        let pairs = [
+            ("kitten", "sitting"),
+            ("flaw", "lawn"),
+            ("saturday", "sunday"),
+            ("", "abc"),
+            ("abc", ""),
+            ("same", "same"),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(damerau_levenshtein(a, b), levenshtein(a, b), "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_symmetric() {
+        assert_eq!(damerau_levenshtein("abc", "ca"), 2);
+        assert_eq!(damerau_levenshtein("recieve", "receive"), 1);
+        assert_eq!(damerau_levenshtein("receive", "recieve"), 1);
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_eq!(damerau_levenshtein("éa", "aé"), 1);
+        assert_eq!(damerau_levenshtein("世界", "界世"), 1);
+    }
+}
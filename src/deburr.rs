@@ -0,0 +1,136 @@
+/// Replacements for U+00C0 to U+00FF. The multiplication and division signs are not letters and
+/// are kept.
+const LATIN_1_SUPPLEMENT: [&str; 64] = [
+    "A", "A", "A", "A", "A", "A", "Ae", "C", "E", "E", "E", "E", "I", "I", "I", "I", //
+    "D", "N", "O", "O", "O", "O", "O", "\u{d7}", "O", "U", "U", "U", "U", "Y", "Th", "ss", //
+    "a", "a", "a", "a", "a", "a", "ae", "c", "e", "e", "e", "e", "i", "i", "i", "i", //
+    "d", "n", "o", "o", "o", "o", "o", "\u{f7}", "o", "u", "u", "u", "u", "y", "th", "y",
+];
+
+/// Replacements for U+0100 to U+017F.
+const LATIN_EXTENDED_A: [&str; 128] = [
+    "A", "a", "A", "a", "A", "a", "C", "c", "C", "c", "C", "c", "C", "c", "D", "d", //
+    "D", "d", "E", "e", "E", "e", "E", "e", "E", "e", "E", "e", "G", "g", "G", "g", //
+    "G", "g", "G", "g", "H", "h", "H", "h", "I", "i", "I", "i", "I", "i", "I", "i", //
+    "I", "i", "IJ", "ij", "J", "j", "K", "k", "k", "L", "l", "L", "l", "L", "l", "L", //
+    "l", "L", "l", "N", "n", "N", "n", "N", "n", "'n", "N", "n", "O", "o", "O", "o", //
+    "O", "o", "Oe", "oe", "R", "r", "R", "r", "R", "r", "S", "s", "S", "s", "S", "s", //
+    "S", "s", "T", "t", "T", "t", "T", "t", "U", "u", "U", "u", "U", "u", "U", "u", //
+    "U", "u", "U", "u", "W", "w", "Y", "y", "Y", "Z", "z", "Z", "z", "Z", "z", "s",
+];
+
+/// Removes diacritics from Latin letters, like lodash's `deburr`.
+///
+/// Letters of the Latin-1 Supplement and Latin Extended-A blocks are replaced with basic Latin
+/// letters, spelling out ligatures and special letters (`"Æ"` becomes `"Ae"`, `"ß"` becomes
+/// `"ss"`), and combining diacritical marks are removed. Everything else is kept, so the result is
+/// ASCII only if the input is Latin text; see [`fold_ascii`](crate::fold_ascii) for a wider
+/// transliteration.
+///
+/// # Arguments
+/// * `str_input` - The string to deburr
+///
+/// # Returns
+/// * `String` - The string without diacritics
+///
+/// # Examples
+/// ```rust
+/// use lowdash::deburr;
+///
+/// assert_eq!(deburr("déjà vu"), "deja vu");
+/// assert_eq!(deburr("Crème Brûlée"), "Creme Brulee");
+/// assert_eq!(deburr("Straße Łódź"), "Strasse Lodz");
+/// assert_eq!(deburr("e\u{301}"), "e");
+/// ```
+pub fn deburr(str_input: &str) -> String {
+    let mut result = String::with_capacity(str_input.len());
+    for c in str_input.chars() {
+        match deburr_char(c) {
+            Some(replacement) => result.push_str(replacement),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+/// The replacement of a Latin letter or combining mark, or `None` if `c` is kept as is.
+pub(crate) fn deburr_char(c: char) -> Option<&'static str> {
+    match c {
+        '\u{c0}'..='\u{ff}' if c != '\u{d7}' && c != '\u{f7}' => {
+            Some(LATIN_1_SUPPLEMENT[c as usize - 0xc0])
+        }
+        '\u{100}'..='\u{17f}' => Some(LATIN_EXTENDED_A[c as usize - 0x100]),
+        _ if is_combining_mark(c) => Some(""),
+        _ => None,
+    }
+}
+
+/// Combining diacritical marks, half marks and marks for symbols.
+fn is_combining_mark(c: char) -> bool {
+    matches!(c, '\u{300}'..='\u{36f}' | '\u{fe20}'..='\u{fe2f}' | '\u{20d0}'..='\u{20ff}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The `burredLetters` and `deburredLetters` fixtures of lodash's test suite.
+    const DEBURRED_LETTERS: [&str; 190] = [
+        "A", "A", "A", "A", "A", "A", "Ae", "C", "E", "E", "E", "E", "I", "I", "I", "I", "D", "N",
+        "O", "O", "O", "O", "O", "O", "U", "U", "U", "U", "Y", "Th", "ss", "a", "a", "a", "a", "a",
+        "a", "ae", "c", "e", "e", "e", "e", "i", "i", "i", "i", "d", "n", "o", "o", "o", "o", "o",
+        "o", "u", "u", "u", "u", "y", "th", "y", "A", "a", "A", "a", "A", "a", "C", "c", "C", "c",
+        "C", "c", "C", "c", "D", "d", "D", "d", "E", "e", "E", "e", "E", "e", "E", "e", "E", "e",
+        "G", "g", "G", "g", "G", "g", "G", "g", "H", "h", "H", "h", "I", "i", "I", "i", "I", "i",
+        "I", "i", "I", "i", "IJ", "ij", "J", "j", "K", "k", "k", "L", "l", "L", "l", "L", "l", "L",
+        "l", "L", "l", "N", "n", "N", "n", "N", "n", "'n", "N", "n", "O", "o", "O", "o", "O", "o",
+        "Oe", "oe", "R", "r", "R", "r", "R", "r", "S", "s", "S", "s", "S", "s", "S", "s", "T", "t",
+        "T", "t", "T", "t", "U", "u", "U", "u", "U", "u", "U", "u", "U", "u", "U", "u", "W", "w",
+        "Y", "y", "Y", "Z", "z", "Z", "z", "Z", "z", "s",
+    ];
+
+    fn burred_letters() -> Vec<char> {
+        ('\u{c0}'..='\u{17f}')
+            .filter(|&c| c != '\u{d7}' && c != '\u{f7}')
+            .collect()
+    }
+
+    #[test]
+    fn test_lodash_burred_letters() {
+        let letters = burred_letters();
+        assert_eq!(letters.len(), DEBURRED_LETTERS.len());
+        for (letter, expected) in letters.iter().zip(DEBURRED_LETTERS) {
+            assert_eq!(deburr(&letter.to_string()), expected, "{:?}", letter);
+        }
+    }
+
+    #[test]
+    fn test_lodash_mathematical_operators_are_kept() {
+        assert_eq!(deburr("\u{d7}\u{f7}"), "\u{d7}\u{f7}");
+    }
+
+    #[test]
+    fn test_lodash_combining_marks_are_removed() {
+        let marks = ('\u{300}'..='\u{36f}')
+            .chain('\u{fe20}'..='\u{fe2f}')
+            .chain('\u{20d0}'..='\u{20ff}');
+        for mark in marks {
+            assert_eq!(deburr(&format!("ei{}", mark)), "ei", "{:?}", mark);
+        }
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(deburr("déjà vu"), "deja vu");
+        assert_eq!(deburr("Ångström"), "Angstrom");
+        assert_eq!(deburr("Œuvre"), "Oeuvre");
+    }
+
+    #[test]
+    fn test_other_characters_are_kept() {
+        assert_eq!(deburr("hello, world!"), "hello, world!");
+        assert_eq!(deburr("こんにちは"), "こんにちは");
+        assert_eq!(deburr("Ǎ ệ Ж"), "Ǎ ệ Ж");
+        assert_eq!(deburr(""), "");
+    }
+}
@@ -0,0 +1,183 @@
+use crate::Permutations;
+use std::iter::FusedIterator;
+
+/// Lazily generates the derangements of the indices `0..n`: the permutations that leave no index
+/// in its own position, in lexicographic order.
+///
+/// About 1/e of all permutations are derangements, so stepping through the permutations and
+/// skipping the others costs a small constant factor per derangement.
+///
+/// # Arguments
+/// * `n` - The number of items to rearrange.
+///
+/// # Returns
+/// * `Derangements` - An iterator over `Vec<usize>` index arrangements. It yields one empty
+///   arrangement when `n` is 0, and nothing when `n` is 1.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::derangements;
+///
+/// let all: Vec<Vec<usize>> = derangements(3).collect();
+/// assert_eq!(all, vec![vec![1, 2, 0], vec![2, 0, 1]]);
+///
+/// assert_eq!(derangements(4).len(), Some(9));
+///
+/// // Secret-santa assignments where nobody draws their own name.
+/// let names = ["Ann", "Ben", "Cat"];
+/// let mut draws = derangements(names.len());
+/// let mut buffer = Vec::new();
+/// assert!(draws.next_into(&names, &mut buffer));
+/// assert_eq!(buffer, vec!["Ben", "Cat", "Ann"]);
+/// ```
+pub fn derangements(n: usize) -> Derangements {
+    Derangements::new(n)
+}
+
+/// Iterator returned by [`derangements`], yielding fixed-point-free permutations in
+/// lexicographic order.
+#[derive(Clone, Debug)]
+pub struct Derangements {
+    permutations: Permutations,
+    remaining: Option<usize>,
+}
+
+impl Derangements {
+    /// Creates an iterator over the derangements of `0..n`.
+    pub fn new(n: usize) -> Self {
+        Derangements {
+            permutations: Permutations::new(n, n),
+            remaining: checked_subfactorial(n),
+        }
+    }
+
+    /// The number of derangements not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every derangement has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.remaining == Some(0) || self.permutations.is_empty()
+    }
+
+    /// Advances to the next derangement and returns its indices, borrowed from the iterator's own
+    /// buffer so that no allocation happens per derangement.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        loop {
+            let indices = self.permutations.next_indices()?;
+            if indices.iter().enumerate().all(|(i, &index)| i != index) {
+                break;
+            }
+        }
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        Some(self.permutations.indices())
+    }
+
+    /// Advances to the next derangement and replaces the contents of `buffer` with the items of
+    /// `items` at its indices.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every derangement has been yielded.
+    ///
+    /// # Panics
+    /// Panics if `items` is shorter than the `n` the iterator was created with.
+    pub fn next_into<T: Clone>(&mut self, items: &[T], buffer: &mut Vec<T>) -> bool {
+        match self.next_indices() {
+            Some(indices) => {
+                buffer.clear();
+                buffer.extend(indices.iter().map(|&index| items[index].clone()));
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The number of derangements of `n` items, !n = (n - 1)(!(n - 1) + !(n - 2)), or `None` if it
+/// overflows `usize`.
+fn checked_subfactorial(n: usize) -> Option<usize> {
+    let (mut previous, mut current) = (1usize, 0usize);
+    if n == 0 {
+        return Some(previous);
+    }
+    for i in 2..=n {
+        let next = (i - 1).checked_mul(previous.checked_add(current)?)?;
+        previous = current;
+        current = next;
+    }
+    Some(current)
+}
+
+impl Iterator for Derangements {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indices().map(<[usize]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for Derangements {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derangements_have_no_fixed_points() {
+        let all: Vec<Vec<usize>> = derangements(5).collect();
+        assert_eq!(all.len(), 44);
+        for arrangement in &all {
+            assert!(arrangement.iter().enumerate().all(|(i, &index)| i != index));
+            let mut sorted = arrangement.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![0, 1, 2, 3, 4]);
+        }
+        let mut ordered = all.clone();
+        ordered.sort();
+        assert_eq!(all, ordered);
+    }
+
+    #[test]
+    fn test_derangements_small() {
+        assert_eq!(
+            derangements(0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(derangements(1).count(), 0);
+        assert_eq!(derangements(2).collect::<Vec<_>>(), vec![vec![1, 0]]);
+    }
+
+    #[test]
+    fn test_derangements_len() {
+        let mut iter = derangements(4);
+        assert_eq!(iter.len(), Some(9));
+        assert_eq!(iter.size_hint(), (9, Some(9)));
+        iter.next();
+        assert_eq!(iter.len(), Some(8));
+        assert_eq!(iter.by_ref().count(), 8);
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+        assert_eq!(derangements(1).len(), Some(0));
+        assert!(derangements(1).is_empty());
+    }
+
+    #[test]
+    fn test_checked_subfactorial() {
+        let expected = [1, 0, 1, 2, 9, 44, 265, 1854, 14833];
+        for (n, &count) in expected.iter().enumerate() {
+            assert_eq!(checked_subfactorial(n), Some(count), "n = {}", n);
+        }
+        assert_eq!(checked_subfactorial(20), Some(895_014_631_192_902_121));
+        assert_eq!(checked_subfactorial(21), None);
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements of a collection that are not in another collection.
+/// The result has no duplicates and keeps the order in which elements first appear in `collection`.
+/// Use `without` to remove values while keeping duplicates.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - The items to remove.
+///
+/// # Returns
+/// * `Vec<T>` - The distinct items of `collection` that are not in `other`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::difference;
+///
+/// let result = difference(&[2, 1, 2, 3], &[3, 4]);
+/// assert_eq!(result, vec![2, 1]);
+/// ```
+pub fn difference<T>(collection: &[T], other: &[T]) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    let other: HashSet<&T> = other.iter().collect();
+    let mut seen = HashSet::new();
+
+    collection
+        .iter()
+        .filter(|item| !other.contains(item) && seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(difference(&[1, 2, 3, 4, 5], &[2, 4]), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn test_difference_removes_duplicates() {
+        assert_eq!(difference(&[1, 1, 2, 3, 3], &[2]), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_difference_nothing_removed() {
+        assert_eq!(difference(&[3, 2, 1], &[]), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_difference_everything_removed() {
+        assert!(difference(&[1, 2], &[2, 1, 0]).is_empty());
+    }
+
+    #[test]
+    fn test_difference_is_not_symmetric() {
+        assert_eq!(difference(&[1, 2], &[2, 3]), vec![1]);
+        assert_eq!(difference(&[2, 3], &[1, 2]), vec![3]);
+    }
+}
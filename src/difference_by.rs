@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements of a collection whose key, generated by the iteratee function, is not the
+/// key of any element of another collection.
+/// Only the first element with each key is kept, in the order of `collection`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - The items to remove.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `Vec<T>` - The items of `collection` whose keys are not in `other`, one per key.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::difference_by;
+///
+/// let result = difference_by(&[2.1, 1.2], &[2.3, 3.4], |x: &f64| x.floor() as i64);
+/// assert_eq!(result, vec![1.2]);
+/// ```
+pub fn difference_by<T, U, F>(collection: &[T], other: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let other: HashSet<U> = other.iter().map(&iteratee).collect();
+    let mut seen = HashSet::new();
+
+    collection
+        .iter()
+        .filter(|item| {
+            let key = iteratee(item);
+            !other.contains(&key) && seen.insert(key)
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct File {
+        path: &'static str,
+        size: u64,
+    }
+
+    #[test]
+    fn test_difference_by_field() {
+        let before = vec![
+            File {
+                path: "a.txt",
+                size: 1,
+            },
+            File {
+                path: "b.txt",
+                size: 2,
+            },
+        ];
+        let after = vec![File {
+            path: "b.txt",
+            size: 3,
+        }];
+        assert_eq!(
+            difference_by(&before, &after, |file| file.path),
+            vec![File {
+                path: "a.txt",
+                size: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn test_difference_by_keeps_first_per_key() {
+        assert_eq!(difference_by(&[1, 3, 2, 4], &[0], |x| x % 2), vec![1]);
+    }
+
+    #[test]
+    fn test_difference_by_empty_other() {
+        let empty: Vec<&str> = vec![];
+        assert_eq!(
+            difference_by(&["a", "B", "b"], &empty, |s| s.to_lowercase()),
+            vec!["a", "B"]
+        );
+    }
+}
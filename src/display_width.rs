@@ -0,0 +1,74 @@
+use crate::unicode::{grapheme_width, graphemes};
+
+/// Returns the number of terminal columns a string takes when printed.
+///
+/// The string is measured one grapheme cluster at a time, so combining marks add nothing to the
+/// character they modify and an emoji sequence joined with zero width joiners counts once. East
+/// Asian wide and fullwidth characters and emoji take two columns; control characters take none.
+///
+/// # Arguments
+/// * `str_input` - The string to measure
+///
+/// # Returns
+/// * `usize` - The display width of the string in columns
+///
+/// # Examples
+/// ```rust
+/// use lowdash::display_width;
+///
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("こんにちは"), 10);
+/// assert_eq!(display_width("cafe\u{301}"), 4);
+/// assert_eq!(display_width("👨\u{200D}👩\u{200D}👧"), 2);
+/// ```
+pub fn display_width(str_input: &str) -> usize {
+    graphemes(str_input).map(grapheme_width).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("hello world"), 11);
+    }
+
+    #[test]
+    fn test_wide_characters() {
+        assert_eq!(display_width("世界"), 4);
+        assert_eq!(display_width("hello世界"), 9);
+        assert_eq!(display_width("ＡＢＣ"), 6);
+        assert_eq!(display_width("한국어"), 6);
+    }
+
+    #[test]
+    fn test_combining_marks() {
+        assert_eq!(display_width("cafe\u{301}"), 4);
+        assert_eq!(display_width("a\u{308}\u{304}"), 1);
+    }
+
+    #[test]
+    fn test_emoji() {
+        assert_eq!(display_width("😀"), 2);
+        assert_eq!(display_width("👍🏽"), 2);
+        assert_eq!(display_width("🇯🇵"), 2);
+        assert_eq!(display_width("❤\u{FE0F}"), 2);
+        assert_eq!(display_width("👩\u{200D}💻 code"), 7);
+    }
+
+    #[test]
+    fn test_control_and_format_characters() {
+        assert_eq!(display_width("a\u{200B}b"), 2);
+        assert_eq!(display_width("\u{1b}"), 0);
+        assert_eq!(display_width("line\r\n"), 4);
+    }
+
+    #[test]
+    fn test_differs_from_char_count() {
+        let text = "e\u{301}世😀";
+        assert_eq!(text.chars().count(), 4);
+        assert_eq!(display_width(text), 5);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{dot_case_with, WordOptions};
+
+/// Converts a string to dot.case.
+///
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then converts each word to lowercase and joins them with dots. Use
+/// [`dot_case_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in dot.case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::dot_case;
+///
+/// assert_eq!(dot_case("hello world"), "hello.world");
+/// assert_eq!(dot_case("fooBarBaz"), "foo.bar.baz");
+/// assert_eq!(dot_case("XMLHttpRequest"), "xml.http.request");
+/// ```
+pub fn dot_case(str_input: &str) -> String {
+    dot_case_with(str_input, &WordOptions::for_case())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_space_separated() {
+        assert_eq!(dot_case("hello world"), "hello.world");
+    }
+
+    #[test]
+    fn test_mixed_separators() {
+        assert_eq!(dot_case("foo-bar_baz hello"), "foo.bar.baz.hello");
+    }
+
+    #[test]
+    fn test_camel_and_pascal_case() {
+        assert_eq!(dot_case("fooBarBaz"), "foo.bar.baz");
+        assert_eq!(dot_case("FooBarBaz"), "foo.bar.baz");
+    }
+
+    #[test]
+    fn test_leading_and_trailing_separators() {
+        assert_eq!(dot_case("--foo--bar--"), "foo.bar");
+    }
+
+    #[test]
+    fn test_already_dot_case() {
+        assert_eq!(dot_case("hello.world"), "hello.world");
+    }
+
+    #[test]
+    fn test_with_numbers() {
+        assert_eq!(dot_case("version2"), "version.2");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(dot_case(""), "");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to dot.case, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then converts each word to
+/// lowercase and joins them with dots.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in dot.case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{dot_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(dot_case_with("HTTP2Server", &WordOptions::default()), "http.2.server");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(dot_case_with("HTTP2Server", &attached), "http2.server");
+/// ```
+pub fn dot_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Dot, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(
+            dot_case_with("HTTP2Server", &WordOptions::default()),
+            "http.2.server"
+        );
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(dot_case_with("HTTP2Server", &options), "http2.server");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(dot_case_with("", &WordOptions::default()), "");
+        assert_eq!(dot_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
@@ -55,10 +55,10 @@ impl DurationUnit {
 /// assert_eq!(duration_between(epoch, day_later, DurationUnit::Days), 1);
 /// ```
 pub fn duration_between(date1: SystemTime, date2: SystemTime, unit: DurationUnit) -> u64 {
-    let duration = if date1 > date2 {
-        date1.duration_since(date2).expect("Time went backwards")
-    } else {
-        date2.duration_since(date1).expect("Time went backwards")
+    // When date2 is later, the error carries the positive difference, so this never panics
+    let duration = match date1.duration_since(date2) {
+        Ok(duration) => duration,
+        Err(error) => error.duration(),
     };
     duration.as_secs() / unit.seconds_per_unit()
 }
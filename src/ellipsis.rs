@@ -6,6 +6,9 @@
 /// `length`, it returns the trimmed string as is. If either the trimmed string or the
 /// specified `length` is less than 3, it returns `"..."`.
 ///
+/// Lengths are counted in `char`s. Use [`truncate`](crate::truncate) to measure terminal columns
+/// and avoid cutting through grapheme clusters.
+///
 /// # Arguments
 ///
 /// * `s` - The input string to potentially truncate.
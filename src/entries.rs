@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use crate::MapLike;
 
 /// Represents a key-value pair entry in a map.
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -42,9 +42,10 @@ pub struct Entry<K, V> {
 ///
 /// assert_eq!(sorted_result, sorted_expected);
 /// ```
-pub fn entries<K, V>(map: &HashMap<K, V>) -> Vec<Entry<K, V>>
+pub fn entries<M, K, V>(map: &M) -> Vec<Entry<K, V>>
 where
-    K: Clone + std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
 {
     map.iter()
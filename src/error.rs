@@ -0,0 +1,100 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error type returned by the fallible (`try_`) functions of the crate.
+///
+/// Every variant records the name of the function that produced it, so the message reads the
+/// same way as the panics of the non-fallible counterparts.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{try_chunk, LowdashError};
+///
+/// let result = try_chunk(&[1, 2, 3], 0);
+/// assert_eq!(
+///     result,
+///     Err(LowdashError::InvalidArgument {
+///         function: "chunk",
+///         reason: "Size parameter must be greater than 0",
+///     })
+/// );
+/// assert_eq!(
+///     result.unwrap_err().to_string(),
+///     "chunk: Size parameter must be greater than 0"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LowdashError {
+    /// An index fell outside the bounds of the collection.
+    IndexOutOfBounds {
+        /// The function that produced the error.
+        function: &'static str,
+        /// The offending index.
+        index: i64,
+    },
+    /// The function needs at least one element but the collection was empty.
+    EmptyCollection {
+        /// The function that produced the error.
+        function: &'static str,
+    },
+    /// An argument was outside the range the function accepts.
+    InvalidArgument {
+        /// The function that produced the error.
+        function: &'static str,
+        /// Why the argument was rejected.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for LowdashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LowdashError::IndexOutOfBounds { function, index } => {
+                write!(f, "{}: {} out of slice bounds", function, index)
+            }
+            LowdashError::EmptyCollection { function } => {
+                write!(f, "{}: Collection must not be empty", function)
+            }
+            LowdashError::InvalidArgument { function, reason } => {
+                write!(f, "{}: {}", function, reason)
+            }
+        }
+    }
+}
+
+impl Error for LowdashError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_index_out_of_bounds() {
+        let error = LowdashError::IndexOutOfBounds {
+            function: "nth",
+            index: -3,
+        };
+        assert_eq!(error.to_string(), "nth: -3 out of slice bounds");
+    }
+
+    #[test]
+    fn test_display_empty_collection() {
+        let error = LowdashError::EmptyCollection { function: "latest" };
+        assert_eq!(error.to_string(), "latest: Collection must not be empty");
+    }
+
+    #[test]
+    fn test_display_invalid_argument() {
+        let error = LowdashError::InvalidArgument {
+            function: "clamp",
+            reason: "Min must not be greater than max",
+        };
+        assert_eq!(error.to_string(), "clamp: Min must not be greater than max");
+    }
+
+    #[test]
+    fn test_is_std_error() {
+        let error: Box<dyn Error> = Box::new(LowdashError::EmptyCollection { function: "sample" });
+        assert_eq!(error.to_string(), "sample: Collection must not be empty");
+    }
+}
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+/// Escapes the characters that have a special meaning in HTML, like lodash's `escape`.
+///
+/// `&`, `<`, `>`, `"` and `'` are replaced with `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&#39;`,
+/// which makes the result safe in element content and in quoted attribute values. The input is
+/// borrowed when it contains none of them.
+///
+/// # Arguments
+/// * `str_input` - The string to escape
+///
+/// # Returns
+/// * `Cow<str>` - The escaped string, borrowed from the input when nothing needs escaping
+///
+/// # Examples
+/// ```rust
+/// use lowdash::escape_html;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(
+///     escape_html("<a href=\"/?q=1&r=2\">Tom's</a>"),
+///     "&lt;a href=&quot;/?q=1&amp;r=2&quot;&gt;Tom&#39;s&lt;/a&gt;"
+/// );
+/// assert!(matches!(escape_html("plain text"), Cow::Borrowed("plain text")));
+/// ```
+pub fn escape_html(str_input: &str) -> Cow<'_, str> {
+    let Some(first) = str_input.find(['&', '<', '>', '"', '\'']) else {
+        return Cow::Borrowed(str_input);
+    };
+
+    let mut result = String::with_capacity(str_input.len() + 16);
+    result.push_str(&str_input[..first]);
+    for c in str_input[first..].chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    Cow::Owned(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lodash_escape() {
+        assert_eq!(escape_html("&<>\"'/"), "&amp;&lt;&gt;&quot;&#39;/");
+        assert_eq!(
+            escape_html("fred, barney, & pebbles"),
+            "fred, barney, &amp; pebbles"
+        );
+    }
+
+    #[test]
+    fn test_escapes_existing_entities_again() {
+        assert_eq!(escape_html("&amp;"), "&amp;amp;");
+    }
+
+    #[test]
+    fn test_borrows_when_nothing_to_escape() {
+        assert!(matches!(escape_html("abc"), Cow::Borrowed("abc")));
+        assert!(matches!(escape_html(""), Cow::Borrowed("")));
+        assert!(matches!(escape_html("café 😀"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_keeps_unicode() {
+        assert_eq!(escape_html("<é>"), "&lt;é&gt;");
+        assert_eq!(escape_html("日本 & 中国"), "日本 &amp; 中国");
+    }
+}
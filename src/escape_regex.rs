@@ -0,0 +1,74 @@
+use std::borrow::Cow;
+
+/// Escapes the regular expression special characters in a string, like lodash's `escapeRegExp`.
+///
+/// `^`, `$`, `\`, `.`, `*`, `+`, `?`, `(`, `)`, `[`, `]`, `{`, `}` and `|` are prefixed with a
+/// backslash, so the result matches the input literally. The input is borrowed when it contains
+/// none of them.
+///
+/// # Arguments
+/// * `str_input` - The string to escape
+///
+/// # Returns
+/// * `Cow<str>` - The escaped string, borrowed from the input when nothing needs escaping
+///
+/// # Examples
+/// ```rust
+/// use lowdash::escape_regex;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(escape_regex("[lodash](https://lodash.com/)"), "\\[lodash\\]\\(https://lodash\\.com/\\)");
+/// assert_eq!(escape_regex("1+1=2?"), "1\\+1=2\\?");
+/// assert!(matches!(escape_regex("plain text"), Cow::Borrowed("plain text")));
+/// ```
+pub fn escape_regex(str_input: &str) -> Cow<'_, str> {
+    let Some(first) = str_input.find(is_special) else {
+        return Cow::Borrowed(str_input);
+    };
+
+    let mut result = String::with_capacity(str_input.len() + 8);
+    result.push_str(&str_input[..first]);
+    for c in str_input[first..].chars() {
+        if is_special(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    Cow::Owned(result)
+}
+
+fn is_special(c: char) -> bool {
+    matches!(
+        c,
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lodash_escape_reg_exp() {
+        assert_eq!(
+            escape_regex("^$.*+?()[]{}|\\"),
+            "\\^\\$\\.\\*\\+\\?\\(\\)\\[\\]\\{\\}\\|\\\\"
+        );
+        assert_eq!(
+            escape_regex("[lodash](https://lodash.com/)"),
+            "\\[lodash\\]\\(https://lodash\\.com/\\)"
+        );
+    }
+
+    #[test]
+    fn test_other_characters_are_kept() {
+        assert_eq!(escape_regex("a-b_c/d#e&f~g"), "a-b_c/d#e&f~g");
+        assert_eq!(escape_regex("café.日本"), "café\\.日本");
+    }
+
+    #[test]
+    fn test_borrows_when_nothing_to_escape() {
+        assert!(matches!(escape_regex("abc"), Cow::Borrowed("abc")));
+        assert!(matches!(escape_regex(""), Cow::Borrowed("")));
+    }
+}
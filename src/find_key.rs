@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Find the key in a map that corresponds to a given value.
 /// If no key corresponds to the value, return None.
 ///
@@ -46,12 +48,13 @@
 /// let result = find_key(&map, "Manager");
 /// assert_eq!(result, Some(&Person { name: "Bob".to_string(), age: 30 }));
 /// ```
-pub fn find_key<K, V>(object: &std::collections::HashMap<K, V>, value: V) -> Option<&K>
+pub fn find_key<'a, M, K, V>(object: &'a M, value: V) -> Option<&'a K>
 where
-    K: std::cmp::Eq + std::hash::Hash,
-    V: std::cmp::PartialEq,
+    M: MapLike<Key = K, Value = V>,
+    K: 'a,
+    V: 'a + std::cmp::PartialEq,
 {
-    for (k, v) in object {
+    for (k, v) in object.iter() {
         if *v == value {
             return Some(k);
         }
@@ -134,4 +137,13 @@ mod tests {
         let not_found = find_key(&map, "Intern");
         assert_eq!(not_found, None);
     }
+
+    #[test]
+    fn test_find_key_entry_vec() {
+        use crate::Entry;
+
+        let list = vec![Entry { key: "a", value: 1 }, Entry { key: "b", value: 2 }];
+        assert_eq!(find_key(&list, 2), Some(&"b"));
+        assert_eq!(find_key(&list, 3), None);
+    }
 }
@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Find the key in a map that satisfies a predicate based on both key and value.
 /// If no key satisfies the predicate, return None.
 ///
@@ -28,12 +30,14 @@
 /// let result = find_key_by(&map, |_, v| *v > 2);
 /// assert_eq!(result, Some(&"c"));
 /// ```
-pub fn find_key_by<K, V, F>(object: &std::collections::HashMap<K, V>, predicate: F) -> Option<&K>
+pub fn find_key_by<'a, M, K, V, F>(object: &'a M, predicate: F) -> Option<&'a K>
 where
-    K: std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K, Value = V>,
+    K: 'a,
+    V: 'a,
     F: Fn(&K, &V) -> bool,
 {
-    for (k, v) in object {
+    for (k, v) in object.iter() {
         if predicate(k, v) {
             return Some(k);
         }
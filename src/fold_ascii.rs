@@ -0,0 +1,400 @@
+use crate::deburr::deburr_char;
+
+/// Base letters of U+1E00 to U+1E95, one per uppercase and lowercase pair.
+const LATIN_EXTENDED_ADDITIONAL: &[u8; 75] =
+    b"ABBBCDDDDDEEEEEFGHHHHHIIKKKLLLLMMMNNNNOOOOPPRRRRSSSSSTTTTUUUUUVVWWWWWXXYZZZ";
+
+/// Base letters of the Vietnamese letters U+1EA0 to U+1EF9, one per uppercase and lowercase pair.
+const VIETNAMESE: &[u8; 45] = b"AAAAAAAAAAAAEEEEEEEEIIOOOOOOOOOOOOUUUUUUUYYYY";
+
+/// Transliterates a string to ASCII where a conventional ASCII spelling exists.
+///
+/// Extends [`deburr`](crate::deburr) with:
+/// * the other accented Latin letters, including Latin Extended-B and the Vietnamese letters
+///   (`"ǎ"`, `"ș"`, `"ệ"`);
+/// * Greek and Cyrillic letters (`"Ωμέγα"` becomes `"Omega"`, `"Жук"` becomes `"Zhuk"`);
+/// * typographic punctuation and spaces (`"“…”"` becomes `"\"...\""`), ligatures, fullwidth
+///   forms, superscript and subscript digits, and a few symbols such as `"©"` and `"½"`.
+///
+/// Characters without an ASCII spelling, such as CJK ideographs or emoji, are kept.
+///
+/// # Arguments
+/// * `str_input` - The string to transliterate
+///
+/// # Returns
+/// * `String` - The transliterated string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::fold_ascii;
+///
+/// assert_eq!(fold_ascii("Crème Brûlée"), "Creme Brulee");
+/// assert_eq!(fold_ascii("Tiếng Việt"), "Tieng Viet");
+/// assert_eq!(fold_ascii("“Smart” quotes — and ellipses…"), "\"Smart\" quotes - and ellipses...");
+/// assert_eq!(fold_ascii("Москва"), "Moskva");
+/// assert_eq!(fold_ascii("ﬁnance ＡＢＣ x²"), "finance ABC x2");
+/// assert_eq!(fold_ascii("東京"), "東京");
+/// ```
+pub fn fold_ascii(str_input: &str) -> String {
+    let mut result = String::with_capacity(str_input.len());
+    for c in str_input.chars() {
+        if c.is_ascii() {
+            result.push(c);
+        } else if let Some(replacement) = deburr_char(c).or_else(|| fold_char(c)) {
+            result.push_str(replacement);
+        } else if let Some(folded) = fold_computed(c) {
+            result.push(folded);
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Characters folded to a single ASCII character by their position in a block.
+fn fold_computed(c: char) -> Option<char> {
+    let code = c as u32;
+    let pair_letter = |letters: &[u8], first: u32| {
+        let offset = (code - first) as usize;
+        let letter = letters[offset / 2] as char;
+        if offset.is_multiple_of(2) {
+            letter
+        } else {
+            letter.to_ascii_lowercase()
+        }
+    };
+
+    match code {
+        0x1e00..=0x1e95 => Some(pair_letter(LATIN_EXTENDED_ADDITIONAL, 0x1e00)),
+        0x1ea0..=0x1ef9 => Some(pair_letter(VIETNAMESE, 0x1ea0)),
+        // Fullwidth forms of the printable ASCII characters.
+        0xff01..=0xff5e => char::from_u32(code - 0xfee0),
+        0x2070 | 0x2080 => Some('0'),
+        0x2074..=0x2079 => char::from_u32(code - 0x2070 + '0' as u32),
+        0x2081..=0x2089 => char::from_u32(code - 0x2080 + '0' as u32),
+        _ => None,
+    }
+}
+
+/// The transliteration table for everything not covered by `deburr` or `fold_computed`.
+fn fold_char(c: char) -> Option<&'static str> {
+    let folded = match c {
+        // Latin Extended-B.
+        'ƀ' => "b",
+        'Ɓ' | 'Ƃ' => "B",
+        'ƃ' => "b",
+        'Ƈ' => "C",
+        'ƈ' => "c",
+        'Ɖ' | 'Ɗ' | 'Ƌ' => "D",
+        'ƌ' => "d",
+        'Ƒ' => "F",
+        'ƒ' => "f",
+        'Ɠ' => "G",
+        'Ɨ' => "I",
+        'Ƙ' => "K",
+        'ƙ' => "k",
+        'ƚ' => "l",
+        'Ɲ' => "N",
+        'ƞ' => "n",
+        'Ơ' | 'Ǒ' | 'Ǫ' | 'Ǭ' | 'Ǿ' | 'Ȍ' | 'Ȏ' | 'Ȫ' | 'Ȭ' | 'Ȯ' | 'Ȱ' => "O",
+        'ơ' | 'ǒ' | 'ǫ' | 'ǭ' | 'ǿ' | 'ȍ' | 'ȏ' | 'ȫ' | 'ȭ' | 'ȯ' | 'ȱ' => "o",
+        'Ƥ' => "P",
+        'ƥ' => "p",
+        'Ƭ' | 'Ʈ' | 'Ț' => "T",
+        'ƫ' | 'ƭ' | 'ț' => "t",
+        'Ư' | 'Ǔ' | 'Ǖ' | 'Ǘ' | 'Ǚ' | 'Ǜ' | 'Ȕ' | 'Ȗ' => "U",
+        'ư' | 'ǔ' | 'ǖ' | 'ǘ' | 'ǚ' | 'ǜ' | 'ȕ' | 'ȗ' => "u",
+        'Ʋ' => "V",
+        'Ƴ' | 'Ȳ' => "Y",
+        'ƴ' | 'ȳ' => "y",
+        'Ƶ' | 'Ȥ' => "Z",
+        'ƶ' | 'ȥ' => "z",
+        'Ǆ' | 'Ǳ' => "DZ",
+        'ǅ' | 'ǲ' => "Dz",
+        'ǆ' | 'ǳ' => "dz",
+        'Ǉ' => "LJ",
+        'ǈ' => "Lj",
+        'ǉ' => "lj",
+        'Ǌ' => "NJ",
+        'ǋ' => "Nj",
+        'ǌ' => "nj",
+        'Ǎ' | 'Ǟ' | 'Ǡ' | 'Ǻ' | 'Ȁ' | 'Ȃ' | 'Ȧ' => "A",
+        'ǎ' | 'ǟ' | 'ǡ' | 'ǻ' | 'ȁ' | 'ȃ' | 'ȧ' => "a",
+        'Ǣ' | 'Ǽ' => "Ae",
+        'ǣ' | 'ǽ' => "ae",
+        'Ǐ' | 'Ȉ' | 'Ȋ' => "I",
+        'ǐ' | 'ȉ' | 'ȋ' => "i",
+        'Ǥ' | 'Ǧ' | 'Ǵ' => "G",
+        'ǥ' | 'ǧ' | 'ǵ' => "g",
+        'Ǩ' => "K",
+        'ǩ' => "k",
+        'ǰ' | 'ȷ' => "j",
+        'Ǹ' => "N",
+        'ǹ' => "n",
+        'Ȅ' | 'Ȇ' | 'Ȩ' => "E",
+        'ȅ' | 'ȇ' | 'ȩ' => "e",
+        'Ȑ' | 'Ȓ' => "R",
+        'ȑ' | 'ȓ' => "r",
+        'Ș' => "S",
+        'ș' => "s",
+        'Ȟ' => "H",
+        'ȟ' => "h",
+        'ȡ' => "d",
+        'ȴ' => "l",
+        'ȵ' => "n",
+        'ȶ' => "t",
+        // Latin Extended Additional letters outside the pairs of `fold_computed`.
+        'ẖ' => "h",
+        'ẗ' => "t",
+        'ẘ' => "w",
+        'ẙ' => "y",
+        'ẚ' => "a",
+        'ẛ' => "s",
+        'ẞ' => "SS",
+        // Greek.
+        'Α' | 'Ά' => "A",
+        'α' | 'ά' => "a",
+        'Β' => "B",
+        'β' => "b",
+        'Γ' => "G",
+        'γ' => "g",
+        'Δ' => "D",
+        'δ' => "d",
+        'Ε' | 'Έ' => "E",
+        'ε' | 'έ' => "e",
+        'Ζ' => "Z",
+        'ζ' => "z",
+        'Η' | 'Ή' | 'Ι' | 'Ί' | 'Ϊ' => "I",
+        'η' | 'ή' | 'ι' | 'ί' | 'ϊ' | 'ΐ' => "i",
+        'Θ' => "Th",
+        'θ' => "th",
+        'Κ' => "K",
+        'κ' => "k",
+        'Λ' => "L",
+        'λ' => "l",
+        'Μ' => "M",
+        'μ' | 'µ' => "m",
+        'Ν' => "N",
+        'ν' => "n",
+        'Ξ' => "X",
+        'ξ' => "x",
+        'Ο' | 'Ό' | 'Ω' | 'Ώ' => "O",
+        'ο' | 'ό' | 'ω' | 'ώ' => "o",
+        'Π' => "P",
+        'π' => "p",
+        'Ρ' => "R",
+        'ρ' => "r",
+        'Σ' => "S",
+        'σ' | 'ς' => "s",
+        'Τ' => "T",
+        'τ' => "t",
+        'Υ' | 'Ύ' | 'Ϋ' => "Y",
+        'υ' | 'ύ' | 'ϋ' | 'ΰ' => "y",
+        'Φ' => "F",
+        'φ' => "f",
+        'Χ' => "Ch",
+        'χ' => "ch",
+        'Ψ' => "Ps",
+        'ψ' => "ps",
+        // Cyrillic.
+        'А' => "A",
+        'а' => "a",
+        'Б' => "B",
+        'б' => "b",
+        'В' => "V",
+        'в' => "v",
+        'Г' | 'Ґ' => "G",
+        'г' | 'ґ' => "g",
+        'Д' => "D",
+        'д' => "d",
+        'Е' | 'Э' => "E",
+        'е' | 'э' => "e",
+        'Ё' => "Yo",
+        'ё' => "yo",
+        'Є' => "Ye",
+        'є' => "ye",
+        'Ж' => "Zh",
+        'ж' => "zh",
+        'З' => "Z",
+        'з' => "z",
+        'И' | 'І' => "I",
+        'и' | 'і' => "i",
+        'Ї' => "Yi",
+        'ї' => "yi",
+        'Й' | 'Ы' => "Y",
+        'й' | 'ы' => "y",
+        'К' => "K",
+        'к' => "k",
+        'Л' => "L",
+        'л' => "l",
+        'М' => "M",
+        'м' => "m",
+        'Н' => "N",
+        'н' => "n",
+        'О' => "O",
+        'о' => "o",
+        'П' => "P",
+        'п' => "p",
+        'Р' => "R",
+        'р' => "r",
+        'С' => "S",
+        'с' => "s",
+        'Т' => "T",
+        'т' => "t",
+        'У' => "U",
+        'у' => "u",
+        'Ф' => "F",
+        'ф' => "f",
+        'Х' => "Kh",
+        'х' => "kh",
+        'Ц' => "Ts",
+        'ц' => "ts",
+        'Ч' => "Ch",
+        'ч' => "ch",
+        'Ш' => "Sh",
+        'ш' => "sh",
+        'Щ' => "Shch",
+        'щ' => "shch",
+        'Ъ' | 'ъ' | 'Ь' | 'ь' => "",
+        'Ю' => "Yu",
+        'ю' => "yu",
+        'Я' => "Ya",
+        'я' => "ya",
+        // Punctuation and spaces.
+        '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{201b}' | '\u{2032}' => "'",
+        '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{201f}' | '\u{2033}' | '«' | '»' => "\"",
+        '\u{2039}' => "<",
+        '\u{203a}' => ">",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+        '\u{2026}' => "...",
+        '\u{2044}' => "/",
+        '\u{a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => " ",
+        // Symbols.
+        '©' => "(C)",
+        '®' => "(R)",
+        '™' => "TM",
+        '×' => "x",
+        '¹' => "1",
+        '²' => "2",
+        '³' => "3",
+        '¼' => "1/4",
+        '½' => "1/2",
+        '¾' => "3/4",
+        // Ligatures.
+        'ﬀ' => "ff",
+        'ﬁ' => "fi",
+        'ﬂ' => "fl",
+        'ﬃ' => "ffi",
+        'ﬄ' => "ffl",
+        'ﬅ' | 'ﬆ' => "st",
+        _ => return None,
+    };
+    Some(folded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deburr;
+
+    #[test]
+    fn test_extends_deburr() {
+        for c in ('\u{c0}'..='\u{17f}').filter(|&c| c != '\u{d7}' && c != '\u{f7}') {
+            assert_eq!(
+                fold_ascii(&c.to_string()),
+                deburr(&c.to_string()),
+                "{:?}",
+                c
+            );
+        }
+        assert_eq!(fold_ascii("e\u{301}\u{20d7}"), "e");
+    }
+
+    #[test]
+    fn test_latin_extended_additional_pairs() {
+        let letters = ('\u{1e00}'..='\u{1e9b}').chain('\u{1ea0}'..='\u{1ef9}');
+        for c in letters {
+            let folded = fold_ascii(&c.to_string());
+            assert!(
+                !folded.is_empty() && folded.chars().all(|f| f.is_ascii_alphabetic()),
+                "{:?} -> {:?}",
+                c,
+                folded
+            );
+            assert_eq!(
+                folded.chars().all(|f| f.is_ascii_uppercase()),
+                c.is_uppercase(),
+                "{:?}",
+                c
+            );
+        }
+    }
+
+    #[test]
+    fn test_vietnamese() {
+        assert_eq!(fold_ascii("Tiếng Việt có dấu"), "Tieng Viet co dau");
+        assert_eq!(fold_ascii("Phở Hà Nội"), "Pho Ha Noi");
+        assert_eq!(fold_ascii("ỲỳỸỹ"), "YyYy");
+    }
+
+    #[test]
+    fn test_latin_extended_additional() {
+        assert_eq!(fold_ascii("Ḁḁ Ḃḃ Ṩṩ Ẕẕ ẞ"), "Aa Bb Ss Zz SS");
+        assert_eq!(fold_ascii("Muḥammad ibn Mūsā"), "Muhammad ibn Musa");
+    }
+
+    #[test]
+    fn test_latin_extended_b() {
+        assert_eq!(fold_ascii("Ștefan Țiriac"), "Stefan Tiriac");
+        assert_eq!(fold_ascii("ǅemal"), "Dzemal");
+        assert_eq!(fold_ascii("Ǎǎ Ǒǒ Ǔǔ"), "Aa Oo Uu");
+    }
+
+    #[test]
+    fn test_greek() {
+        assert_eq!(fold_ascii("Ωμέγα"), "Omega");
+        assert_eq!(fold_ascii("Αθήνα"), "Athina");
+        assert_eq!(fold_ascii("ΧΑΟΣ"), "ChAOS");
+    }
+
+    #[test]
+    fn test_cyrillic() {
+        assert_eq!(fold_ascii("Москва"), "Moskva");
+        assert_eq!(fold_ascii("Жук щука"), "Zhuk shchuka");
+        assert_eq!(fold_ascii("Київ"), "Kiyiv");
+        assert_eq!(fold_ascii("объект"), "obekt");
+    }
+
+    #[test]
+    fn test_punctuation() {
+        assert_eq!(
+            fold_ascii("‘single’ “double” «guillemets»"),
+            "'single' \"double\" \"guillemets\""
+        );
+        assert_eq!(fold_ascii("a–b—c…"), "a-b-c...");
+        assert_eq!(fold_ascii("10\u{a0}km\u{2009}h"), "10 km h");
+    }
+
+    #[test]
+    fn test_symbols_and_digits() {
+        assert_eq!(fold_ascii("© 2024 ™ ½"), "(C) 2024 TM 1/2");
+        assert_eq!(
+            fold_ascii("x² H₂O ⁰¹²³⁴⁵⁶⁷⁸⁹ ₀₁₂₃₄₅₆₇₈₉"),
+            "x2 H2O 0123456789 0123456789"
+        );
+        assert_eq!(fold_ascii("2×3"), "2x3");
+    }
+
+    #[test]
+    fn test_ligatures_and_fullwidth() {
+        assert_eq!(fold_ascii("ﬁﬂﬀﬃﬄﬆ"), "fiflffffifflst");
+        assert_eq!(fold_ascii("ＡＢＣ１２３！～"), "ABC123!~");
+    }
+
+    #[test]
+    fn test_unknown_characters_are_kept() {
+        assert_eq!(fold_ascii("東京 😀"), "東京 😀");
+        assert_eq!(fold_ascii("plain ascii"), "plain ascii");
+        assert_eq!(fold_ascii(""), "");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::common::ToF64;
+use crate::moments::compensated_sum;
+
+/// Calculates the geometric mean of a collection of non-negative numbers: the n-th root of the
+/// product of the values.
+/// The mean is computed from the logarithms of the values, so large collections do not overflow.
+/// A zero in the collection makes the geometric mean zero.
+///
+/// # Arguments
+/// * `collection` - A slice of non-negative numbers.
+///
+/// # Returns
+/// * `Option<f64>` - The geometric mean, or None if the collection is empty or contains a negative
+///   value or NaN.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::geometric_mean;
+///
+/// let growth = vec![1.1, 1.2, 0.9];
+/// let result = geometric_mean(&growth).unwrap();
+/// assert!((result - 1.0591).abs() < 1e-4);
+///
+/// assert!((geometric_mean(&[2, 8]).unwrap() - 4.0).abs() < 1e-12);
+/// assert_eq!(geometric_mean(&[-1, 4]), None);
+/// ```
+pub fn geometric_mean<T>(collection: &[T]) -> Option<f64>
+where
+    T: ToF64,
+{
+    if collection.is_empty() {
+        return None;
+    }
+
+    let mut has_zero = false;
+    for &x in collection {
+        let value: f64 = x.to_f64();
+        if value.is_nan() || value < 0.0 {
+            return None;
+        }
+        has_zero |= value == 0.0;
+    }
+    if has_zero {
+        return Some(0.0);
+    }
+
+    let log_sum = compensated_sum(collection.iter().map(|&x| x.to_f64().ln()));
+
+    Some((log_sum / collection.len() as f64).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometric_mean_basic() {
+        let result = geometric_mean(&[1, 3, 9]).unwrap();
+        assert!((result - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_geometric_mean_does_not_overflow() {
+        let numbers = vec![1e300; 1000];
+        let result = geometric_mean(&numbers).unwrap();
+        assert!((result / 1e300 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_geometric_mean_zero() {
+        assert_eq!(geometric_mean(&[0.0, 5.0, 10.0]), Some(0.0));
+    }
+
+    #[test]
+    fn test_geometric_mean_negative_or_nan() {
+        assert_eq!(geometric_mean(&[1.0, -2.0]), None);
+        assert_eq!(geometric_mean(&[1.0, f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_geometric_mean_empty() {
+        let empty: Vec<u32> = vec![];
+        assert_eq!(geometric_mean(&empty), None);
+    }
+}
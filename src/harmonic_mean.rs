@@ -0,0 +1,81 @@
+use crate::common::ToF64;
+use crate::moments::compensated_sum;
+
+/// Calculates the harmonic mean of a collection of non-negative numbers: the number of values
+/// divided by the sum of their reciprocals.
+/// It is the right average for rates, such as speeds over equal distances.
+/// A zero in the collection makes the harmonic mean zero.
+///
+/// # Arguments
+/// * `collection` - A slice of non-negative numbers.
+///
+/// # Returns
+/// * `Option<f64>` - The harmonic mean, or None if the collection is empty or contains a negative
+///   value or NaN.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::harmonic_mean;
+///
+/// // Average speed when driving equal distances at 40 and 60 km/h.
+/// assert!((harmonic_mean(&[40, 60]).unwrap() - 48.0).abs() < 1e-12);
+/// assert_eq!(harmonic_mean(&[1.0, -1.0]), None);
+/// ```
+pub fn harmonic_mean<T>(collection: &[T]) -> Option<f64>
+where
+    T: ToF64,
+{
+    if collection.is_empty() {
+        return None;
+    }
+
+    let mut has_zero = false;
+    for &x in collection {
+        let value: f64 = x.to_f64();
+        if value.is_nan() || value < 0.0 {
+            return None;
+        }
+        has_zero |= value == 0.0;
+    }
+    if has_zero {
+        return Some(0.0);
+    }
+
+    let reciprocal_sum = compensated_sum(collection.iter().map(|&x| 1.0 / x.to_f64()));
+
+    Some(collection.len() as f64 / reciprocal_sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_harmonic_mean_basic() {
+        let result = harmonic_mean(&[1.0, 2.0, 4.0]).unwrap();
+        assert!((result - 12.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_harmonic_mean_large_collection() {
+        let numbers = vec![4u32; 1000];
+        assert_eq!(harmonic_mean(&numbers), Some(4.0));
+    }
+
+    #[test]
+    fn test_harmonic_mean_zero() {
+        assert_eq!(harmonic_mean(&[0, 5]), Some(0.0));
+    }
+
+    #[test]
+    fn test_harmonic_mean_negative_or_nan() {
+        assert_eq!(harmonic_mean(&[3.0, -2.0]), None);
+        assert_eq!(harmonic_mean(&[f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_harmonic_mean_empty() {
+        let empty: Vec<f32> = vec![];
+        assert_eq!(harmonic_mean(&empty), None);
+    }
+}
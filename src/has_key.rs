@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Checks if a map contains a specific key.
 ///
 /// # Arguments
@@ -19,9 +21,9 @@
 /// assert!(has_key(&map, &"a"));
 /// assert!(!has_key(&map, &"c"));
 /// ```
-pub fn has_key<K, V>(map: &std::collections::HashMap<K, V>, key: &K) -> bool
+pub fn has_key<M, K>(map: &M, key: &K) -> bool
 where
-    K: std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K>,
 {
     map.contains_key(key)
 }
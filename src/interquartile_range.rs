@@ -0,0 +1,46 @@
+use crate::interquartile_range_by;
+
+/// Calculate the interquartile range (the 75th percentile minus the 25th percentile) of a
+/// collection.
+/// The percentiles are interpolated linearly, as in `percentile`.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The interquartile range, or None if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::interquartile_range;
+///
+/// let numbers = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+/// assert_eq!(interquartile_range(&numbers), Some(4.0));
+/// ```
+pub fn interquartile_range<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    interquartile_range_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interquartile_range_integers() {
+        assert_eq!(interquartile_range(&[1, 2, 3, 4]), Some(1.5));
+    }
+
+    #[test]
+    fn test_interquartile_range_floats() {
+        assert_eq!(interquartile_range(&[6.0, 2.0, 4.0]), Some(2.0));
+    }
+
+    #[test]
+    fn test_interquartile_range_empty() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(interquartile_range(&empty), None);
+    }
+}
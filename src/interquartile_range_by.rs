@@ -0,0 +1,70 @@
+use crate::percentile;
+
+/// Calculate the interquartile range (the 75th percentile minus the 25th percentile) of the values
+/// produced by applying an iteratee to each element of a collection.
+/// The percentiles are interpolated linearly, as in `percentile`.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The interquartile range, or None if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::interquartile_range_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = (1..=9).map(|age| Person { age }).collect();
+/// assert_eq!(interquartile_range_by(&people, |p| p.age as f64), Some(4.0));
+/// ```
+pub fn interquartile_range_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    let values: Vec<f64> = collection.iter().map(iteratee).collect();
+    let upper = percentile(&values, 75.0)?;
+    let lower = percentile(&values, 25.0)?;
+
+    Some(upper - lower)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interquartile_range_by_interpolates() {
+        let points = vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+        assert_eq!(
+            interquartile_range_by(&points, |&(x, _)| x as f64),
+            Some(1.5)
+        );
+    }
+
+    #[test]
+    fn test_interquartile_range_by_ignores_outliers() {
+        let latencies = vec![9.0, 1.0, 5.0, 3.0, 7.0];
+        let with_outlier = vec![9.0, 1.0, 5.0, 3.0, 7.0, 5000.0];
+        assert_eq!(interquartile_range_by(&latencies, |&ms| ms), Some(4.0));
+        assert_eq!(interquartile_range_by(&with_outlier, |&ms| ms), Some(5.0));
+    }
+
+    #[test]
+    fn test_interquartile_range_by_single() {
+        assert_eq!(
+            interquartile_range_by(&["solo"], |w| w.len() as f64),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_interquartile_range_by_empty() {
+        let empty: Vec<&str> = vec![];
+        assert_eq!(interquartile_range_by(&empty, |w| w.len() as f64), None);
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements of a collection that are also in another collection.
+/// The result has no duplicates and keeps the order in which elements first appear in `collection`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - The items to intersect with.
+///
+/// # Returns
+/// * `Vec<T>` - The distinct items found in both slices, in the order of `collection`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::intersect;
+///
+/// let result = intersect(&[1, 2, 2, 3, 4], &[4, 2, 5]);
+/// assert_eq!(result, vec![2, 4]);
+///
+/// let result = intersect(&["a", "b"], &["c"]);
+/// assert!(result.is_empty());
+/// ```
+pub fn intersect<T>(collection: &[T], other: &[T]) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    let other: HashSet<&T> = other.iter().collect();
+    let mut seen = HashSet::new();
+
+    collection
+        .iter()
+        .filter(|item| other.contains(item) && seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect_keeps_collection_order() {
+        assert_eq!(intersect(&[5, 1, 3, 2], &[2, 3, 5]), vec![5, 3, 2]);
+    }
+
+    #[test]
+    fn test_intersect_removes_duplicates() {
+        assert_eq!(intersect(&[1, 1, 2, 2], &[1, 2, 2]), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_intersect_disjoint() {
+        assert!(intersect(&[1, 2], &[3, 4]).is_empty());
+    }
+
+    #[test]
+    fn test_intersect_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(intersect(&empty, &[1]).is_empty());
+        assert!(intersect(&[1], &empty).is_empty());
+    }
+
+    #[test]
+    fn test_intersect_strings() {
+        let a = vec![
+            "apple".to_string(),
+            "banana".to_string(),
+            "cherry".to_string(),
+        ];
+        let b = vec!["cherry".to_string(), "apple".to_string()];
+        assert_eq!(intersect(&a, &b), vec!["apple", "cherry"]);
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements of a collection whose key, generated by the iteratee function, is also the
+/// key of an element of another collection.
+/// Only the first element with each key is kept, in the order of `collection`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - The items to intersect with.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `Vec<T>` - The items of `collection` whose keys appear in both slices, one per key.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::intersect_by;
+///
+/// let result = intersect_by(&[2.1, 1.2, 1.8], &[2.3, 3.4], |x: &f64| x.floor() as i64);
+/// assert_eq!(result, vec![2.1]);
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let current = vec![User { id: 1, name: "Ada" }, User { id: 2, name: "Bob" }];
+/// let invited = vec![User { id: 2, name: "Robert" }];
+/// let result = intersect_by(&current, &invited, |user| user.id);
+/// assert_eq!(result, vec![User { id: 2, name: "Bob" }]);
+/// ```
+pub fn intersect_by<T, U, F>(collection: &[T], other: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let other: HashSet<U> = other.iter().map(&iteratee).collect();
+    let mut seen = HashSet::new();
+
+    collection
+        .iter()
+        .filter(|item| {
+            let key = iteratee(item);
+            other.contains(&key) && seen.insert(key)
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect_by_key() {
+        let result = intersect_by(&["apple", "bob", "cat"], &["dog", "kiwi"], |s| s.len());
+        assert_eq!(result, vec!["bob"]);
+    }
+
+    #[test]
+    fn test_intersect_by_keeps_first_per_key() {
+        let result = intersect_by(&[1, 3, 2, 5], &[7], |x| x % 2);
+        assert_eq!(result, vec![1]);
+    }
+
+    #[test]
+    fn test_intersect_by_case_insensitive() {
+        let result = intersect_by(&["Rust", "Go", "Zig"], &["rust", "ZIG"], |s| {
+            s.to_lowercase()
+        });
+        assert_eq!(result, vec!["Rust", "Zig"]);
+    }
+
+    #[test]
+    fn test_intersect_by_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(intersect_by(&[1, 2], &empty, |x| *x).is_empty());
+    }
+}
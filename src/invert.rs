@@ -1,17 +1,18 @@
-use std::collections::HashMap;
+use crate::MapLike;
 
-/// Constructs a `HashMap` by inverting the keys and values of the input map.
+/// Constructs a map by inverting the keys and values of the input map.
 ///
-/// This function iterates over each key-value pair in the input `HashMap` and
-/// inserts them into a new `HashMap` with the keys and values swapped.
-/// If duplicate values are present in the input map, the value from the last
-/// `Entry` with that value will be used in the inverted map.
+/// This function iterates over each key-value pair in the input map and
+/// inserts them, with the keys and values swapped, into a new map of the same
+/// kind as the input (`HashMap`, `BTreeMap` or `Vec<Entry<K, V>>`).
+/// If duplicate values are present in the input map, the key of the last pair
+/// visited with that value will be used in the inverted map.
 ///
 /// # Arguments
-/// * `input` - A reference to the input `HashMap` to invert.
+/// * `input` - A reference to the input map to invert.
 ///
 /// # Returns
-/// * `HashMap<V, K>` - A new `HashMap` with keys and values inverted from the input.
+/// * `M::Rebind<V, K>` - A new map of the same kind as the input with keys and values inverted.
 ///
 /// # Examples
 /// ```rust
@@ -34,21 +35,20 @@ use std::collections::HashMap;
 ///    assert_eq!(result.get(key), Some(value));
 /// }
 /// ```
-pub fn invert<K, V>(input: &HashMap<K, V>) -> HashMap<V, K>
+pub fn invert<M, K, V>(input: &M) -> M::Rebind<V, K>
 where
-    K: Clone + std::cmp::Eq + std::hash::Hash,
-    V: Clone + std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K, Value = V>,
+    M::Rebind<V, K>: MapLike<Key = V, Value = K>,
+    K: Clone,
+    V: Clone,
 {
-    let mut inverted = HashMap::with_capacity(input.len());
-    for (k, v) in input {
-        inverted.insert(v.clone(), k.clone());
-    }
-    inverted
+    MapLike::from_iter(input.iter().map(|(k, v)| (v.clone(), k.clone())))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_invert_single_entry() {
@@ -132,4 +132,31 @@ mod tests {
             assert_eq!(result.get(key), Some(value));
         }
     }
+
+    #[test]
+    fn test_invert_btree_map() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert("a", 2);
+        map.insert("b", 1);
+
+        let inverted: BTreeMap<i32, &str> = invert(&map);
+        assert_eq!(
+            inverted.into_iter().collect::<Vec<_>>(),
+            vec![(1, "b"), (2, "a")]
+        );
+    }
+
+    #[test]
+    fn test_invert_entry_vec() {
+        use crate::Entry;
+
+        let list = vec![Entry { key: "a", value: 1 }, Entry { key: "b", value: 2 }];
+        let inverted = invert(&list);
+        assert_eq!(
+            inverted,
+            vec![Entry { key: 1, value: "a" }, Entry { key: 2, value: "b" }]
+        );
+    }
 }
@@ -0,0 +1,93 @@
+use crate::order_by::{check_directions, compare_by_keys, Comparator};
+use crate::SortDirection;
+
+/// Determines if a collection is sorted by several comparators, each with its own direction, as
+/// `order_by` would sort it.
+///
+/// Neighbouring elements that compare equal on every comparator are allowed in any order, so the
+/// output of `order_by` always passes with the same comparators and directions.
+///
+/// **Panics:**
+/// Panics if `directions` has more entries than `comparators`, as `order_by` does.
+///
+/// # Arguments
+/// * `collection` - A slice of items to check.
+/// * `comparators` - The comparators, from most to least significant.
+/// * `directions` - The direction for each comparator; missing entries mean ascending.
+///
+/// # Returns
+/// * `true` if no element comes after one it should precede.
+/// * `false` otherwise.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{is_sorted_by_keys, SortDirection};
+///
+/// let rows = vec![("b", 2), ("a", 9), ("a", 1)];
+/// let by_score = |a: &(&str, i32), b: &(&str, i32)| a.1.cmp(&b.1);
+/// let by_name = |a: &(&str, i32), b: &(&str, i32)| a.0.cmp(b.0);
+///
+/// assert!(!is_sorted_by_keys(&rows, &[&by_name, &by_score], &[]));
+/// assert!(is_sorted_by_keys(
+///     &rows,
+///     &[&by_name, &by_score],
+///     &[SortDirection::Desc, SortDirection::Desc],
+/// ));
+/// ```
+pub fn is_sorted_by_keys<T>(
+    collection: &[T],
+    comparators: &[Comparator<'_, T>],
+    directions: &[SortDirection],
+) -> bool {
+    check_directions(comparators, directions);
+
+    collection
+        .windows(2)
+        .all(|pair| compare_by_keys(&pair[0], &pair[1], comparators, directions).is_le())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order_by;
+
+    #[test]
+    fn test_is_sorted_by_keys_accepts_order_by_output() {
+        let items = vec![(3, 'x'), (1, 'y'), (3, 'a'), (2, 'y'), (1, 'b')];
+        let by_number = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+        let by_letter = |a: &(i32, char), b: &(i32, char)| a.1.cmp(&b.1);
+        let comparators: [Comparator<'_, (i32, char)>; 2] = [&by_number, &by_letter];
+        for directions in [
+            [SortDirection::Asc, SortDirection::Asc],
+            [SortDirection::Asc, SortDirection::Desc],
+            [SortDirection::Desc, SortDirection::Asc],
+            [SortDirection::Desc, SortDirection::Desc],
+        ] {
+            let sorted = order_by(&items, &comparators, &directions);
+            assert!(is_sorted_by_keys(&sorted, &comparators, &directions));
+        }
+        assert!(!is_sorted_by_keys(&items, &comparators, &[]));
+    }
+
+    #[test]
+    fn test_is_sorted_by_keys_allows_ties() {
+        let by_len = |a: &&str, b: &&str| a.len().cmp(&b.len());
+        assert!(is_sorted_by_keys(&["bb", "aa", "ccc"], &[&by_len], &[]));
+        assert!(!is_sorted_by_keys(&["bb", "aa", "c"], &[&by_len], &[]));
+    }
+
+    #[test]
+    fn test_is_sorted_by_keys_trivial() {
+        let natural = |a: &i32, b: &i32| a.cmp(b);
+        let empty: Vec<i32> = vec![];
+        assert!(is_sorted_by_keys(&empty, &[&natural], &[]));
+        assert!(is_sorted_by_keys(&[5], &[&natural], &[]));
+        assert!(is_sorted_by_keys(&[3, 1, 2], &[], &[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Directions must not outnumber comparators")]
+    fn test_is_sorted_by_keys_extra_directions() {
+        is_sorted_by_keys(&[1, 2], &[], &[SortDirection::Asc]);
+    }
+}
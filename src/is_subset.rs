@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Checks whether every element of a collection is also in another collection.
+/// Duplicates are ignored, and the empty collection is a subset of every collection.
+///
+/// # Arguments
+/// * `collection` - The candidate subset.
+/// * `other` - The candidate superset.
+///
+/// # Returns
+/// * `bool` - `true` if every item of `collection` is in `other`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::is_subset;
+///
+/// assert!(is_subset(&[1, 3, 3], &[3, 2, 1]));
+/// assert!(!is_subset(&[1, 4], &[1, 2, 3]));
+/// assert!(is_subset(&[], &[1, 2]));
+/// ```
+pub fn is_subset<T>(collection: &[T], other: &[T]) -> bool
+where
+    T: Eq + Hash,
+{
+    let other: HashSet<&T> = other.iter().collect();
+    collection.iter().all(|item| other.contains(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_subset() {
+        assert!(is_subset(&["b", "a"], &["a", "b", "c"]));
+        assert!(!is_subset(&["a", "b", "c"], &["b", "a"]));
+    }
+
+    #[test]
+    fn test_is_subset_of_itself() {
+        assert!(is_subset(&[1, 2, 3], &[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_is_subset_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(is_subset(&empty, &empty));
+        assert!(!is_subset(&[1], &empty));
+    }
+}
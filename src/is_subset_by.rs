@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Checks whether the key of every element of a collection, generated by the iteratee function, is
+/// also the key of an element of another collection.
+/// The empty collection is a subset of every collection.
+///
+/// # Arguments
+/// * `collection` - The candidate subset.
+/// * `other` - The candidate superset.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `bool` - `true` if every key of `collection` is a key of `other`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::is_subset_by;
+///
+/// #[derive(Debug)]
+/// struct Permission {
+///     name: &'static str,
+///     granted_by: &'static str,
+/// }
+///
+/// let required = [Permission { name: "read", granted_by: "policy" }];
+/// let granted = [
+///     Permission { name: "read", granted_by: "admin" },
+///     Permission { name: "write", granted_by: "admin" },
+/// ];
+/// assert!(is_subset_by(&required, &granted, |permission| permission.name));
+/// assert!(!is_subset_by(&granted, &required, |permission| permission.name));
+/// ```
+pub fn is_subset_by<T, U, F>(collection: &[T], other: &[T], iteratee: F) -> bool
+where
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let other: HashSet<U> = other.iter().map(&iteratee).collect();
+    collection
+        .iter()
+        .all(|item| other.contains(&iteratee(item)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_subset_by() {
+        assert!(is_subset_by(&[1, 3], &[5, 7], |x| x % 2));
+        assert!(!is_subset_by(&[1, 2], &[5, 7], |x| x % 2));
+    }
+
+    #[test]
+    fn test_is_subset_by_floats() {
+        assert!(is_subset_by(&[1.5, 2.5], &[2.0, 1.0], |x: &f64| x.floor() as i64));
+    }
+
+    #[test]
+    fn test_is_subset_by_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(is_subset_by(&empty, &[1], |x| *x));
+    }
+}
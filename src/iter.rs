@@ -0,0 +1,665 @@
+//! Lazy iterator adapters mirroring the slice utilities.
+//!
+//! The functions at the crate root take a slice and eagerly return a `Vec`. The adapters in this
+//! module do the same work one element at a time, so a pipeline such as
+//! `filter` → `uniq_by` → `chunk` runs in a single pass without intermediate vectors.
+//! Bring [`LowdashIterExt`] into scope to use them on any `Iterator`.
+
+use crate::LowdashError;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::iter::{Fuse, SkipWhile};
+
+/// Extension trait adding lowdash's collection helpers as lazy adapters on every `Iterator`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::LowdashIterExt;
+///
+/// let result: Vec<Vec<i32>> = vec![0, 1, 2, 0, 2, 3, 4, 5, 4]
+///     .into_iter()
+///     .compact()
+///     .uniq()
+///     .chunk(2)
+///     .collect();
+/// assert_eq!(result, vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub trait LowdashIterExt: Iterator + Sized {
+    /// Lazily splits the iterator into chunks of `size` elements, like `chunk`.
+    /// The last chunk holds the remaining elements and may be shorter than `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<Vec<i32>> = (1..=5).chunk(2).collect();
+    /// assert_eq!(result, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    fn chunk(self, size: usize) -> Chunk<Self> {
+        if size == 0 {
+            panic!("Chunk size must be greater than 0");
+        }
+
+        Chunk {
+            iter: self.fuse(),
+            size,
+        }
+    }
+
+    /// Like `chunk`, but returns an error instead of panicking when `size` is 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<Vec<i32>> = (1..=5).try_chunk(2).unwrap().collect();
+    /// assert_eq!(result, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// assert!((1..=5).try_chunk(0).is_err());
+    /// ```
+    fn try_chunk(self, size: usize) -> Result<Chunk<Self>, LowdashError> {
+        if size == 0 {
+            return Err(LowdashError::InvalidArgument {
+                function: "chunk",
+                reason: "Size parameter must be greater than 0",
+            });
+        }
+
+        Ok(self.chunk(size))
+    }
+
+    /// Lazily removes duplicate elements, keeping the first occurrence, like `uniq`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<i32> = vec![1, 2, 2, 1, 3].into_iter().uniq().collect();
+    /// assert_eq!(result, vec![1, 2, 3]);
+    /// ```
+    fn uniq(self) -> Uniq<Self>
+    where
+        Self::Item: PartialEq + Clone,
+    {
+        Uniq {
+            iter: self,
+            seen: Vec::new(),
+        }
+    }
+
+    /// Lazily removes elements whose key has already been seen, keeping the first occurrence,
+    /// like `uniq_by`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<i32> = vec![1, 2, 3, 4, 5].into_iter().uniq_by(|x| x % 3).collect();
+    /// assert_eq!(result, vec![1, 2, 3]);
+    /// ```
+    fn uniq_by<U, F>(self, iteratee: F) -> UniqBy<Self, U, F>
+    where
+        U: PartialEq,
+        F: FnMut(&Self::Item) -> U,
+    {
+        UniqBy {
+            iter: self,
+            iteratee,
+            seen: Vec::new(),
+        }
+    }
+
+    /// Lazily alternates elements from this iterator and `other`, like `interleave` on two
+    /// collections. Once one side is exhausted, the remaining elements of the other follow.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<i32> = vec![1, 2, 3].into_iter().interleave(vec![4, 5, 6, 7]).collect();
+    /// assert_eq!(result, vec![1, 4, 2, 5, 3, 6, 7]);
+    /// ```
+    fn interleave<J>(self, other: J) -> Interleave<Self, J::IntoIter>
+    where
+        J: IntoIterator<Item = Self::Item>,
+    {
+        Interleave {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+            flag: false,
+        }
+    }
+
+    /// Lazily skips elements while `predicate` returns `true`, like `drop_while`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<i32> = vec![1, 2, 3, 1].into_iter().drop_while(|x| *x < 3).collect();
+    /// assert_eq!(result, vec![3, 1]);
+    /// ```
+    fn drop_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.skip_while(predicate)
+    }
+
+    /// Groups elements by the key returned by `iteratee`, like `partition_by`.
+    ///
+    /// Grouping needs to see every element, so the source is consumed on the first call to
+    /// `next`; the groups are then yielded in the order their keys first appeared.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<Vec<i32>> = vec![1, 2, 3, 4, 5, 6].into_iter().partition_by(|x| x % 3).collect();
+    /// assert_eq!(result, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    /// ```
+    fn partition_by<K, F>(self, iteratee: F) -> PartitionBy<Self, F>
+    where
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        PartitionBy {
+            source: Some((self, iteratee)),
+            groups: Vec::new().into_iter(),
+        }
+    }
+
+    /// Lazily removes elements equal to their type's default value, like `compact`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<&str> = vec!["a", "", "b"].into_iter().compact().collect();
+    /// assert_eq!(result, vec!["a", "b"]);
+    /// ```
+    fn compact(self) -> Compact<Self>
+    where
+        Self::Item: PartialEq + Default,
+    {
+        Compact {
+            iter: self,
+            zero: Self::Item::default(),
+        }
+    }
+
+    /// Lazily yields each element that appears more than once, at the moment its second
+    /// occurrence is seen, like `find_duplicates`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<i32> = vec![1, 2, 2, 3, 3, 3, 4].into_iter().find_duplicates().collect();
+    /// assert_eq!(result, vec![2, 3]);
+    /// ```
+    fn find_duplicates(self) -> FindDuplicates<Self>
+    where
+        Self::Item: Eq + Hash + Clone,
+    {
+        FindDuplicates {
+            iter: self,
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Lazily yields every element whose key has already been seen, like `find_duplicates_by`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::LowdashIterExt;
+    ///
+    /// let result: Vec<i32> = vec![1, 2, 3, 4].into_iter().find_duplicates_by(|x| x % 2).collect();
+    /// assert_eq!(result, vec![3, 4]);
+    /// ```
+    fn find_duplicates_by<U, F>(self, iteratee: F) -> FindDuplicatesBy<Self, U, F>
+    where
+        U: Eq + Hash,
+        F: FnMut(&Self::Item) -> U,
+    {
+        FindDuplicatesBy {
+            iter: self,
+            iteratee,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<I: Iterator> LowdashIterExt for I {}
+
+/// Iterator returned by [`LowdashIterExt::chunk`].
+#[derive(Clone, Debug)]
+pub struct Chunk<I: Iterator> {
+    iter: Fuse<I>,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for Chunk<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk: Vec<I::Item> = self.iter.by_ref().take(self.size).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.div_ceil(self.size),
+            upper.map(|upper| upper.div_ceil(self.size)),
+        )
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::uniq`].
+#[derive(Clone, Debug)]
+pub struct Uniq<I: Iterator> {
+    iter: I,
+    seen: Vec<I::Item>,
+}
+
+impl<I> Iterator for Uniq<I>
+where
+    I: Iterator,
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            if !self.seen.contains(&item) {
+                self.seen.push(item.clone());
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::uniq_by`].
+#[derive(Clone)]
+pub struct UniqBy<I, U, F> {
+    iter: I,
+    iteratee: F,
+    seen: Vec<U>,
+}
+
+impl<I, U, F> Iterator for UniqBy<I, U, F>
+where
+    I: Iterator,
+    U: PartialEq,
+    F: FnMut(&I::Item) -> U,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            let key = (self.iteratee)(&item);
+            if !self.seen.contains(&key) {
+                self.seen.push(key);
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::interleave`].
+#[derive(Clone, Debug)]
+pub struct Interleave<I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    flag: bool,
+}
+
+impl<I, J> Iterator for Interleave<I, J>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.flag = !self.flag;
+        if self.flag {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.saturating_add(b_lower), upper)
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::partition_by`].
+pub struct PartitionBy<I: Iterator, F> {
+    source: Option<(I, F)>,
+    groups: std::vec::IntoIter<Vec<I::Item>>,
+}
+
+impl<I, K, F> Iterator for PartitionBy<I, F>
+where
+    I: Iterator,
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((iter, mut iteratee)) = self.source.take() {
+            let mut seen: HashMap<K, usize> = HashMap::new();
+            let mut groups: Vec<Vec<I::Item>> = Vec::new();
+
+            for item in iter {
+                match seen.entry(iteratee(&item)) {
+                    Entry::Occupied(e) => groups[*e.get()].push(item),
+                    Entry::Vacant(e) => {
+                        e.insert(groups.len());
+                        groups.push(vec![item]);
+                    }
+                }
+            }
+
+            self.groups = groups.into_iter();
+        }
+
+        self.groups.next()
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::compact`].
+#[derive(Clone, Debug)]
+pub struct Compact<I: Iterator> {
+    iter: I,
+    zero: I::Item,
+}
+
+impl<I> Iterator for Compact<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let zero = &self.zero;
+        self.iter.by_ref().find(|item| item != zero)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::find_duplicates`].
+#[derive(Clone, Debug)]
+pub struct FindDuplicates<I: Iterator> {
+    iter: I,
+    seen: HashMap<I::Item, bool>,
+}
+
+impl<I> Iterator for FindDuplicates<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            match self.seen.entry(item) {
+                Entry::Occupied(mut e) => {
+                    if !*e.get() {
+                        e.insert(true);
+                        return Some(e.key().clone());
+                    }
+                }
+                Entry::Vacant(e) => {
+                    e.insert(false);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Iterator returned by [`LowdashIterExt::find_duplicates_by`].
+#[derive(Clone)]
+pub struct FindDuplicatesBy<I, U, F> {
+    iter: I,
+    iteratee: F,
+    seen: HashSet<U>,
+}
+
+impl<I, U, F> Iterator for FindDuplicatesBy<I, U, F>
+where
+    I: Iterator,
+    U: Eq + Hash,
+    F: FnMut(&I::Item) -> U,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            let key = (self.iteratee)(&item);
+            if !self.seen.insert(key) {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        chunk, compact, drop_while, find_duplicates, find_duplicates_by, interleave, partition_by,
+        uniq, uniq_by,
+    };
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    fn people() -> Vec<Person> {
+        vec![
+            Person {
+                name: "Alice".to_string(),
+                age: 25,
+            },
+            Person {
+                name: "Bob".to_string(),
+                age: 30,
+            },
+            Person {
+                name: "Carol".to_string(),
+                age: 25,
+            },
+            Person {
+                name: "Alice".to_string(),
+                age: 25,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_chunk_matches_slice_version() {
+        let numbers: Vec<i32> = (0..10).collect();
+        for size in 1..12 {
+            let lazy: Vec<Vec<i32>> = numbers.iter().copied().chunk(size).collect();
+            assert_eq!(lazy, chunk(&numbers, size));
+        }
+    }
+
+    #[test]
+    fn test_chunk_size_hint() {
+        let chunks = (0..10).chunk(3);
+        assert_eq!(chunks.size_hint(), (4, Some(4)));
+        assert_eq!(chunks.count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunk size must be greater than 0")]
+    fn test_chunk_zero_size() {
+        let _ = (0..10).chunk(0);
+    }
+
+    #[test]
+    fn test_chunk_empty() {
+        let result: Vec<Vec<i32>> = std::iter::empty().chunk(3).collect();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_try_chunk() {
+        let result: Vec<Vec<i32>> = (1..=4).try_chunk(3).unwrap().collect();
+        assert_eq!(result, vec![vec![1, 2, 3], vec![4]]);
+        assert_eq!(
+            (1..=4).try_chunk(0).err(),
+            Some(LowdashError::InvalidArgument {
+                function: "chunk",
+                reason: "Size parameter must be greater than 0",
+            })
+        );
+    }
+
+    #[test]
+    fn test_uniq_matches_slice_version() {
+        let numbers = vec![3, 1, 3, 2, 1, 4, 2];
+        let lazy: Vec<i32> = numbers.iter().copied().uniq().collect();
+        assert_eq!(lazy, uniq(&numbers));
+    }
+
+    #[test]
+    fn test_uniq_by_matches_slice_version() {
+        let lazy: Vec<Person> = people().into_iter().uniq_by(|p| p.age).collect();
+        assert_eq!(lazy, uniq_by(&people(), |p| p.age));
+    }
+
+    #[test]
+    fn test_interleave_matches_slice_version() {
+        let a = vec![1, 2, 3];
+        let b = vec![4, 5, 6, 7, 8];
+        let lazy: Vec<i32> = a.iter().copied().interleave(b.iter().copied()).collect();
+        assert_eq!(lazy, interleave(&[a.clone(), b.clone()]));
+
+        let lazy: Vec<i32> = b.iter().copied().interleave(a.iter().copied()).collect();
+        assert_eq!(lazy, interleave(&[b, a]));
+    }
+
+    #[test]
+    fn test_interleave_size_hint() {
+        let iter = (0..3).interleave(10..15);
+        assert_eq!(iter.size_hint(), (8, Some(8)));
+    }
+
+    #[test]
+    fn test_drop_while_matches_slice_version() {
+        let numbers = vec![1, 2, 3, 4, 1, 2];
+        let lazy: Vec<i32> = numbers.iter().copied().drop_while(|x| *x < 3).collect();
+        assert_eq!(lazy, drop_while(&numbers, |x| *x < 3));
+    }
+
+    #[test]
+    fn test_partition_by_matches_slice_version() {
+        let lazy: Vec<Vec<Person>> = people().into_iter().partition_by(|p| p.age).collect();
+        assert_eq!(lazy, partition_by(&people(), |p| p.age));
+    }
+
+    #[test]
+    fn test_partition_by_empty() {
+        let result: Vec<Vec<i32>> = std::iter::empty().partition_by(|x: &i32| *x).collect();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_compact_matches_slice_version() {
+        let values = vec![0, 1, 0, 2, 3, 0];
+        let lazy: Vec<i32> = values.iter().copied().compact().collect();
+        assert_eq!(lazy, compact(&values));
+
+        let lazy: Vec<Person> = people()
+            .into_iter()
+            .chain(std::iter::once(Person::default()))
+            .compact()
+            .collect();
+        assert_eq!(lazy, people());
+    }
+
+    #[test]
+    fn test_find_duplicates_matches_slice_version() {
+        let numbers = vec![1, 2, 2, 3, 3, 3, 4, 1];
+        let lazy: Vec<i32> = numbers.iter().copied().find_duplicates().collect();
+        assert_eq!(lazy, find_duplicates(&numbers));
+    }
+
+    #[test]
+    fn test_find_duplicates_by_matches_slice_version() {
+        let lazy: Vec<Person> = people().into_iter().find_duplicates_by(|p| p.age).collect();
+        assert_eq!(lazy, find_duplicates_by(&people(), |p| p.age));
+    }
+
+    #[test]
+    fn test_adapters_are_lazy() {
+        let mut calls = 0;
+        let first: Option<Vec<i32>> = (0..)
+            .inspect(|_| calls += 1)
+            .compact()
+            .uniq_by(|x| x % 100)
+            .chunk(3)
+            .next();
+        assert_eq!(first, Some(vec![1, 2, 3]));
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let result: Vec<Vec<i32>> = vec![0, 5, 1, 5, 0, 2, 7, 1, 3]
+            .into_iter()
+            .filter(|x| *x != 7)
+            .compact()
+            .uniq()
+            .chunk(2)
+            .collect();
+        assert_eq!(result, vec![vec![5, 1], vec![2, 3]]);
+    }
+}
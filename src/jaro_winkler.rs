@@ -0,0 +1,134 @@
+/// Computes the Jaro–Winkler similarity between two strings.
+///
+/// The Jaro similarity counts the characters the strings have in common within a window of half
+/// the longer length, penalising those that appear in a different order. The Winkler adjustment
+/// then favours strings sharing a prefix of up to four characters, with a scaling factor of 0.1.
+/// Characters are compared as `char`s. The corresponding distance is `1.0 - similarity`.
+///
+/// # Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// # Returns
+/// * `f64` - The similarity, from 0.0 for nothing in common to 1.0 for identical strings
+///
+/// # Examples
+/// ```rust
+/// use lowdash::jaro_winkler;
+///
+/// assert!((jaro_winkler("MARTHA", "MARHTA") - 0.9611).abs() < 1e-4);
+/// assert!((jaro_winkler("DWAYNE", "DUANE") - 0.84).abs() < 1e-4);
+/// assert_eq!(jaro_winkler("abc", "abc"), 1.0);
+/// assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let jaro = jaro(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+
+    for (i, &c) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let a_order = a.iter().zip(&a_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let b_order = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let transpositions = a_order.zip(b_order).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_reference_values() {
+        assert_close(jaro_winkler("MARTHA", "MARHTA"), 0.9611);
+        assert_close(jaro_winkler("DWAYNE", "DUANE"), 0.84);
+        assert_close(jaro_winkler("DIXON", "DICKSONX"), 0.8133);
+    }
+
+    #[test]
+    fn test_jaro_without_prefix_bonus() {
+        let chars = |s: &str| s.chars().collect::<Vec<char>>();
+        assert_close(jaro(&chars("MARTHA"), &chars("MARHTA")), 0.9444);
+        assert_close(jaro(&chars("DWAYNE"), &chars("DUANE")), 0.8222);
+        assert_close(jaro(&chars("DIXON"), &chars("DICKSONX")), 0.7667);
+    }
+
+    #[test]
+    fn test_identical_and_disjoint() {
+        assert_eq!(jaro_winkler("same", "same"), 1.0);
+        assert_eq!(jaro_winkler("", ""), 1.0);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+        assert_eq!(jaro_winkler("", "abc"), 0.0);
+    }
+
+    #[test]
+    fn test_symmetric() {
+        assert_close(
+            jaro_winkler("crate", "trace"),
+            jaro_winkler("trace", "crate"),
+        );
+    }
+
+    #[test]
+    fn test_prefix_is_limited_to_four_chars() {
+        assert!(jaro_winkler("prefixes", "prefixed") < 1.0);
+        assert!(jaro_winkler("prefixes", "prefixed") > jaro_winkler("xprefixes", "yprefixed"));
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_close(
+            jaro_winkler("ÉCOLE", "ECOLE"),
+            jaro_winkler("XCOLE", "ECOLE"),
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Collects all keys from one or more maps into a single vector.
 ///
 /// Iterates over each map and collects all keys into a single vector.
@@ -28,13 +30,14 @@
 /// assert!(result.contains(&3));
 /// assert!(result.contains(&4));
 /// ```
-pub fn keys<K, V>(maps: &[&std::collections::HashMap<K, V>]) -> Vec<K>
+pub fn keys<M, K>(maps: &[&M]) -> Vec<K>
 where
-    K: Clone + std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K>,
+    K: Clone,
 {
     let mut result = Vec::new();
     for map in maps {
-        for key in map.keys() {
+        for (key, _) in map.iter() {
             result.push(key.clone());
         }
     }
@@ -120,4 +123,14 @@ mod tests {
         assert!(result.contains(&"c"));
         assert!(result.contains(&"d"));
     }
+
+    #[test]
+    fn test_keys_btree_map() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(2, "b");
+        map.insert(1, "a");
+        assert_eq!(keys(&[&map]), vec![1, 2]);
+    }
 }
@@ -0,0 +1,69 @@
+use crate::kurtosis_by;
+
+/// Calculate the population excess kurtosis of a collection.
+/// Kurtosis measures the weight of the tails of the distribution. The excess kurtosis subtracts 3,
+/// the kurtosis of a normal distribution, so a normal distribution scores 0.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The population excess kurtosis, or None if the collection is empty or all values are equal
+///
+/// # Examples
+/// ```rust
+/// use lowdash::kurtosis;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// let result = kurtosis(&numbers);
+/// assert!((result.unwrap() - -0.21875).abs() < 1e-12);
+/// ```
+pub fn kurtosis<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    kurtosis_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kurtosis_integers() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let result = kurtosis(&numbers);
+        assert!((result.unwrap() - -0.21875).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kurtosis_floats() {
+        let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let result = kurtosis(&numbers);
+        assert!((result.unwrap() - -0.21875).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kurtosis_is_shift_invariant() {
+        let numbers: Vec<f64> = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|x| x + 1e9)
+            .collect();
+        let result = kurtosis(&numbers);
+        assert!((result.unwrap() - -0.21875).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_kurtosis_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(kurtosis(&numbers), None);
+    }
+
+    #[test]
+    fn test_kurtosis_constant() {
+        let numbers: Vec<f64> = vec![3.0, 3.0, 3.0];
+        assert_eq!(kurtosis(&numbers), None);
+    }
+}
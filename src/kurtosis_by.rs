@@ -0,0 +1,76 @@
+use crate::moments::Moments;
+
+/// Calculate the population excess kurtosis of the values produced by applying an iteratee to each element
+/// of a collection.
+/// Kurtosis measures the weight of the tails of the distribution. The excess kurtosis subtracts 3,
+/// the kurtosis of a normal distribution, so a normal distribution scores 0.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The population excess kurtosis, or None if the collection is empty or all values are equal
+///
+/// # Examples
+/// ```rust
+/// use lowdash::kurtosis_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// let result = kurtosis_by(&people, |p| p.age as f64);
+/// assert!((result.unwrap() - -0.21875).abs() < 1e-12);
+/// ```
+pub fn kurtosis_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    Moments::from_values(collection.iter().map(iteratee)).kurtosis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kurtosis_by_is_raw_kurtosis_minus_three() {
+        let pairs = vec![(0, 1.0), (1, 2.0), (2, 2.0), (3, 3.0), (4, 10.0)];
+        let values: Vec<f64> = pairs.iter().map(|&(_, v)| v).collect();
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let m2 = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let m4 = values.iter().map(|v| (v - mean).powi(4)).sum::<f64>() / n;
+        let raw = m4 / (m2 * m2);
+
+        let excess = kurtosis_by(&pairs, |&(_, v)| v).unwrap();
+        assert!((excess - (raw - 3.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kurtosis_by_two_point_distribution() {
+        // Half the values at each of two points: the raw kurtosis is 1, its minimum
+        let flags = vec![true, false, true, false, true, false];
+        let result = kurtosis_by(&flags, |&flag| if flag { 1.0 } else { 0.0 }).unwrap();
+        assert!((result - -2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kurtosis_by_heavy_tail_is_positive() {
+        let latencies = vec![10.0, 11.0, 10.0, 12.0, 11.0, 10.0, 11.0, 250.0];
+        assert!(kurtosis_by(&latencies, |&ms| ms).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_kurtosis_by_constant_key() {
+        let words = vec!["red", "tan", "sky"];
+        assert_eq!(kurtosis_by(&words, |w| w.len() as f64), None);
+    }
+}
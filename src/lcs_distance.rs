@@ -0,0 +1,79 @@
+/// Computes the longest-common-subsequence distance between two strings.
+///
+/// The distance is the number of characters that are not part of the longest common subsequence,
+/// which is the minimum number of insertions and deletions, without substitutions, needed to turn
+/// one string into the other. Characters are compared as `char`s.
+///
+/// # Arguments
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// # Returns
+/// * `usize` - The number of characters of `a` and `b` outside their longest common subsequence
+///
+/// # Examples
+/// ```rust
+/// use lowdash::lcs_distance;
+///
+/// // The longest common subsequence is "ittn".
+/// assert_eq!(lcs_distance("kitten", "sitting"), 5);
+/// assert_eq!(lcs_distance("abc", "abc"), 0);
+/// assert_eq!(lcs_distance("abc", ""), 3);
+/// ```
+pub fn lcs_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    a.len() + b.len() - 2 * lcs_length(&a, &b)
+}
+
+fn lcs_length(a: &[char], b: &[char]) -> usize {
+    let mut previous = vec![0; b.len() + 1];
+    let mut current = vec![0; b.len() + 1];
+
+    for &ca in a {
+        for (j, &cb) in b.iter().enumerate() {
+            current[j + 1] = if ca == cb {
+                previous[j] + 1
+            } else {
+                previous[j + 1].max(current[j])
+            };
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classic_examples() {
+        assert_eq!(lcs_distance("kitten", "sitting"), 5);
+        // The longest common subsequence of these two is "BCBA".
+        assert_eq!(lcs_distance("ABCBDAB", "BDCABA"), 5);
+    }
+
+    #[test]
+    fn test_substitution_costs_two() {
+        assert_eq!(lcs_distance("cat", "cut"), 2);
+    }
+
+    #[test]
+    fn test_identical_and_empty() {
+        assert_eq!(lcs_distance("same", "same"), 0);
+        assert_eq!(lcs_distance("", ""), 0);
+        assert_eq!(lcs_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_disjoint() {
+        assert_eq!(lcs_distance("abc", "xyz"), 6);
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_eq!(lcs_distance("café", "cafe"), 2);
+    }
+}
@@ -1,19 +1,48 @@
 pub mod common;
+pub mod iter;
+pub mod parallel;
 
 mod assign;
 mod associate;
 mod camel_case;
+mod camel_case_with;
 mod capitalize;
+mod cartesian_product;
+mod case_writer;
 mod char_length;
 mod chunk;
+mod chunk_by;
+mod chunk_evenly;
 mod chunk_string;
+mod chunk_while;
 mod clamp;
+mod closest_matches;
+mod closest_matches_with;
 mod combination;
+mod combination_rank;
+mod combinations;
+mod combinations_with_repetition;
 mod compact;
+mod constant_case;
+mod constant_case_with;
+mod contains_all;
+mod contains_all_by;
+mod contains_any;
+mod contains_any_by;
+mod convert_case;
+mod convert_case_with;
 mod count;
 mod count_by;
 mod count_values;
 mod count_values_by;
+mod damerau_levenshtein;
+mod deburr;
+mod derangements;
+mod difference;
+mod difference_by;
+mod display_width;
+mod dot_case;
+mod dot_case_with;
 mod drop;
 mod drop_by_index;
 mod drop_right;
@@ -24,6 +53,9 @@ mod earliest;
 mod earliest_by;
 mod ellipsis;
 mod entries;
+mod error;
+mod escape_html;
+mod escape_regex;
 mod fill;
 mod filter;
 mod filter_map;
@@ -43,54 +75,94 @@ mod first_or;
 mod first_or_empty;
 mod flat_map;
 mod flatten;
+mod fold_ascii;
 mod foreach;
 mod foreach_while;
 mod from_entries;
 mod from_pairs;
+mod geometric_mean;
 mod group_by;
+mod harmonic_mean;
 mod has_key;
 mod index_of;
 mod interleave;
 mod interpolate;
+mod interquartile_range;
+mod interquartile_range_by;
+mod intersect;
+mod intersect_by;
 mod invert;
 mod is_sorted;
 mod is_sorted_by_key;
+mod is_sorted_by_keys;
+mod is_subset;
+mod is_subset_by;
+mod jaro_winkler;
 mod kebab_case;
+mod kebab_case_with;
 mod key_by;
 mod keys;
+mod kurtosis;
+mod kurtosis_by;
 mod last;
 mod last_index_of;
 mod last_or;
 mod last_or_empty;
 mod latest;
 mod latest_by;
+mod lcs_distance;
+mod levenshtein;
 mod map;
 mod map_entries;
 mod map_keys;
+mod map_like;
 mod map_to_slice;
 mod map_values;
+mod match_options;
 mod max;
 mod max_by;
 mod mean;
 mod mean_by;
+mod mean_checked;
 mod median;
 mod min;
 mod min_by;
+mod mode;
+mod mode_by;
+mod moments;
+mod natural_cmp;
+mod natural_sort;
 mod nearest_power_of_two;
 mod nth;
+mod nth_combination;
+mod nth_permutation;
 mod omit_by;
 mod omit_by_keys;
 mod omit_by_values;
+mod online_stats;
+mod order_by;
+mod pad;
+mod pad_end;
+mod pad_start;
 mod partition_by;
 mod pascal_case;
+mod pascal_case_with;
+mod path_case;
+mod path_case_with;
 mod percentile;
 mod permutation;
+mod permutation_rank;
+mod permutations;
+mod permutations_with_repetition;
 mod pick_by;
 mod pick_by_keys;
 mod pick_by_values;
+mod power_set;
 mod product;
 mod product_by;
+mod quantiles;
 mod random_string;
+mod random_string_with_rng;
 mod range;
 mod range_from;
 mod range_with_steps;
@@ -98,46 +170,152 @@ mod reduce;
 mod reduce_right;
 mod reject;
 mod reject_map;
+mod render_options;
 mod repeat;
 mod repeat_by;
 mod replace;
 mod replace_all;
 mod reverse;
 mod sample;
+mod sample_std_dev;
+mod sample_std_dev_by;
+mod sample_variance;
+mod sample_variance_by;
+mod sample_with_rng;
 mod samples;
+mod samples_with_rng;
+mod sentence_case;
+mod sentence_case_with;
+mod shell_quote;
 mod shuffle;
+mod shuffle_with_rng;
+mod skewness;
+mod skewness_by;
 mod slice;
 mod slice_to_map;
+mod slug_options;
+mod slugify;
+mod slugify_with;
 mod snake_case;
+mod snake_case_with;
+mod sort_by_key_cached;
+mod sort_direction;
 mod splice;
+mod start_case;
+mod start_case_with;
+mod std_dev;
+mod std_dev_by;
 mod subset;
 mod substring;
 mod sum;
 mod sum_by;
+mod symmetric_difference;
+mod symmetric_difference_by;
+mod template;
+mod template_error;
+mod template_with;
 mod times;
+mod title_case;
+mod title_case_with;
 mod to_pairs;
+mod train_case;
+mod train_case_with;
+mod trim_chars;
+mod trim_end_chars;
+mod trim_start_chars;
+mod truncate;
+mod truncate_options;
+mod truncate_with;
+mod try_chunk;
+mod try_chunk_evenly;
+mod try_chunk_string;
+mod try_clamp;
+mod try_latest;
+mod try_latest_by;
+mod try_nearest_power_of_two;
+mod try_percentile;
+mod try_random_string;
+mod try_random_string_with_rng;
+mod try_range_with_steps;
+mod try_sample;
+mod try_sample_with_rng;
+mod try_windows;
+mod try_windows_partial;
+mod unescape_html;
+mod unicode;
+mod union;
+mod union_by;
 mod uniq;
 mod uniq_by;
+mod uniq_by_hashed;
+mod uniq_hashed;
 mod uniq_keys;
 mod uniq_values;
 mod value_or;
+mod value_range;
+mod value_range_by;
 mod values;
+mod variance;
+mod variance_by;
+mod weighted_mean;
+mod windows;
+mod windows_partial;
+mod without;
+mod without_by;
+mod word_options;
+mod word_spans;
+mod word_spans_with;
+mod word_wrap;
 mod words;
+mod words_with;
+mod write_case;
+mod write_case_with;
 
 pub use assign::assign;
 pub use associate::associate;
 pub use camel_case::camel_case;
+pub use camel_case_with::camel_case_with;
 pub use capitalize::capitalize;
+pub use cartesian_product::cartesian_product;
+pub use cartesian_product::CartesianProduct;
 pub use char_length::char_length;
 pub use chunk::chunk;
+pub use chunk_by::chunk_by;
+pub use chunk_evenly::chunk_evenly;
 pub use chunk_string::chunk_string;
+pub use chunk_while::chunk_while;
 pub use clamp::clamp;
+pub use closest_matches::closest_matches;
+pub use closest_matches_with::closest_matches_with;
 pub use combination::combination;
+pub use combination_rank::combination_rank;
+pub use combinations::combinations;
+pub use combinations::Combinations;
+pub use combinations_with_repetition::combinations_with_repetition;
+pub use combinations_with_repetition::CombinationsWithRepetition;
 pub use compact::compact;
+pub use constant_case::constant_case;
+pub use constant_case_with::constant_case_with;
+pub use contains_all::contains_all;
+pub use contains_all_by::contains_all_by;
+pub use contains_any::contains_any;
+pub use contains_any_by::contains_any_by;
+pub use convert_case::convert_case;
+pub use convert_case::Case;
+pub use convert_case_with::convert_case_with;
 pub use count::count;
 pub use count_by::count_by;
 pub use count_values::count_values;
 pub use count_values_by::count_values_by;
+pub use damerau_levenshtein::damerau_levenshtein;
+pub use deburr::deburr;
+pub use derangements::derangements;
+pub use derangements::Derangements;
+pub use difference::difference;
+pub use difference_by::difference_by;
+pub use display_width::display_width;
+pub use dot_case::dot_case;
+pub use dot_case_with::dot_case_with;
 pub use drop::drop;
 pub use drop_by_index::drop_by_index;
 pub use drop_right::drop_right;
@@ -150,6 +328,9 @@ pub use earliest_by::earliest_by;
 pub use ellipsis::ellipsis;
 pub use entries::entries;
 pub use entries::Entry;
+pub use error::LowdashError;
+pub use escape_html::escape_html;
+pub use escape_regex::escape_regex;
 pub use fill::fill;
 pub use filter::filter;
 pub use filter_map::filter_map;
@@ -169,54 +350,101 @@ pub use first_or::first_or;
 pub use first_or_empty::first_or_empty;
 pub use flat_map::flat_map;
 pub use flatten::flatten;
+pub use fold_ascii::fold_ascii;
 pub use foreach::foreach;
 pub use foreach_while::foreach_while;
 pub use from_entries::from_entries;
 pub use from_pairs::from_pairs;
+pub use geometric_mean::geometric_mean;
 pub use group_by::group_by;
+pub use harmonic_mean::harmonic_mean;
 pub use has_key::has_key;
 pub use index_of::index_of;
 pub use interleave::interleave;
 pub use interpolate::interpolate;
+pub use interquartile_range::interquartile_range;
+pub use interquartile_range_by::interquartile_range_by;
+pub use intersect::intersect;
+pub use intersect_by::intersect_by;
 pub use invert::invert;
 pub use is_sorted::is_sorted;
 pub use is_sorted_by_key::is_sorted_by_key;
+pub use is_sorted_by_keys::is_sorted_by_keys;
+pub use is_subset::is_subset;
+pub use is_subset_by::is_subset_by;
+pub use iter::LowdashIterExt;
+pub use jaro_winkler::jaro_winkler;
 pub use kebab_case::kebab_case;
+pub use kebab_case_with::kebab_case_with;
 pub use key_by::key_by;
 pub use keys::keys;
+pub use kurtosis::kurtosis;
+pub use kurtosis_by::kurtosis_by;
 pub use last::last;
 pub use last_index_of::last_index_of;
 pub use last_or::last_or;
 pub use last_or_empty::last_or_empty;
 pub use latest::latest;
 pub use latest_by::latest_by;
+pub use lcs_distance::lcs_distance;
+pub use levenshtein::levenshtein;
 pub use map::map;
 pub use map_entries::map_entries;
 pub use map_keys::map_keys;
+pub use map_like::EntryIter;
+pub use map_like::MapLike;
 pub use map_to_slice::map_to_slice;
 pub use map_values::map_values;
+pub use match_options::MatchOptions;
+pub use match_options::StringMetric;
 pub use max::max;
 pub use max_by::max_by;
 pub use mean::mean;
 pub use mean_by::mean_by;
+pub use mean_checked::mean_checked;
 pub use median::median;
 pub use min::min;
 pub use min_by::min_by;
+pub use mode::mode;
+pub use mode_by::mode_by;
+pub use natural_cmp::natural_cmp;
+pub use natural_sort::natural_sort;
 pub use nearest_power_of_two::nearest_power_of_two;
 pub use nth::nth;
+pub use nth_combination::nth_combination;
+pub use nth_permutation::nth_permutation;
 pub use omit_by::omit_by;
 pub use omit_by_keys::omit_by_keys;
 pub use omit_by_values::omit_by_values;
+pub use online_stats::OnlineStats;
+pub use order_by::order_by;
+pub use order_by::Comparator;
+pub use pad::pad;
+pub use pad_end::pad_end;
+pub use pad_start::pad_start;
 pub use partition_by::partition_by;
 pub use pascal_case::pascal_case;
+pub use pascal_case_with::pascal_case_with;
+pub use path_case::path_case;
+pub use path_case_with::path_case_with;
 pub use percentile::percentile;
 pub use permutation::permutation;
+pub use permutation_rank::permutation_rank;
+pub use permutations::permutations;
+pub use permutations::Permutations;
+pub use permutations_with_repetition::permutations_with_repetition;
+pub use permutations_with_repetition::PermutationsWithRepetition;
 pub use pick_by::pick_by;
 pub use pick_by_keys::pick_by_keys;
 pub use pick_by_values::pick_by_values;
+pub use power_set::power_set;
+pub use power_set::PowerSet;
 pub use product::product;
 pub use product_by::product_by;
+pub use quantiles::quantiles;
+pub use quantiles::QuantileMethod;
 pub use random_string::random_string;
+pub use random_string_with_rng::random_string_with_rng;
 pub use range::range;
 pub use range_from::range_from;
 pub use range_with_steps::range_with_steps;
@@ -224,28 +452,106 @@ pub use reduce::reduce;
 pub use reduce_right::reduce_right;
 pub use reject::reject;
 pub use reject_map::reject_map;
+pub use render_options::RenderOptions;
 pub use repeat::repeat;
 pub use repeat_by::repeat_by;
 pub use replace::replace;
 pub use replace_all::replace_all;
 pub use reverse::reverse;
 pub use sample::sample;
+pub use sample_std_dev::sample_std_dev;
+pub use sample_std_dev_by::sample_std_dev_by;
+pub use sample_variance::sample_variance;
+pub use sample_variance_by::sample_variance_by;
+pub use sample_with_rng::sample_with_rng;
 pub use samples::samples;
+pub use samples_with_rng::samples_with_rng;
+pub use sentence_case::sentence_case;
+pub use sentence_case_with::sentence_case_with;
+pub use shell_quote::shell_quote;
 pub use shuffle::shuffle;
+pub use shuffle_with_rng::shuffle_with_rng;
+pub use skewness::skewness;
+pub use skewness_by::skewness_by;
 pub use slice::slice;
 pub use slice_to_map::slice_to_map;
+pub use slug_options::SlugOptions;
+pub use slugify::slugify;
+pub use slugify_with::slugify_with;
 pub use snake_case::snake_case;
+pub use snake_case_with::snake_case_with;
+pub use sort_by_key_cached::sort_by_key_cached;
+pub use sort_direction::SortDirection;
 pub use splice::splice;
+pub use start_case::start_case;
+pub use start_case_with::start_case_with;
+pub use std_dev::std_dev;
+pub use std_dev_by::std_dev_by;
 pub use subset::subset;
 pub use substring::substring;
 pub use sum::sum;
 pub use sum_by::sum_by;
+pub use symmetric_difference::symmetric_difference;
+pub use symmetric_difference_by::symmetric_difference_by;
+pub use template::template;
+pub use template::Template;
+pub use template_error::TemplateError;
+pub use template_with::template_with;
 pub use times::times;
+pub use title_case::title_case;
+pub use title_case_with::title_case_with;
 pub use to_pairs::to_pairs;
+pub use train_case::train_case;
+pub use train_case_with::train_case_with;
+pub use trim_chars::trim_chars;
+pub use trim_end_chars::trim_end_chars;
+pub use trim_start_chars::trim_start_chars;
+pub use truncate::truncate;
+pub use truncate_options::TruncateOptions;
+pub use truncate_options::TruncatePosition;
+pub use truncate_with::truncate_with;
+pub use try_chunk::try_chunk;
+pub use try_chunk_evenly::try_chunk_evenly;
+pub use try_chunk_string::try_chunk_string;
+pub use try_clamp::try_clamp;
+pub use try_latest::try_latest;
+pub use try_latest_by::try_latest_by;
+pub use try_nearest_power_of_two::try_nearest_power_of_two;
+pub use try_percentile::try_percentile;
+pub use try_random_string::try_random_string;
+pub use try_random_string_with_rng::try_random_string_with_rng;
+pub use try_range_with_steps::try_range_with_steps;
+pub use try_sample::try_sample;
+pub use try_sample_with_rng::try_sample_with_rng;
+pub use try_windows::try_windows;
+pub use try_windows_partial::try_windows_partial;
+pub use unescape_html::unescape_html;
+pub use union::union;
+pub use union_by::union_by;
 pub use uniq::uniq;
 pub use uniq_by::uniq_by;
+pub use uniq_by_hashed::uniq_by_hashed;
+pub use uniq_hashed::uniq_hashed;
 pub use uniq_keys::uniq_keys;
 pub use uniq_values::uniq_values;
 pub use value_or::value_or;
+pub use value_range::value_range;
+pub use value_range_by::value_range_by;
 pub use values::values;
+pub use variance::variance;
+pub use variance_by::variance_by;
+pub use weighted_mean::weighted_mean;
+pub use windows::windows;
+pub use windows_partial::windows_partial;
+pub use without::without;
+pub use without_by::without_by;
+pub use word_options::Apostrophes;
+pub use word_options::DigitGrouping;
+pub use word_options::WordOptions;
+pub use word_spans::word_spans;
+pub use word_spans_with::word_spans_with;
+pub use word_wrap::word_wrap;
 pub use words::words;
+pub use words_with::words_with;
+pub use write_case::write_case;
+pub use write_case_with::write_case_with;
@@ -1,13 +1,17 @@
-use std::collections::{BTreeMap, HashMap};
+use crate::MapLike;
 
 /// Transforms the entries of a map using a provided function.
 ///
+/// The pairs are visited in ascending key order, so when several entries map to the same new key,
+/// the one from the largest original key is kept. A `Vec<Entry<K, V>>` is returned in that
+/// ascending order rather than in its input order.
+///
 /// # Arguments
 /// * `map` - The input map whose entries are to be transformed.
 /// * `iteratee` - A function that takes a reference to a key and its value, returning a new key and value.
 ///
 /// # Returns
-/// * `BTreeMap<K2, V2>` - A new map with transformed entries.
+/// * `M::Rebind<K2, V2>` - A new map of the same kind as the input with transformed entries.
 ///
 /// # Examples
 /// ```rust
@@ -22,27 +26,18 @@ use std::collections::{BTreeMap, HashMap};
 /// assert_eq!(transformed.get("A"), Some(&10));
 /// assert_eq!(transformed.get("B"), Some(&20));
 /// ```
-pub fn map_entries<K1, V1, K2, V2, F>(map: &HashMap<K1, V1>, iteratee: F) -> BTreeMap<K2, V2>
+pub fn map_entries<M, K1, V1, K2, V2, F>(map: &M, iteratee: F) -> M::Rebind<K2, V2>
 where
-    K1: Eq + std::hash::Hash + Clone + Ord,
-    K2: Eq + std::hash::Hash + Ord,
-    V1: Clone,
-    V2: Clone,
+    M: MapLike<Key = K1, Value = V1>,
+    M::Rebind<K2, V2>: MapLike<Key = K2, Value = V2>,
+    K1: Ord,
     F: Fn(&K1, &V1) -> (K2, V2),
 {
-    let mut result = BTreeMap::new();
-
-    // Collect and sort the keys in ascending order for deterministic behavior
-    let mut keys: Vec<&K1> = map.keys().collect();
-    keys.sort(); // Sort in ascending order. Use keys.sort_by(|a, b| b.cmp(a)) for descending.
-
-    for k1 in keys {
-        let v1 = &map[k1];
-        let (k2, v2) = iteratee(k1, v1);
-        result.insert(k2, v2);
-    }
+    // Collect and sort the pairs by key in ascending order for deterministic behavior
+    let mut pairs: Vec<(&K1, &V1)> = map.iter().collect();
+    pairs.sort_by(|a, b| a.0.cmp(b.0));
 
-    result
+    MapLike::from_iter(pairs.into_iter().map(|(k1, v1)| iteratee(k1, v1)))
 }
 
 #[cfg(test)]
@@ -88,4 +83,33 @@ mod tests {
         assert_eq!(transformed.get(&10), Some(&3));
         assert_eq!(transformed.get(&20), Some(&3));
     }
+
+    #[test]
+    fn test_map_entries_returns_same_map_kind() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        let transformed: HashMap<i32, &str> = map_entries(&map, |k, v| (*v, *k));
+        assert_eq!(transformed.get(&1), Some(&"a"));
+    }
+
+    #[test]
+    fn test_map_entries_entry_vec_is_key_sorted() {
+        use crate::Entry;
+
+        let list = vec![Entry { key: "b", value: 2 }, Entry { key: "a", value: 1 }];
+        let transformed = map_entries(&list, |k, v| (k.to_uppercase(), v * 10));
+        assert_eq!(
+            transformed,
+            vec![
+                Entry {
+                    key: "A".to_string(),
+                    value: 10
+                },
+                Entry {
+                    key: "B".to_string(),
+                    value: 20
+                },
+            ]
+        );
+    }
 }
@@ -1,13 +1,17 @@
-use std::collections::{BTreeMap, HashMap};
+use crate::MapLike;
 
 /// Transforms the keys of a map using a provided function.
 ///
+/// The pairs are visited in descending key order, so when several keys map to the same new key,
+/// the value of the smallest original key is kept. A `Vec<Entry<K, V>>` is returned in that
+/// descending order rather than in its input order.
+///
 /// # Arguments
 /// * `map` - The input map whose keys are to be transformed.
 /// * `iteratee` - A function that takes a reference to a value and its key, returning a new key.
 ///
 /// # Returns
-/// * `BTreeMap<R, V>` - A new map with transformed keys.
+/// * `M::Rebind<R, V>` - A new map of the same kind as the input with transformed keys.
 ///
 /// # Examples
 /// ```rust
@@ -21,26 +25,20 @@ use std::collections::{BTreeMap, HashMap};
 /// assert_eq!(transformed.get("key_a"), Some(&1));
 /// assert_eq!(transformed.get("key_b"), Some(&2));
 /// ```
-pub fn map_keys<K, V, R, F>(map: &HashMap<K, V>, iteratee: F) -> BTreeMap<R, V>
+pub fn map_keys<M, K, V, R, F>(map: &M, iteratee: F) -> M::Rebind<R, V>
 where
-    K: Eq + std::hash::Hash + Ord,
+    M: MapLike<Key = K, Value = V>,
+    M::Rebind<R, V>: MapLike<Key = R, Value = V>,
+    K: Ord,
     V: Clone,
-    R: Ord,
     F: Fn(&V, &K) -> R,
 {
-    let mut result = BTreeMap::new();
-    let mut keys: Vec<&K> = map.keys().collect();
+    let mut pairs: Vec<(&K, &V)> = map.iter().collect();
 
     // Sort keys in descending order to ensure later keys overwrite earlier ones
-    keys.sort_by(|a, b| b.cmp(a));
-
-    for k in keys {
-        let v = &map[k];
-        let new_key = iteratee(v, k);
-        result.insert(new_key, v.clone());
-    }
+    pairs.sort_by(|a, b| b.0.cmp(a.0));
 
-    result
+    MapLike::from_iter(pairs.into_iter().map(|(k, v)| (iteratee(v, k), v.clone())))
 }
 
 #[cfg(test)]
@@ -74,4 +72,69 @@ mod tests {
         // Since keys are sorted in descending order, "a" is inserted first, then "A" overwrites it
         assert_eq!(transformed.get("a"), Some(&2));
     }
+
+    #[test]
+    fn test_map_keys_returns_same_map_kind() {
+        let mut map = HashMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        let transformed: HashMap<String, i32> = map_keys(&map, |_, k| k.to_uppercase());
+        assert_eq!(transformed.len(), 2);
+        assert_eq!(transformed.get("A"), Some(&1));
+    }
+
+    #[test]
+    fn test_map_keys_collision_keeps_smallest_key() {
+        let mut map = HashMap::new();
+        map.insert("b", 2);
+        map.insert("a", 1);
+        map.insert("c", 3);
+        let transformed = map_keys(&map, |_, _| "same");
+        assert_eq!(transformed.len(), 1);
+        assert_eq!(transformed.get("same"), Some(&1));
+    }
+
+    #[test]
+    fn test_map_keys_btree_map() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert(1, "one");
+        map.insert(2, "two");
+        let transformed: BTreeMap<i32, &str> = map_keys(&map, |_, k| k * 10);
+        assert_eq!(
+            transformed.into_iter().collect::<Vec<_>>(),
+            vec![(10, "one"), (20, "two")]
+        );
+    }
+
+    #[test]
+    fn test_map_keys_entry_vec_is_key_sorted() {
+        use crate::Entry;
+
+        let list = vec![
+            Entry {
+                key: 1,
+                value: "one",
+            },
+            Entry {
+                key: 3,
+                value: "three",
+            },
+        ];
+        let transformed = map_keys(&list, |_, k| k * 10);
+        assert_eq!(
+            transformed,
+            vec![
+                Entry {
+                    key: 30,
+                    value: "three"
+                },
+                Entry {
+                    key: 10,
+                    value: "one"
+                },
+            ]
+        );
+    }
 }
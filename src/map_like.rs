@@ -0,0 +1,292 @@
+use crate::entries::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+/// A key-value container the map utilities can read from and build.
+///
+/// Implemented for `HashMap` with any hasher, `BTreeMap`, and `Vec<Entry<K, V>>` (an association
+/// list that keeps insertion order). Utilities such as `pick_by`, `invert` or `map_values` are
+/// generic over this trait and return the same kind of map they were given, visiting the pairs in
+/// the input's order. `map_keys` and `map_entries` are the exception: they sort the pairs by key
+/// first, so that colliding keys resolve the same way for every map kind, and a `Vec<Entry<K, V>>`
+/// comes back in that sorted order.
+///
+/// The methods share their names with the inherent methods of the standard collections, which take
+/// precedence on concrete types. Call them as `MapLike::get(&map, &key)` outside generic code.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{Entry, MapLike};
+///
+/// let mut list: Vec<Entry<&str, i32>> = MapLike::from_iter(vec![("a", 1), ("b", 2)]);
+/// MapLike::insert(&mut list, "a", 3);
+/// assert_eq!(MapLike::get(&list, &"a"), Some(&3));
+/// assert_eq!(MapLike::len(&list), 2);
+/// ```
+pub trait MapLike {
+    /// The key type.
+    type Key;
+    /// The value type.
+    type Value;
+    /// Iterator over the `(key, value)` pairs of the map.
+    type Iter<'a>: Iterator<Item = (&'a Self::Key, &'a Self::Value)>
+    where
+        Self: 'a;
+    /// The same kind of map holding different key and value types.
+    type Rebind<K2, V2>;
+
+    /// Returns the number of entries in the map.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the map holds no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the value stored for `key`.
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value>;
+
+    /// Returns `true` if the map holds a value for `key`.
+    fn contains_key(&self, key: &Self::Key) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Inserts a value, returning the previous value stored for the key.
+    fn insert(&mut self, key: Self::Key, value: Self::Value) -> Option<Self::Value>;
+
+    /// Removes a key, returning its value if it was present.
+    fn remove(&mut self, key: &Self::Key) -> Option<Self::Value>;
+
+    /// Iterates over the `(key, value)` pairs of the map.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Builds a map from `(key, value)` pairs. Later pairs overwrite earlier ones with the same key.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Self::Key, Self::Value)>;
+}
+
+impl<K, V, S> MapLike for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Key = K;
+    type Value = V;
+    type Iter<'a>
+        = std::collections::hash_map::Iter<'a, K, V>
+    where
+        Self: 'a;
+    type Rebind<K2, V2> = HashMap<K2, V2, S>;
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        HashMap::contains_key(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        HashMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        HashMap::remove(self, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        HashMap::iter(self)
+    }
+
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        iter.into_iter().collect()
+    }
+}
+
+impl<K, V> MapLike for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    type Key = K;
+    type Value = V;
+    type Iter<'a>
+        = std::collections::btree_map::Iter<'a, K, V>
+    where
+        Self: 'a;
+    type Rebind<K2, V2> = BTreeMap<K2, V2>;
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        BTreeMap::get(self, key)
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        BTreeMap::contains_key(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        BTreeMap::insert(self, key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        BTreeMap::remove(self, key)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTreeMap::iter(self)
+    }
+
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        iter.into_iter().collect()
+    }
+}
+
+/// Iterator over the pairs of a `Vec<Entry<K, V>>` used as a map.
+pub type EntryIter<'a, K, V> =
+    std::iter::Map<std::slice::Iter<'a, Entry<K, V>>, fn(&'a Entry<K, V>) -> (&'a K, &'a V)>;
+
+fn entry_pair<K, V>(entry: &Entry<K, V>) -> (&K, &V) {
+    (&entry.key, &entry.value)
+}
+
+impl<K, V> MapLike for Vec<Entry<K, V>>
+where
+    K: PartialEq,
+{
+    type Key = K;
+    type Value = V;
+    type Iter<'a>
+        = EntryIter<'a, K, V>
+    where
+        Self: 'a;
+    type Rebind<K2, V2> = Vec<Entry<K2, V2>>;
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.as_slice()
+            .iter()
+            .find(|entry| entry.key == *key)
+            .map(|entry| &entry.value)
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self
+            .as_mut_slice()
+            .iter_mut()
+            .find(|entry| entry.key == key)
+        {
+            Some(entry) => Some(std::mem::replace(&mut entry.value, value)),
+            None => {
+                self.push(Entry { key, value });
+                None
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.as_slice().iter().position(|entry| entry.key == *key)?;
+        Some(Vec::remove(self, index).value)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice()
+            .iter()
+            .map(entry_pair as fn(&Entry<K, V>) -> (&K, &V))
+    }
+
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut entries = Vec::new();
+        for (key, value) in iter {
+            MapLike::insert(&mut entries, key, value);
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<M>(pairs: Vec<(&'static str, i32)>) -> M
+    where
+        M: MapLike<Key = &'static str, Value = i32>,
+    {
+        let mut map = M::from_iter(pairs);
+        map.insert("z", 26);
+        map
+    }
+
+    #[test]
+    fn test_hash_map() {
+        let mut map: HashMap<&str, i32> = roundtrip(vec![("a", 1), ("b", 2), ("a", 3)]);
+        assert_eq!(MapLike::len(&map), 3);
+        assert_eq!(MapLike::get(&map, &"a"), Some(&3));
+        assert!(MapLike::contains_key(&map, &"z"));
+        assert_eq!(MapLike::remove(&mut map, &"b"), Some(2));
+        assert!(!MapLike::contains_key(&map, &"b"));
+    }
+
+    #[test]
+    fn test_hash_map_custom_hasher() {
+        use std::hash::BuildHasherDefault;
+        type Hasher = BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+
+        let map: HashMap<&str, i32, Hasher> = roundtrip(vec![("a", 1)]);
+        assert_eq!(MapLike::get(&map, &"a"), Some(&1));
+        assert_eq!(MapLike::iter(&map).count(), 2);
+    }
+
+    #[test]
+    fn test_btree_map() {
+        let map: BTreeMap<&str, i32> = roundtrip(vec![("b", 2), ("a", 1)]);
+        let pairs: Vec<(&&str, &i32)> = MapLike::iter(&map).collect();
+        assert_eq!(pairs, vec![(&"a", &1), (&"b", &2), (&"z", &26)]);
+    }
+
+    #[test]
+    fn test_entry_vec_keeps_insertion_order() {
+        let mut list: Vec<Entry<&str, i32>> = roundtrip(vec![("b", 2), ("a", 1), ("b", 3)]);
+        assert_eq!(
+            list,
+            vec![
+                Entry { key: "b", value: 3 },
+                Entry { key: "a", value: 1 },
+                Entry {
+                    key: "z",
+                    value: 26
+                },
+            ]
+        );
+        assert_eq!(MapLike::insert(&mut list, "a", 10), Some(1));
+        assert_eq!(MapLike::get(&list, &"a"), Some(&10));
+        assert_eq!(MapLike::remove(&mut list, &"b"), Some(3));
+        assert_eq!(MapLike::remove(&mut list, &"b"), None);
+        assert_eq!(MapLike::len(&list), 2);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let map: BTreeMap<i32, i32> = MapLike::from_iter(Vec::new());
+        assert!(MapLike::is_empty(&map));
+    }
+}
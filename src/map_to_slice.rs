@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use crate::MapLike;
 
 /// Transforms the entries of a map into a slice using a provided function.
 ///
@@ -22,13 +22,13 @@ use std::collections::HashMap;
 /// assert!(transformed.contains(&"a:1".to_string()));
 /// assert!(transformed.contains(&"b:2".to_string()));
 /// ```
-pub fn map_to_slice<K, V, R, F>(map: &HashMap<K, V>, iteratee: F) -> Vec<R>
+pub fn map_to_slice<M, K, V, R, F>(map: &M, iteratee: F) -> Vec<R>
 where
-    K: Eq + std::hash::Hash,
+    M: MapLike<Key = K, Value = V>,
     F: Fn(&K, &V) -> R,
 {
     let mut result = Vec::with_capacity(map.len());
-    for (k, v) in map {
+    for (k, v) in map.iter() {
         result.push(iteratee(k, v));
     }
     result
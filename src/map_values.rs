@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use crate::MapLike;
 
 /// Transforms the values of a map using a provided function.
 ///
@@ -7,7 +7,7 @@ use std::collections::HashMap;
 /// * `iteratee` - A function that takes a reference to a value and its key, returning a new value.
 ///
 /// # Returns
-/// * `HashMap<K, R>` - A new map with transformed values.
+/// * `M::Rebind<K, R>` - A new map of the same kind as the input with transformed values.
 ///
 /// # Examples
 /// ```
@@ -22,17 +22,14 @@ use std::collections::HashMap;
 /// assert_eq!(transformed.get("a"), Some(&10));
 /// assert_eq!(transformed.get("b"), Some(&20));
 /// ```
-pub fn map_values<K, V, R, F>(map: &HashMap<K, V>, iteratee: F) -> HashMap<K, R>
+pub fn map_values<M, K, V, R, F>(map: &M, iteratee: F) -> M::Rebind<K, R>
 where
-    K: Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    M::Rebind<K, R>: MapLike<Key = K, Value = R>,
+    K: Clone,
     F: Fn(&V, &K) -> R,
 {
-    let mut result = HashMap::with_capacity(map.len());
-    for (k, v) in map {
-        let new_value = iteratee(v, k);
-        result.insert(k.clone(), new_value);
-    }
-    result
+    MapLike::from_iter(map.iter().map(|(k, v)| (k.clone(), iteratee(v, k))))
 }
 
 #[cfg(test)]
@@ -66,4 +63,18 @@ mod tests {
         assert_eq!(transformed.get("x"), Some(&6));
         assert_eq!(transformed.get("y"), Some(&20));
     }
+
+    #[test]
+    fn test_map_values_btree_map() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        let transformed: BTreeMap<&str, String> = map_values(&map, |v, k| format!("{}{}", k, v));
+        assert_eq!(
+            transformed.into_iter().collect::<Vec<_>>(),
+            vec![("a", "a1".to_string()), ("b", "b2".to_string())]
+        );
+    }
 }
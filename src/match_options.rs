@@ -0,0 +1,49 @@
+/// The string metric used by [`closest_matches_with`](crate::closest_matches_with) to rank
+/// candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StringMetric {
+    /// [`levenshtein`](crate::levenshtein) distance; fewer edits rank first.
+    #[default]
+    Levenshtein,
+    /// [`damerau_levenshtein`](crate::damerau_levenshtein) distance, where swapped neighbours
+    /// count as one edit; fewer edits rank first.
+    DamerauLevenshtein,
+    /// [`jaro_winkler`](crate::jaro_winkler) similarity; higher similarity ranks first.
+    JaroWinkler,
+    /// [`lcs_distance`](crate::lcs_distance); fewer unmatched characters rank first.
+    LongestCommonSubsequence,
+}
+
+/// Options for [`closest_matches_with`](crate::closest_matches_with).
+///
+/// Both thresholds are inclusive. `min_similarity` applies to every metric through a similarity
+/// between 0.0 and 1.0: the Jaro–Winkler similarity itself, `1 - distance / longer length` for the
+/// Levenshtein metrics, and `1 - distance / total length` for the longest common subsequence. The
+/// default options rank by Levenshtein distance, compare case-sensitively and keep every
+/// candidate.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{closest_matches_with, MatchOptions, StringMetric};
+///
+/// let options = MatchOptions {
+///     metric: StringMetric::DamerauLevenshtein,
+///     case_insensitive: true,
+///     max_distance: Some(2),
+///     ..MatchOptions::default()
+/// };
+/// let commands = ["status", "stash", "commit", "checkout"];
+/// assert_eq!(closest_matches_with("STAUTS", &commands, 3, &options), vec!["status"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MatchOptions {
+    /// The metric that ranks candidates.
+    pub metric: StringMetric,
+    /// Compare the lowercased needle and candidates. The candidates are returned unchanged.
+    pub case_insensitive: bool,
+    /// Drop candidates more than this many edits away. Ignored by
+    /// [`StringMetric::JaroWinkler`], which has no edit count.
+    pub max_distance: Option<usize>,
+    /// Drop candidates whose similarity is below this value.
+    pub min_similarity: f64,
+}
@@ -1,47 +1,42 @@
-use std::ops::{Add, Div};
+use crate::common::ToF64;
+use crate::mean_by;
 
 /// Calculates the arithmetic mean of a collection of numbers.
-/// If the collection is empty, returns zero.
+/// Every primitive integer and float type is accepted. Values are converted to `f64`, so the mean
+/// of integers keeps its fractional part.
 ///
 /// # Arguments
 /// * `collection` - A slice of numbers.
 ///
 /// # Returns
-/// * `T` - The arithmetic mean of the collection.
+/// * `Option<f64>` - The arithmetic mean of the collection, or None if the collection is empty.
 ///
 /// # Examples
 /// ```rust
 /// use lowdash::mean;
 /// let numbers = vec![1.0, 2.0, 3.0, 4.0, 5.0];
 /// let result = mean(&numbers);
-/// assert_eq!(result, 3.0);
+/// assert_eq!(result, Some(3.0));
 /// ```
 ///
 /// ```rust
 /// use lowdash::mean;
-/// let numbers = vec![1, 2, 3, 4, 5];
+/// let numbers = vec![1, 2, 3, 4];
 /// let result = mean(&numbers);
-/// assert_eq!(result, 3);
+/// assert_eq!(result, Some(2.5));
 /// ```
 ///
 /// ```rust
 /// use lowdash::mean;
 /// let empty: Vec<f64> = vec![];
 /// let result = mean(&empty);
-/// assert_eq!(result, 0.0);
+/// assert_eq!(result, None);
 /// ```
-pub fn mean<T>(collection: &[T]) -> T
+pub fn mean<T>(collection: &[T]) -> Option<f64>
 where
-    T: Add<Output = T> + Div<Output = T> + From<u8> + Copy,
+    T: ToF64,
 {
-    let length = collection.len();
-    if length == 0 {
-        return T::from(0);
-    }
-
-    let sum = collection.iter().fold(T::from(0), |acc, &x| acc + x);
-
-    sum / T::from(length as u8)
+    mean_by(collection, |x| x.to_f64())
 }
 
 #[cfg(test)]
@@ -51,42 +46,80 @@ mod tests {
     #[test]
     fn test_mean_integers() {
         let numbers = vec![1, 2, 3, 4, 5];
-        assert_eq!(mean(&numbers), 3);
+        assert_eq!(mean(&numbers), Some(3.0));
+    }
+
+    #[test]
+    fn test_mean_wide_integers() {
+        assert_eq!(mean(&[1i64, 2, 3]), Some(2.0));
+        assert_eq!(mean(&[1u64, 2]), Some(1.5));
+        assert_eq!(mean(&[4usize, 8, 9]), Some(7.0));
     }
 
     #[test]
     fn test_mean_floats() {
         let numbers = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-        assert_eq!(mean(&numbers), 3.0);
+        assert_eq!(mean(&numbers), Some(3.0));
     }
 
     #[test]
     fn test_mean_empty() {
         let empty: Vec<f64> = vec![];
-        assert_eq!(mean(&empty), 0.0);
+        assert_eq!(mean(&empty), None);
     }
 
     #[test]
     fn test_mean_single_element() {
         let numbers = vec![42];
-        assert_eq!(mean(&numbers), 42);
+        assert_eq!(mean(&numbers), Some(42.0));
     }
 
     #[test]
     fn test_mean_negative_numbers() {
         let numbers = vec![-1.0, -2.0, -3.0, -4.0, -5.0];
-        assert_eq!(mean(&numbers), -3.0);
+        assert_eq!(mean(&numbers), Some(-3.0));
     }
 
     #[test]
     fn test_mean_mixed_positive_negative() {
         let numbers = vec![-2, -1, 0, 1, 2];
-        assert_eq!(mean(&numbers), 0);
+        assert_eq!(mean(&numbers), Some(0.0));
     }
 
     #[test]
     fn test_mean_decimal_result() {
         let numbers = vec![1.0, 2.0, 3.0];
-        assert_eq!(mean(&numbers), 2.0);
+        assert_eq!(mean(&numbers), Some(2.0));
+    }
+
+    #[test]
+    fn test_mean_integers_keep_fraction() {
+        let numbers = vec![1, 2];
+        assert_eq!(mean(&numbers), Some(1.5));
+    }
+
+    #[test]
+    fn test_mean_256_elements() {
+        // The length used to be truncated to a u8, so 256 elements divided by 0.
+        let numbers = vec![2.0; 256];
+        assert_eq!(mean(&numbers), Some(2.0));
+    }
+
+    #[test]
+    fn test_mean_300_integers() {
+        let numbers: Vec<u16> = (1..=300).collect();
+        assert_eq!(mean(&numbers), Some(150.5));
+    }
+
+    #[test]
+    fn test_mean_one_million_elements() {
+        let numbers: Vec<u32> = (0..1_000_000).collect();
+        assert_eq!(mean(&numbers), Some(499_999.5));
+    }
+
+    #[test]
+    fn test_mean_many_small_floats() {
+        let numbers = vec![0.1; 10_000];
+        assert!((mean(&numbers).unwrap() - 0.1).abs() < 1e-15);
     }
 }
@@ -1,11 +1,15 @@
+use crate::moments::compensated_sum;
+
 /// Calculates the mean value of a collection after applying a transformation function to each element.
+/// The values are added with compensated summation, so the result does not drift on long
+/// collections of values with different magnitudes.
 ///
 /// # Arguments
 /// * `collection` - A slice of items to calculate the mean from
 /// * `iteratee` - A function that transforms each item before calculating the mean
 ///
 /// # Returns
-/// The mean value after applying the transformation
+/// * `Option<f64>` - The mean value after applying the transformation, or None if the collection is empty
 ///
 /// # Examples
 /// ```rust
@@ -13,26 +17,24 @@
 ///
 /// let objects = vec![(1, 4), (2, 6), (3, 8)];
 /// let mean = mean_by(&objects, |&(x, _)| x as f64);
-/// assert!((mean - 2.0).abs() < f64::EPSILON);
+/// assert!((mean.unwrap() - 2.0).abs() < f64::EPSILON);
 ///
 /// // Calculate mean of y coordinates
 /// let mean_y = mean_by(&objects, |&(_, y)| y as f64);
-/// assert!((mean_y - 6.0).abs() < f64::EPSILON);
+/// assert!((mean_y.unwrap() - 6.0).abs() < f64::EPSILON);
 /// ```
-pub fn mean_by<T, F>(collection: &[T], iteratee: F) -> f64
+pub fn mean_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
 where
     F: Fn(&T) -> f64,
 {
     let length = collection.len();
     if length == 0 {
-        return 0.0;
+        return None;
     }
 
-    let sum = collection
-        .iter()
-        .fold(0.0, |acc, item| acc + iteratee(item));
+    let sum = compensated_sum(collection.iter().map(iteratee));
 
-    sum / length as f64
+    Some(sum / length as f64)
 }
 
 #[cfg(test)]
@@ -45,11 +47,11 @@ mod tests {
         let numbers = vec![(1, 10), (2, 20), (3, 30)];
 
         // Test x coordinates
-        let mean_x = mean_by(&numbers, |&(x, _)| x as f64);
+        let mean_x = mean_by(&numbers, |&(x, _)| x as f64).unwrap();
         assert!((mean_x - 2.0).abs() < EPSILON);
 
         // Test y coordinates
-        let mean_y = mean_by(&numbers, |&(_, y)| y as f64);
+        let mean_y = mean_by(&numbers, |&(_, y)| y as f64).unwrap();
         assert!((mean_y - 20.0).abs() < EPSILON);
     }
 
@@ -57,13 +59,13 @@ mod tests {
     fn test_mean_by_empty() {
         let empty: Vec<(i32, i32)> = vec![];
         let mean = mean_by(&empty, |&(x, _)| x as f64);
-        assert_eq!(mean, 0.0);
+        assert_eq!(mean, None);
     }
 
     #[test]
     fn test_mean_by_single_element() {
         let numbers = vec![(5, 10)];
-        let mean = mean_by(&numbers, |&(x, _)| x as f64);
+        let mean = mean_by(&numbers, |&(x, _)| x as f64).unwrap();
         assert!((mean - 5.0).abs() < EPSILON);
     }
 }
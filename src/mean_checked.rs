@@ -0,0 +1,77 @@
+use crate::common::ToF64;
+use crate::{mean, LowdashError};
+
+/// Calculates the arithmetic mean of a collection of numbers, reporting why no meaningful mean
+/// exists instead of returning `None`, NaN or an infinity.
+///
+/// # Arguments
+/// * `collection` - A slice of numbers.
+///
+/// # Returns
+/// * `Ok(f64)` - The arithmetic mean of the collection.
+/// * `Err(LowdashError::EmptyCollection)` - If the collection is empty.
+/// * `Err(LowdashError::InvalidArgument)` - If the mean is not finite, because a value is NaN or
+///   infinite or the sum overflowed.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{mean_checked, LowdashError};
+///
+/// assert_eq!(mean_checked(&[1, 2, 3, 4]), Ok(2.5));
+/// assert_eq!(
+///     mean_checked::<f64>(&[]),
+///     Err(LowdashError::EmptyCollection { function: "mean" })
+/// );
+/// assert!(mean_checked(&[1.0, f64::NAN]).is_err());
+/// ```
+pub fn mean_checked<T>(collection: &[T]) -> Result<f64, LowdashError>
+where
+    T: ToF64,
+{
+    let result = mean(collection).ok_or(LowdashError::EmptyCollection { function: "mean" })?;
+    if !result.is_finite() {
+        return Err(LowdashError::InvalidArgument {
+            function: "mean",
+            reason: "Mean is not finite",
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_checked_ok() {
+        assert_eq!(mean_checked(&[1.0, 2.0, 6.0]), Ok(3.0));
+        assert_eq!(mean_checked(&[7u8]), Ok(7.0));
+    }
+
+    #[test]
+    fn test_mean_checked_large_collection() {
+        let numbers: Vec<i32> = (1..=1000).collect();
+        assert_eq!(mean_checked(&numbers), Ok(500.5));
+    }
+
+    #[test]
+    fn test_mean_checked_empty() {
+        let empty: Vec<i32> = vec![];
+        assert_eq!(
+            mean_checked(&empty),
+            Err(LowdashError::EmptyCollection { function: "mean" })
+        );
+    }
+
+    #[test]
+    fn test_mean_checked_not_finite() {
+        let expected = Err(LowdashError::InvalidArgument {
+            function: "mean",
+            reason: "Mean is not finite",
+        });
+        assert_eq!(mean_checked(&[1.0, f64::NAN]), expected);
+        assert_eq!(mean_checked(&[1.0, f64::INFINITY]), expected);
+        assert_eq!(mean_checked(&[f64::MAX, f64::MAX]), expected);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::mode_by;
+
+/// Find the most frequent values of a collection.
+/// Every value that reaches the highest frequency is returned, so a multi-modal collection yields
+/// several values, in the order of their first occurrence.
+/// `0.0` and `-0.0` count as the same value, and NaN values are ignored.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Vec<f64>` - The modal values, or an empty vector if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::mode;
+///
+/// let numbers = vec![1, 2, 2, 3, 3, 4];
+/// assert_eq!(mode(&numbers), vec![2.0, 3.0]);
+///
+/// let empty: Vec<i32> = vec![];
+/// assert!(mode(&empty).is_empty());
+/// ```
+pub fn mode<T>(collection: &[T]) -> Vec<f64>
+where
+    T: Copy + Into<f64>,
+{
+    mode_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_integers() {
+        let numbers = vec![7, 1, 7, 3, 7, 1];
+        assert_eq!(mode(&numbers), vec![7.0]);
+    }
+
+    #[test]
+    fn test_mode_floats_multi_modal() {
+        let numbers = vec![0.5, 1.5, 1.5, 0.5, 2.5];
+        assert_eq!(mode(&numbers), vec![0.5, 1.5]);
+    }
+
+    #[test]
+    fn test_mode_single() {
+        assert_eq!(mode(&[42u8]), vec![42.0]);
+    }
+
+    #[test]
+    fn test_mode_empty() {
+        let empty: Vec<f32> = vec![];
+        assert!(mode(&empty).is_empty());
+    }
+}
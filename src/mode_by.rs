@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+/// Find the most frequent values produced by applying an iteratee to each element of a collection.
+/// Every value that reaches the highest frequency is returned, so a multi-modal collection yields
+/// several values, in the order of their first occurrence.
+/// `0.0` and `-0.0` count as the same value, and NaN values are ignored.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to count
+///
+/// # Returns
+/// * `Vec<f64>` - The modal values, or an empty vector if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::mode_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [30, 25, 30, 40, 25]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// assert_eq!(mode_by(&people, |p| p.age as f64), vec![30.0, 25.0]);
+/// ```
+pub fn mode_by<T, F>(collection: &[T], iteratee: F) -> Vec<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    // Keyed by bit pattern; the entry holds the value, its count and its first position.
+    let mut counts: HashMap<u64, (f64, usize, usize)> = HashMap::new();
+    for (index, item) in collection.iter().enumerate() {
+        let value = iteratee(item);
+        if value.is_nan() {
+            continue;
+        }
+        let value = if value == 0.0 { 0.0 } else { value };
+        counts.entry(value.to_bits()).or_insert((value, 0, index)).1 += 1;
+    }
+
+    let highest = counts
+        .values()
+        .map(|&(_, count, _)| count)
+        .max()
+        .unwrap_or(0);
+    let mut modes: Vec<(f64, usize, usize)> = counts
+        .into_values()
+        .filter(|&(_, count, _)| count == highest)
+        .collect();
+    modes.sort_by_key(|&(_, _, first)| first);
+
+    modes.into_iter().map(|(value, _, _)| value).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_by_multi_modal_ties_in_first_occurrence_order() {
+        let words = vec!["fig", "pear", "kiwi", "apple", "plum", "yam", "oat"];
+        // Lengths 3 and 4 both appear three times; 3 is seen first
+        assert_eq!(mode_by(&words, |w| w.len() as f64), vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_mode_by_all_distinct_keys_are_all_modes() {
+        let points = vec![(4, 'a'), (2, 'b'), (9, 'c')];
+        assert_eq!(mode_by(&points, |&(x, _)| x as f64), vec![4.0, 2.0, 9.0]);
+    }
+
+    #[test]
+    fn test_mode_by_with_struct() {
+        struct Order {
+            quantity: u32,
+        }
+
+        let orders: Vec<Order> = [1, 3, 3, 2, 1, 3]
+            .iter()
+            .map(|&quantity| Order { quantity })
+            .collect();
+        assert_eq!(mode_by(&orders, |o| o.quantity as f64), vec![3.0]);
+    }
+
+    #[test]
+    fn test_mode_by_signed_zero_and_nan() {
+        let readings = vec![-0.0, f64::NAN, 0.0, f64::NAN, f64::NAN, 1.0];
+        assert_eq!(mode_by(&readings, |&r| r), vec![0.0]);
+    }
+
+    #[test]
+    fn test_mode_by_empty() {
+        let empty: Vec<&str> = vec![];
+        assert!(mode_by(&empty, |w| w.len() as f64).is_empty());
+    }
+}
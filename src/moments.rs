@@ -0,0 +1,196 @@
+/// Running central moments of a stream of values, updated with Welford's algorithm extended to
+/// the third and fourth moments (Pébay, 2008).
+///
+/// Only sums of squared differences from the running mean are accumulated, so the results stay
+/// accurate when the values are large and close together, where the naive sum of squares cancels.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Moments {
+    pub(crate) count: usize,
+    pub(crate) mean: f64,
+    pub(crate) m2: f64,
+    pub(crate) m3: f64,
+    pub(crate) m4: f64,
+}
+
+impl Moments {
+    /// Accumulates the moments of every value produced by the iterator.
+    pub(crate) fn from_values<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let mut moments = Moments::default();
+        for value in values {
+            moments.push(value);
+        }
+        moments
+    }
+
+    /// Adds one value to the moments.
+    pub(crate) fn push(&mut self, value: f64) {
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    /// Combines the moments of another stream into these, as if its values had been pushed here.
+    pub(crate) fn merge(&mut self, other: &Moments) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+
+        let na = self.count as f64;
+        let nb = other.count as f64;
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+        let m3 = self.m3
+            + other.m3
+            + delta2 * delta * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta2 * delta2 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+
+        self.count += other.count;
+        self.mean += delta * nb / n;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+    }
+
+    /// Population variance, or `None` if no value was added.
+    pub(crate) fn variance(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.m2 / self.count as f64)
+    }
+
+    /// Sample variance (Bessel's correction), or `None` with fewer than two values.
+    pub(crate) fn sample_variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Population skewness, or `None` if no value was added or all values are equal.
+    pub(crate) fn skewness(&self) -> Option<f64> {
+        if self.count == 0 || self.m2 == 0.0 {
+            return None;
+        }
+        Some((self.count as f64).sqrt() * self.m3 / self.m2.powf(1.5))
+    }
+
+    /// Population excess kurtosis, or `None` if no value was added or all values are equal.
+    pub(crate) fn kurtosis(&self) -> Option<f64> {
+        if self.count == 0 || self.m2 == 0.0 {
+            return None;
+        }
+        Some(self.count as f64 * self.m4 / (self.m2 * self.m2) - 3.0)
+    }
+}
+
+/// Sums the values with Neumaier's compensated summation, which keeps the low-order bits that a
+/// plain running sum loses when adding values of very different magnitudes.
+pub(crate) fn compensated_sum<I>(values: I) -> f64
+where
+    I: IntoIterator<Item = f64>,
+{
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for value in values {
+        let total = sum + value;
+        if sum.abs() >= value.abs() {
+            compensation += (sum - total) + value;
+        } else {
+            compensation += (value - total) + sum;
+        }
+        sum = total;
+    }
+
+    // Infinities make the compensation NaN; the plain sum is the right answer then.
+    if sum.is_finite() {
+        sum + compensation
+    } else {
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_central_moment(values: &[f64], k: i32) -> f64 {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        values.iter().map(|x| (x - mean).powi(k)).sum()
+    }
+
+    #[test]
+    fn test_moments_match_naive() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, 11.5, -3.25];
+        let moments = Moments::from_values(values);
+        assert_eq!(moments.count, values.len());
+        assert!((moments.m2 - naive_central_moment(&values, 2)).abs() < 1e-9);
+        assert!((moments.m3 - naive_central_moment(&values, 3)).abs() < 1e-9);
+        assert!((moments.m4 - naive_central_moment(&values, 4)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_moments_empty() {
+        let moments = Moments::from_values(Vec::new());
+        assert_eq!(moments.variance(), None);
+        assert_eq!(moments.sample_variance(), None);
+        assert_eq!(moments.skewness(), None);
+        assert_eq!(moments.kurtosis(), None);
+    }
+
+    #[test]
+    fn test_moments_large_offset() {
+        let offset = 1e9;
+        let moments = Moments::from_values([4.0, 7.0, 13.0, 16.0].map(|x| x + offset));
+        assert!((moments.variance().unwrap() - 22.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_moments_merge_matches_single_stream() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, 11.5, -3.25, 0.5];
+        let expected = Moments::from_values(values);
+        for split in 0..=values.len() {
+            let mut left = Moments::from_values(values[..split].iter().copied());
+            left.merge(&Moments::from_values(values[split..].iter().copied()));
+            assert_eq!(left.count, expected.count);
+            assert!((left.mean - expected.mean).abs() < 1e-12);
+            assert!((left.m2 - expected.m2).abs() < 1e-9);
+            assert!((left.m3 - expected.m3).abs() < 1e-9);
+            assert!((left.m4 - expected.m4).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_compensated_sum() {
+        assert_eq!(compensated_sum([1.0, 1e100, 1.0, -1e100]), 2.0);
+        assert_eq!(compensated_sum(Vec::new()), 0.0);
+        assert_eq!(compensated_sum(vec![0.1; 10]), 1.0);
+        assert_eq!(compensated_sum([1.0, f64::INFINITY]), f64::INFINITY);
+    }
+}
@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+
+/// Compare two strings in natural order, so that numbers inside them compare by value:
+/// `"file2" < "file10"`.
+///
+/// Both strings are split into runs of ASCII digits and everything else. Digit runs compare as
+/// numbers of any length, without overflow; other text compares character by character, case
+/// sensitively. When two strings only differ in leading zeros, the one whose first differing
+/// number has fewer digits comes first (`"a1" < "a01"`), which keeps the order total.
+///
+/// # Arguments
+/// * `a` - The first string.
+/// * `b` - The second string.
+///
+/// # Returns
+/// * `Ordering` - How `a` compares to `b` in natural order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::natural_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+/// assert_eq!(natural_cmp("v1.10.0", "v1.9.3"), Ordering::Greater);
+/// assert_eq!(natural_cmp("img007", "img7"), Ordering::Greater);
+/// assert_eq!(natural_cmp("same", "same"), Ordering::Equal);
+///
+/// let mut files = vec!["file10.txt", "file2.txt", "file1.txt"];
+/// files.sort_by(|a, b| natural_cmp(a, b));
+/// assert_eq!(files, vec!["file1.txt", "file2.txt", "file10.txt"]);
+/// ```
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    // Working on bytes is safe: a byte that is an ASCII digit never occurs inside a multi-byte
+    // UTF-8 sequence, and byte order matches character order for everything else.
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    let mut tie_break = Ordering::Equal;
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let left = digit_run(a, i);
+            let right = digit_run(b, j);
+            let ordering = compare_numbers(left, right);
+            if ordering.is_ne() {
+                return ordering;
+            }
+            if tie_break.is_eq() {
+                tie_break = left.len().cmp(&right.len());
+            }
+            i += left.len();
+            j += right.len();
+        } else {
+            if a[i] != b[j] {
+                return a[i].cmp(&b[j]);
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    (a.len() - i).cmp(&(b.len() - j)).then(tie_break)
+}
+
+/// The run of ASCII digits in `bytes` starting at `start`.
+fn digit_run(bytes: &[u8], start: usize) -> &[u8] {
+    let end = bytes[start..]
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .map_or(bytes.len(), |offset| start + offset);
+    &bytes[start..end]
+}
+
+/// Compares two digit runs by numeric value, ignoring leading zeros.
+fn compare_numbers(left: &[u8], right: &[u8]) -> Ordering {
+    let trim = |digits: &[u8]| -> usize { digits.iter().take_while(|&&d| d == b'0').count() };
+    let left = &left[trim(left)..];
+    let right = &right[trim(right)..];
+    left.len().cmp(&right.len()).then_with(|| left.cmp(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp_numbers_by_value() {
+        assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("file10", "file2"), Ordering::Greater);
+        assert_eq!(natural_cmp("9", "10"), Ordering::Less);
+        assert_eq!(natural_cmp("a2b10", "a2b9"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_natural_cmp_large_numbers_do_not_overflow() {
+        let small = "n99999999999999999999999999999999999999";
+        let large = "n100000000000000000000000000000000000000";
+        assert_eq!(natural_cmp(small, large), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_cmp_leading_zeros() {
+        assert_eq!(natural_cmp("a01", "a1"), Ordering::Greater);
+        assert_eq!(natural_cmp("a1", "a01"), Ordering::Less);
+        assert_eq!(natural_cmp("a01", "a2"), Ordering::Less);
+        // The value of a later number outweighs leading zeros in an earlier one.
+        assert_eq!(natural_cmp("a01b2", "a1b1"), Ordering::Greater);
+        assert_eq!(natural_cmp("a01b1", "a1b1"), Ordering::Greater);
+        assert_eq!(natural_cmp("x001", "x001"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_natural_cmp_text_and_prefixes() {
+        assert_eq!(natural_cmp("abc", "abd"), Ordering::Less);
+        assert_eq!(natural_cmp("abc", "ab"), Ordering::Greater);
+        assert_eq!(natural_cmp("", "a"), Ordering::Less);
+        assert_eq!(natural_cmp("", ""), Ordering::Equal);
+        assert_eq!(natural_cmp("a", "1"), Ordering::Greater);
+        assert_eq!(natural_cmp("B", "a"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_cmp_unicode() {
+        assert_eq!(natural_cmp("é2", "é10"), Ordering::Less);
+        assert_eq!(natural_cmp("z", "é"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_cmp_is_a_total_order() {
+        let words = [
+            "a1", "a01", "a001", "a2", "a10", "a1b", "a", "b", "", "1", "01",
+        ];
+        for x in words {
+            assert_eq!(natural_cmp(x, x), Ordering::Equal);
+            for y in words {
+                assert_eq!(natural_cmp(x, y), natural_cmp(y, x).reverse());
+                if x != y {
+                    assert_ne!(natural_cmp(x, y), Ordering::Equal, "{} vs {}", x, y);
+                }
+                for z in words {
+                    if natural_cmp(x, y).is_le() && natural_cmp(y, z).is_le() {
+                        assert!(natural_cmp(x, z).is_le(), "{} {} {}", x, y, z);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::natural_cmp;
+
+/// Sort strings in natural order, so that numbers inside them sort by value.
+///
+/// See `natural_cmp` for how two strings are compared. To sort other items by a string field in
+/// natural order, pass `natural_cmp` as one of the comparators of `order_by`.
+///
+/// # Arguments
+/// * `collection` - A slice of strings to sort.
+///
+/// # Returns
+/// * `Vec<S>` - The strings in natural order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::natural_sort;
+///
+/// let files = vec!["file10.txt", "file2.txt", "file1.txt", "File3.txt"];
+/// assert_eq!(
+///     natural_sort(&files),
+///     vec!["File3.txt", "file1.txt", "file2.txt", "file10.txt"]
+/// );
+/// ```
+pub fn natural_sort<S>(collection: &[S]) -> Vec<S>
+where
+    S: AsRef<str> + Clone,
+{
+    let mut result = collection.to_vec();
+    result.sort_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_sort_strings() {
+        let names = vec![
+            String::from("track 10"),
+            String::from("track 9"),
+            String::from("track 1"),
+            String::from("track 100"),
+        ];
+        assert_eq!(
+            natural_sort(&names),
+            vec!["track 1", "track 9", "track 10", "track 100"]
+        );
+    }
+
+    #[test]
+    fn test_natural_sort_versions() {
+        let versions = vec!["v1.10", "v1.2", "v1.9", "v1.02"];
+        assert_eq!(
+            natural_sort(&versions),
+            vec!["v1.2", "v1.02", "v1.9", "v1.10"]
+        );
+    }
+
+    #[test]
+    fn test_natural_sort_empty() {
+        let empty: Vec<&str> = vec![];
+        assert!(natural_sort(&empty).is_empty());
+    }
+}
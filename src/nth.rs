@@ -1,18 +1,4 @@
-use std::error::Error;
-use std::fmt;
-
-#[derive(Debug, PartialEq)]
-pub struct NthError {
-    index: i64,
-}
-
-impl fmt::Display for NthError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "nth: {} out of slice bounds", self.index)
-    }
-}
-
-impl Error for NthError {}
+use crate::LowdashError;
 
 /// Returns the nth element from the collection.
 /// Supports both positive and negative indices.
@@ -24,7 +10,7 @@ impl Error for NthError {}
 ///
 /// # Returns
 /// * `Ok(&T)` - The element at the specified index
-/// * `Err(NthError)` - If the index is out of bounds
+/// * `Err(LowdashError::IndexOutOfBounds)` - If the index is out of bounds
 ///
 /// # Examples
 /// ```rust
@@ -40,11 +26,15 @@ impl Error for NthError {}
 /// let result = nth(&numbers, 10);
 /// assert!(result.is_err());
 /// ```
-pub fn nth<T>(collection: &[T], nth: i64) -> Result<&T, NthError> {
+pub fn nth<T>(collection: &[T], nth: i64) -> Result<&T, LowdashError> {
     let len = collection.len() as i64;
 
-    if nth >= len || -nth > len {
-        return Err(NthError { index: nth });
+    // `unsigned_abs` keeps `i64::MIN` from overflowing on negation
+    if nth >= len || (nth < 0 && nth.unsigned_abs() > len as u64) {
+        return Err(LowdashError::IndexOutOfBounds {
+            function: "nth",
+            index: nth,
+        });
     }
 
     let index = if nth >= 0 { nth } else { len + nth } as usize;
@@ -63,6 +53,18 @@ mod tests {
         assert_eq!(result.unwrap(), &3);
     }
 
+    #[test]
+    fn test_nth_min_index() {
+        let collection = vec![1, 2, 3];
+        assert_eq!(
+            nth(&collection, i64::MIN),
+            Err(LowdashError::IndexOutOfBounds {
+                function: "nth",
+                index: i64::MIN,
+            })
+        );
+    }
+
     #[test]
     fn test_nth_negative_index() {
         let collection = vec![1, 2, 3, 4, 5];
@@ -74,21 +76,39 @@ mod tests {
     fn test_nth_out_of_bounds_positive() {
         let collection = vec![1, 2, 3];
         let result = nth(&collection, 5);
-        assert_eq!(result.unwrap_err(), NthError { index: 5 });
+        assert_eq!(
+            result.unwrap_err(),
+            LowdashError::IndexOutOfBounds {
+                function: "nth",
+                index: 5,
+            }
+        );
     }
 
     #[test]
     fn test_nth_out_of_bounds_negative() {
         let collection = vec![1, 2, 3];
         let result = nth(&collection, -5);
-        assert_eq!(result.unwrap_err(), NthError { index: -5 });
+        assert_eq!(
+            result.unwrap_err(),
+            LowdashError::IndexOutOfBounds {
+                function: "nth",
+                index: -5,
+            }
+        );
     }
 
     #[test]
     fn test_nth_empty_collection() {
         let collection: Vec<i32> = vec![];
         let result = nth(&collection, 0);
-        assert_eq!(result.unwrap_err(), NthError { index: 0 });
+        assert_eq!(
+            result.unwrap_err(),
+            LowdashError::IndexOutOfBounds {
+                function: "nth",
+                index: 0,
+            }
+        );
     }
 
     #[test]
@@ -151,10 +171,16 @@ mod tests {
 
     #[test]
     fn test_nth_error_display() {
-        let error = NthError { index: 5 };
+        let error = LowdashError::IndexOutOfBounds {
+            function: "nth",
+            index: 5,
+        };
         assert_eq!(error.to_string(), "nth: 5 out of slice bounds");
 
-        let error = NthError { index: -3 };
+        let error = LowdashError::IndexOutOfBounds {
+            function: "nth",
+            index: -3,
+        };
         assert_eq!(error.to_string(), "nth: -3 out of slice bounds");
     }
 }
@@ -0,0 +1,124 @@
+use crate::common::checked_binomial;
+
+/// Returns the combination at a given rank among the combinations of `k` indices out of `0..n`,
+/// without generating the combinations before it.
+///
+/// Ranks follow the lexicographic order of `combinations`, which is also the order of
+/// `combination`: `combination(&items, k)[rank]` holds the items at
+/// `nth_combination(items.len(), k, rank)`. This makes it possible to split the combinations into
+/// ranges of ranks and hand each range to a different worker.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items in each combination.
+/// * `rank` - The zero-based position of the combination.
+///
+/// # Returns
+/// * `Option<Vec<usize>>` - The indices of the combination, or `None` if `rank` is not below the
+///   number of combinations.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{combination, nth_combination};
+///
+/// assert_eq!(nth_combination(5, 3, 0), Some(vec![0, 1, 2]));
+/// assert_eq!(nth_combination(5, 3, 6), Some(vec![1, 2, 3]));
+/// assert_eq!(nth_combination(5, 3, 10), None);
+///
+/// let items = ['a', 'b', 'c', 'd'];
+/// let indices = nth_combination(items.len(), 2, 4).unwrap();
+/// let picked: Vec<char> = indices.iter().map(|&i| items[i]).collect();
+/// assert_eq!(picked, combination(&items, 2)[4]);
+///
+/// // Ranks stay usable when the total count does not fit in a usize.
+/// assert_eq!(nth_combination(200, 100, 1).unwrap()[99], 100);
+/// ```
+pub fn nth_combination(n: usize, k: usize, rank: usize) -> Option<Vec<usize>> {
+    if k > n {
+        return None;
+    }
+
+    let mut rank = rank;
+    let mut result = Vec::with_capacity(k);
+    let mut candidate = 0;
+    for position in 0..k {
+        loop {
+            if candidate >= n {
+                return None;
+            }
+            // The number of combinations that pick `candidate` here; `None` means more than any
+            // rank can reach.
+            let block = checked_binomial(n - candidate - 1, k - position - 1);
+            match block {
+                Some(block) if rank >= block => {
+                    rank -= block;
+                    candidate += 1;
+                }
+                _ => break,
+            }
+        }
+        result.push(candidate);
+        candidate += 1;
+    }
+
+    if rank == 0 {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combinations;
+
+    #[test]
+    fn test_nth_combination_matches_enumeration() {
+        for n in 0..8 {
+            for k in 0..=n + 1 {
+                for (rank, expected) in combinations(n, k).enumerate() {
+                    assert_eq!(
+                        nth_combination(n, k, rank),
+                        Some(expected),
+                        "n = {}, k = {}, rank = {}",
+                        n,
+                        k,
+                        rank
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nth_combination_out_of_range() {
+        assert_eq!(nth_combination(4, 2, 6), None);
+        assert_eq!(nth_combination(4, 0, 1), None);
+        assert_eq!(nth_combination(2, 3, 0), None);
+        assert_eq!(nth_combination(4, 2, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_nth_combination_empty() {
+        assert_eq!(nth_combination(3, 0, 0), Some(vec![]));
+        assert_eq!(nth_combination(0, 0, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_nth_combination_last() {
+        assert_eq!(
+            nth_combination(60, 5, 5_461_511),
+            Some(vec![55, 56, 57, 58, 59])
+        );
+    }
+
+    #[test]
+    fn test_nth_combination_huge_count() {
+        let first = nth_combination(200, 100, 0).unwrap();
+        assert_eq!(first, (0..100).collect::<Vec<_>>());
+        let far = nth_combination(200, 100, usize::MAX).unwrap();
+        assert_eq!(far.len(), 100);
+        assert!(far.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
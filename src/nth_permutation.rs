@@ -0,0 +1,141 @@
+use crate::common::checked_permutations;
+
+/// Returns the permutation at a given rank among the permutations of `k` indices out of `0..n`,
+/// without generating the permutations before it.
+///
+/// Ranks follow the lexicographic order of `permutations`, which is also the order of
+/// `permutation`: `permutation(&items, k)[rank]` holds the items at
+/// `nth_permutation(items.len(), k, rank)`.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items in each permutation.
+/// * `rank` - The zero-based position of the permutation.
+///
+/// # Returns
+/// * `Option<Vec<usize>>` - The indices of the permutation, or `None` if `rank` is not below the
+///   number of permutations.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{nth_permutation, permutation};
+///
+/// assert_eq!(nth_permutation(3, 3, 0), Some(vec![0, 1, 2]));
+/// assert_eq!(nth_permutation(3, 3, 3), Some(vec![1, 2, 0]));
+/// assert_eq!(nth_permutation(3, 3, 6), None);
+///
+/// let items = ["north", "east", "south", "west"];
+/// let indices = nth_permutation(items.len(), 2, 7).unwrap();
+/// let picked: Vec<&str> = indices.iter().map(|&i| items[i]).collect();
+/// assert_eq!(picked, permutation(&items, 2)[7]);
+///
+/// // The millionth ordering of 30 items, although 30! does not fit in a usize.
+/// let shuffled = nth_permutation(30, 30, 999_999).unwrap();
+/// assert_eq!(&shuffled[..20], &(0..20).collect::<Vec<_>>()[..]);
+/// ```
+pub fn nth_permutation(n: usize, k: usize, rank: usize) -> Option<Vec<usize>> {
+    if k > n {
+        return None;
+    }
+
+    // Only the chosen indices are kept, sorted, so memory grows with `k` rather than `n`.
+    let mut taken: Vec<usize> = Vec::with_capacity(k);
+    let mut rank = rank;
+    let mut result = Vec::with_capacity(k);
+    for position in 0..k {
+        // Each choice here is followed by this many arrangements of the remaining positions;
+        // `None` means more than any rank can reach, so the smallest unused index is picked.
+        let block = checked_permutations(n - position - 1, k - position - 1);
+        let choice = match block {
+            Some(block) => {
+                let choice = rank / block;
+                rank %= block;
+                choice
+            }
+            None => 0,
+        };
+        // The `choice`-th smallest unused index: step past every taken index at or below it.
+        let mut index = choice;
+        for &t in &taken {
+            if t > index {
+                break;
+            }
+            index += 1;
+        }
+        if index >= n {
+            return None;
+        }
+        let at = taken.partition_point(|&t| t < index);
+        taken.insert(at, index);
+        result.push(index);
+    }
+
+    if rank == 0 {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permutations;
+
+    #[test]
+    fn test_nth_permutation_matches_enumeration() {
+        for n in 0..7 {
+            for k in 0..=n + 1 {
+                for (rank, expected) in permutations(n, k).enumerate() {
+                    assert_eq!(
+                        nth_permutation(n, k, rank),
+                        Some(expected),
+                        "n = {}, k = {}, rank = {}",
+                        n,
+                        k,
+                        rank
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nth_permutation_out_of_range() {
+        assert_eq!(nth_permutation(3, 2, 6), None);
+        assert_eq!(nth_permutation(3, 0, 1), None);
+        assert_eq!(nth_permutation(2, 3, 0), None);
+        assert_eq!(nth_permutation(4, 4, usize::MAX), None);
+    }
+
+    #[test]
+    fn test_nth_permutation_empty() {
+        assert_eq!(nth_permutation(0, 0, 0), Some(vec![]));
+        assert_eq!(nth_permutation(5, 0, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_nth_permutation_last() {
+        assert_eq!(
+            nth_permutation(20, 20, 2_432_902_008_176_640_000 - 1),
+            Some((0..20).rev().collect())
+        );
+    }
+
+    #[test]
+    fn test_nth_permutation_huge_count() {
+        let far = nth_permutation(25, 25, usize::MAX).unwrap();
+        let mut sorted = far.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..25).collect::<Vec<_>>());
+        assert_eq!(&far[..4], &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_nth_permutation_large_n_small_k() {
+        let n = usize::MAX;
+        assert_eq!(nth_permutation(n, 2, 0), Some(vec![0, 1]));
+        assert_eq!(nth_permutation(n, 2, 1), Some(vec![0, 2]));
+        assert_eq!(nth_permutation(n, 2, n - 1), Some(vec![1, 0]));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Filters a map by omitting key-value pairs that satisfy a predicate.
 ///
 /// Iterates over each key-value pair in the input map and excludes it from the result map
@@ -8,7 +10,7 @@
 /// * `predicate` - A function that takes a key and value, and returns `true` if the pair should be omitted.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - A new map containing only the key-value pairs that do not satisfy the predicate.
+/// * `M` - A new map of the same type as the input containing only the key-value pairs that do not satisfy the predicate.
 ///
 /// # Examples
 /// ```rust
@@ -27,22 +29,18 @@
 /// assert!(!result.contains_key("b"));
 /// assert!(!result.contains_key("c"));
 /// ```
-pub fn omit_by<K, V, F>(
-    map: &std::collections::HashMap<K, V>,
-    predicate: F,
-) -> std::collections::HashMap<K, V>
+pub fn omit_by<M, K, V, F>(map: &M, predicate: F) -> M
 where
-    K: std::cmp::Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
     F: Fn(&K, &V) -> bool,
 {
-    let mut result = std::collections::HashMap::with_capacity(map.len());
-    for (k, v) in map.iter() {
-        if !predicate(k, v) {
-            result.insert(k.clone(), v.clone());
-        }
-    }
-    result
+    MapLike::from_iter(
+        map.iter()
+            .filter(|(k, v)| !predicate(k, v))
+            .map(|(k, v)| (k.clone(), v.clone())),
+    )
 }
 
 #[cfg(test)]
@@ -140,4 +138,20 @@ mod tests {
         assert!(!result.contains_key("b"));
         assert!(!result.contains_key("c"));
     }
+
+    #[test]
+    fn test_omit_by_btree_map() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        let result: BTreeMap<&str, i32> = omit_by(&map, |_, v| *v == 2);
+        assert_eq!(
+            result.into_iter().collect::<Vec<_>>(),
+            vec![("a", 1), ("c", 3)]
+        );
+    }
 }
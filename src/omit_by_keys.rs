@@ -1,6 +1,8 @@
+use crate::MapLike;
+
 /// Filters a map by omitting specified keys.
 ///
-/// Iterates over the provided keys and removes them from a cloned version of the input map.
+/// Iterates over the provided keys and removes them from a copy of the input map.
 /// Only key-value pairs not present in the keys slice are included in the result map.
 ///
 /// # Arguments
@@ -8,7 +10,7 @@
 /// * `keys` - A slice of keys to omit from the result map.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - A new map containing only the key-value pairs that are not in the keys slice.
+/// * `M` - A new map of the same type as the input containing only the key-value pairs that are not in the keys slice.
 ///
 /// # Examples
 /// ```rust
@@ -27,15 +29,13 @@
 /// assert!(result.contains_key("c"));
 /// assert!(!result.contains_key("b"));
 /// ```
-pub fn omit_by_keys<K, V>(
-    map: &std::collections::HashMap<K, V>,
-    keys: &[K],
-) -> std::collections::HashMap<K, V>
+pub fn omit_by_keys<M, K, V>(map: &M, keys: &[K]) -> M
 where
-    K: std::cmp::Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
 {
-    let mut result = map.clone();
+    let mut result: M = MapLike::from_iter(map.iter().map(|(k, v)| (k.clone(), v.clone())));
     for key in keys {
         result.remove(key);
     }
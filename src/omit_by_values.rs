@@ -1,3 +1,7 @@
+use crate::MapLike;
+use std::collections::HashSet;
+use std::hash::Hash;
+
 /// Filters a map by omitting key-value pairs that have values present in the provided values slice.
 ///
 /// Iterates over each key-value pair in the input map and excludes it from the result map
@@ -8,7 +12,7 @@
 /// * `values` - A slice of values to omit from the result map.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - A new map containing only the key-value pairs that do not have values in `values`.
+/// * `M` - A new map of the same type as the input containing only the key-value pairs that do not have values in `values`.
 ///
 /// # Examples
 /// ```rust
@@ -27,22 +31,18 @@
 /// assert!(result.contains_key("c"));
 /// assert!(!result.contains_key("b"));
 /// ```
-use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
-
-pub fn omit_by_values<K, V>(map: &HashMap<K, V>, values: &[V]) -> HashMap<K, V>
+pub fn omit_by_values<M, K, V>(map: &M, values: &[V]) -> M
 where
-    K: Eq + Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Eq + Hash + Clone,
 {
-    let value_set: HashSet<V> = values.iter().cloned().collect();
-    let mut result = HashMap::new();
-    for (k, v) in map.iter() {
-        if !value_set.contains(v) {
-            result.insert(k.clone(), v.clone());
-        }
-    }
-    result
+    let value_set: HashSet<&V> = values.iter().collect();
+    MapLike::from_iter(
+        map.iter()
+            .filter(|(_, v)| !value_set.contains(v))
+            .map(|(k, v)| (k.clone(), v.clone())),
+    )
 }
 
 #[cfg(test)]
@@ -0,0 +1,495 @@
+use crate::moments::Moments;
+
+/// A cluster of nearby values in the quantile sketch, summarised by their mean and count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Streaming accumulator for summary statistics.
+///
+/// Values are ingested one at a time, so the samples never need to be held in memory. Count,
+/// minimum, maximum, mean, variance, skewness and kurtosis are exact, maintained with Welford's
+/// algorithm. Quantiles are estimated with a merging t-digest: values are kept individually until
+/// the sketch grows beyond its compression, and clustered afterwards, with small clusters near the
+/// tails so extreme quantiles stay accurate. With at most `compression` values the quantiles are
+/// exact and match `percentile`.
+///
+/// Two accumulators fed on different threads can be combined with `merge`.
+///
+/// NaN values are ignored.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::OnlineStats;
+///
+/// let mut stats = OnlineStats::new();
+/// for value in [2, 4, 4, 4, 5, 5, 7, 9] {
+///     stats.push(value);
+/// }
+///
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.min(), Some(2.0));
+/// assert_eq!(stats.max(), Some(9.0));
+/// assert_eq!(stats.mean(), Some(5.0));
+/// assert!((stats.variance().unwrap() - 4.0).abs() < 1e-12);
+/// assert_eq!(stats.median(), lowdash::median(&[2, 4, 4, 4, 5, 5, 7, 9]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnlineStats {
+    moments: Moments,
+    min: f64,
+    max: f64,
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+}
+
+impl OnlineStats {
+    /// The compression used by `new`.
+    pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+    /// Creates an empty accumulator with the default compression of 100.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::OnlineStats;
+    ///
+    /// let stats = OnlineStats::new();
+    /// assert_eq!(stats.count(), 0);
+    /// assert_eq!(stats.mean(), None);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_compression(Self::DEFAULT_COMPRESSION)
+    }
+
+    /// Creates an empty accumulator whose quantile sketch keeps roughly `compression` clusters.
+    /// Higher values give more accurate quantiles and use more memory. Values below 1 are
+    /// treated as 1.
+    ///
+    /// # Arguments
+    /// * `compression` - The size of the quantile sketch.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::OnlineStats;
+    ///
+    /// let mut stats = OnlineStats::with_compression(20.0);
+    /// stats.extend(0..10_000);
+    /// let median = stats.median().unwrap();
+    /// assert!((median - 4999.5).abs() < 100.0);
+    /// ```
+    pub fn with_compression(compression: f64) -> Self {
+        OnlineStats {
+            moments: Moments::default(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            compression: compression.max(1.0),
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Adds a value to the accumulator. NaN values are ignored.
+    ///
+    /// # Arguments
+    /// * `value` - The value to add.
+    pub fn push<T>(&mut self, value: T)
+    where
+        T: Into<f64>,
+    {
+        let value = value.into();
+        if value.is_nan() {
+            return;
+        }
+
+        self.moments.push(value);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.centroids = self.compressed();
+            self.buffer.clear();
+        }
+    }
+
+    /// Adds every value of another accumulator, as if they had been pushed into this one.
+    /// The sketch keeps the compression of `self`.
+    ///
+    /// # Arguments
+    /// * `other` - The accumulator to merge in.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::OnlineStats;
+    ///
+    /// let mut left: OnlineStats = (1..=50).collect();
+    /// let right: OnlineStats = (51..=100).collect();
+    /// left.merge(&right);
+    ///
+    /// assert_eq!(left.count(), 100);
+    /// assert_eq!(left.mean(), Some(50.5));
+    /// assert_eq!(left.max(), Some(100.0));
+    /// ```
+    pub fn merge(&mut self, other: &OnlineStats) {
+        if other.count() == 0 {
+            return;
+        }
+
+        self.moments.merge(&other.moments);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.buffer.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.centroids = self.compressed();
+        self.buffer.clear();
+    }
+
+    /// Returns the number of values added.
+    pub fn count(&self) -> usize {
+        self.moments.count
+    }
+
+    /// Returns the smallest value added, or None if no value was added.
+    pub fn min(&self) -> Option<f64> {
+        (self.count() > 0).then_some(self.min)
+    }
+
+    /// Returns the largest value added, or None if no value was added.
+    pub fn max(&self) -> Option<f64> {
+        (self.count() > 0).then_some(self.max)
+    }
+
+    /// Returns the arithmetic mean, or None if no value was added.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count() > 0).then_some(self.moments.mean)
+    }
+
+    /// Returns the population variance, or None if no value was added.
+    pub fn variance(&self) -> Option<f64> {
+        self.moments.variance()
+    }
+
+    /// Returns the sample variance, or None if fewer than two values were added.
+    pub fn sample_variance(&self) -> Option<f64> {
+        self.moments.sample_variance()
+    }
+
+    /// Returns the population standard deviation, or None if no value was added.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the sample standard deviation, or None if fewer than two values were added.
+    pub fn sample_std_dev(&self) -> Option<f64> {
+        self.sample_variance().map(f64::sqrt)
+    }
+
+    /// Returns the population skewness, or None if no value was added or all values are equal.
+    pub fn skewness(&self) -> Option<f64> {
+        self.moments.skewness()
+    }
+
+    /// Returns the population excess kurtosis, or None if no value was added or all values are
+    /// equal.
+    pub fn kurtosis(&self) -> Option<f64> {
+        self.moments.kurtosis()
+    }
+
+    /// Estimates the quantile `q` of the values added, interpolating linearly between closest
+    /// ranks like `percentile`.
+    ///
+    /// # Arguments
+    /// * `q` - The quantile to estimate, between 0 and 1 inclusive.
+    ///
+    /// # Returns
+    /// * `Option<f64>` - The estimated quantile, or None if no value was added or `q` is out of range.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::OnlineStats;
+    ///
+    /// let stats: OnlineStats = (1..=100_000).collect();
+    /// let p99 = stats.quantile(0.99).unwrap();
+    /// assert!((p99 - 99_000.0).abs() < 100.0);
+    /// assert_eq!(stats.quantile(1.5), None);
+    /// ```
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.count() == 0 || !(0.0..=1.0).contains(&q) {
+            return None;
+        }
+
+        let centroids = self.compressed();
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+
+        // Positions are measured in cumulative weight. A centroid of weight 1 sits at the middle of
+        // its unit, so with unmerged values this reproduces the closest-ranks interpolation of
+        // `percentile`; the minimum and maximum sit at the middle of the first and last units.
+        let target = q * (total - 1.0) + 0.5;
+        if target <= 0.5 {
+            return Some(self.min);
+        }
+        if target >= total - 0.5 {
+            return Some(self.max);
+        }
+
+        let mut previous = (0.5, self.min);
+        let mut cumulative = 0.0;
+        for centroid in &centroids {
+            let center = cumulative + centroid.weight / 2.0;
+            if target <= center {
+                return Some(interpolate(previous, (center, centroid.mean), target));
+            }
+            previous = (center, centroid.mean);
+            cumulative += centroid.weight;
+        }
+
+        Some(interpolate(previous, (total - 0.5, self.max), target))
+    }
+
+    /// Estimates the percentile `p` of the values added, like `percentile`.
+    ///
+    /// # Arguments
+    /// * `p` - The percentile to estimate, between 0 and 100 inclusive.
+    ///
+    /// # Returns
+    /// * `Option<f64>` - The estimated percentile, or None if no value was added or `p` is out of range.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        self.quantile(p / 100.0)
+    }
+
+    /// Estimates the median of the values added.
+    pub fn median(&self) -> Option<f64> {
+        self.quantile(0.5)
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        (self.compression * 5.0) as usize
+    }
+
+    /// Returns the centroids and the buffered values merged into a sorted list, combining
+    /// neighbours while the size limit of the t-digest allows it.
+    fn compressed(&self) -> Vec<Centroid> {
+        let mut all: Vec<Centroid> = Vec::with_capacity(self.centroids.len() + self.buffer.len());
+        all.extend_from_slice(&self.centroids);
+        all.extend_from_slice(&self.buffer);
+        if self.buffer.is_empty() || all.len() < 2 {
+            return all;
+        }
+        all.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+        let mut result = Vec::with_capacity(all.len());
+        let mut current = all[0];
+        let mut cumulative = 0.0;
+        for &next in &all[1..] {
+            let proposed = current.weight + next.weight;
+            let q = (cumulative + proposed / 2.0) / total;
+            let limit = 4.0 * total * q * (1.0 - q) / self.compression;
+            if proposed <= limit {
+                current.mean += (next.mean - current.mean) * next.weight / proposed;
+                current.weight = proposed;
+            } else {
+                cumulative += current.weight;
+                result.push(current);
+                current = next;
+            }
+        }
+        result.push(current);
+
+        result
+    }
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+impl Default for OnlineStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for OnlineStats
+where
+    T: Into<f64>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for OnlineStats
+where
+    T: Into<f64>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut stats = OnlineStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{Rng, SeededRng};
+    use crate::percentile;
+
+    #[test]
+    fn test_online_stats_empty() {
+        let stats = OnlineStats::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_online_stats_matches_batch_functions() {
+        let values = [2.5, -1.0, 7.25, 3.0, 3.0, 10.0, 0.0];
+        let stats: OnlineStats = values.iter().copied().collect();
+        assert_eq!(stats.count(), values.len());
+        assert_eq!(stats.min(), Some(-1.0));
+        assert_eq!(stats.max(), Some(10.0));
+        assert!((stats.mean().unwrap() - crate::mean(&values).unwrap()).abs() < 1e-12);
+        assert!((stats.variance().unwrap() - crate::variance(&values).unwrap()).abs() < 1e-12);
+        assert!((stats.skewness().unwrap() - crate::skewness(&values).unwrap()).abs() < 1e-12);
+        assert!((stats.kurtosis().unwrap() - crate::kurtosis(&values).unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_online_stats_quantiles_exact_on_small_inputs() {
+        let mut rng = SeededRng::new(7);
+        for len in [1, 2, 3, 10, 57, 100] {
+            let values: Vec<f64> = (0..len).map(|_| (rng.next_u64() % 1000) as f64).collect();
+            let stats: OnlineStats = values.iter().copied().collect();
+            for p in [0.0, 1.0, 10.0, 25.0, 33.3, 50.0, 75.0, 90.0, 99.0, 100.0] {
+                let exact = percentile(&values, p).unwrap();
+                let estimate = stats.percentile(p).unwrap();
+                assert!(
+                    (exact - estimate).abs() < 1e-9,
+                    "len {} p {}: {} != {}",
+                    len,
+                    p,
+                    exact,
+                    estimate
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_online_stats_quantiles_approximate_on_large_inputs() {
+        let mut rng = SeededRng::new(11);
+        let values: Vec<f64> = (0..100_000)
+            .map(|_| (rng.next_u64() % 1_000_000) as f64)
+            .collect();
+        let stats: OnlineStats = values.iter().copied().collect();
+        assert!(stats.centroids.len() < 1_000);
+        for p in [1.0, 10.0, 50.0, 90.0, 99.0, 99.9] {
+            let exact = percentile(&values, p).unwrap();
+            let estimate = stats.percentile(p).unwrap();
+            assert!(
+                (exact - estimate).abs() < 5_000.0,
+                "p {}: {} vs {}",
+                p,
+                exact,
+                estimate
+            );
+        }
+        assert_eq!(stats.percentile(0.0), Some(stats.min().unwrap()));
+        assert_eq!(stats.percentile(100.0), Some(stats.max().unwrap()));
+    }
+
+    #[test]
+    fn test_online_stats_merge() {
+        let values: Vec<f64> = (0..5_000).map(|i| ((i * 7919) % 5_000) as f64).collect();
+        let whole: OnlineStats = values.iter().copied().collect();
+
+        let mut merged = OnlineStats::new();
+        for chunk in values.chunks(700) {
+            let part: OnlineStats = chunk.iter().copied().collect();
+            merged.merge(&part);
+        }
+
+        assert_eq!(merged.count(), whole.count());
+        assert_eq!(merged.min(), whole.min());
+        assert_eq!(merged.max(), whole.max());
+        assert!((merged.mean().unwrap() - whole.mean().unwrap()).abs() < 1e-9);
+        assert!((merged.variance().unwrap() - whole.variance().unwrap()).abs() < 1e-6);
+        assert!((merged.median().unwrap() - 2_499.5).abs() < 50.0);
+    }
+
+    #[test]
+    fn test_online_stats_merge_small_is_exact() {
+        let mut left: OnlineStats = [5, 1, 9].into_iter().collect();
+        let right: OnlineStats = [3, 7].into_iter().collect();
+        left.merge(&right);
+        assert_eq!(left.median(), Some(5.0));
+        assert_eq!(left.percentile(25.0), percentile(&[1, 3, 5, 7, 9], 25.0));
+    }
+
+    #[test]
+    fn test_online_stats_merge_empty() {
+        let mut stats: OnlineStats = [1.0, 2.0].into_iter().collect();
+        stats.merge(&OnlineStats::new());
+        assert_eq!(stats.count(), 2);
+
+        let mut empty = OnlineStats::new();
+        empty.merge(&stats);
+        assert_eq!(empty.mean(), Some(1.5));
+    }
+
+    #[test]
+    fn test_online_stats_ignores_nan() {
+        let stats: OnlineStats = [1.0, f64::NAN, 3.0].into_iter().collect();
+        assert_eq!(stats.count(), 2);
+        assert_eq!(stats.mean(), Some(2.0));
+    }
+
+    #[test]
+    fn test_online_stats_out_of_range_quantile() {
+        let stats: OnlineStats = [1.0, 2.0].into_iter().collect();
+        assert_eq!(stats.quantile(-0.1), None);
+        assert_eq!(stats.percentile(100.1), None);
+        assert_eq!(stats.quantile(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_online_stats_across_threads() {
+        let parts: Vec<OnlineStats> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|t| scope.spawn(move || (t * 1_000..(t + 1) * 1_000).collect::<OnlineStats>()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        let mut total = OnlineStats::new();
+        for part in &parts {
+            total.merge(part);
+        }
+        assert_eq!(total.count(), 4_000);
+        assert_eq!(total.mean(), Some(1_999.5));
+    }
+}
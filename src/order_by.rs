@@ -0,0 +1,199 @@
+use crate::SortDirection;
+use std::cmp::Ordering;
+
+/// A comparator borrowed as a trait object, as taken by `order_by` and `is_sorted_by_keys`.
+pub type Comparator<'a, T> = &'a dyn Fn(&T, &T) -> Ordering;
+
+/// Sort a collection by several comparators, each with its own direction, like lodash's `orderBy`.
+///
+/// Elements are compared with the first comparator; the next comparator only breaks ties left by
+/// the ones before it. The sort is stable, so elements that compare equal on every comparator keep
+/// their original order.
+///
+/// Where lodash takes key functions, this takes comparators that receive two elements and return
+/// an `Ordering`. Every entry of a slice of closures must have the same type, so key functions
+/// would all have to return the same key type; comparators let each column compare its own type:
+/// `|a, b| a.age.cmp(&b.age)`, `|a, b| a.score.total_cmp(&b.score)` or
+/// `|a, b| natural_cmp(&a.file, &b.file)`.
+///
+/// As with lodash, `directions` may be shorter than `comparators`, and the comparators without a
+/// direction sort ascending; pass `&[]` to sort every comparator ascending.
+///
+/// **Panics:**
+/// Panics if `directions` has more entries than `comparators`.
+///
+/// # Arguments
+/// * `collection` - A slice of items to sort.
+/// * `comparators` - The comparators, from most to least significant.
+/// * `directions` - The direction for each comparator; missing trailing entries mean ascending.
+///
+/// # Returns
+/// * `Vec<T>` - The sorted elements.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{order_by, SortDirection};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Player {
+///     name: &'static str,
+///     team: &'static str,
+///     score: f64,
+/// }
+///
+/// let players = vec![
+///     Player { name: "Ana", team: "red", score: 7.5 },
+///     Player { name: "Ben", team: "blue", score: 9.0 },
+///     Player { name: "Cy", team: "red", score: 9.5 },
+///     Player { name: "Di", team: "blue", score: 6.0 },
+/// ];
+///
+/// let by_team = |a: &Player, b: &Player| a.team.cmp(b.team);
+/// let by_score = |a: &Player, b: &Player| a.score.total_cmp(&b.score);
+/// let sorted = order_by(
+///     &players,
+///     &[&by_team, &by_score],
+///     &[SortDirection::Asc, SortDirection::Desc],
+/// );
+/// let names: Vec<&str> = sorted.iter().map(|player| player.name).collect();
+/// assert_eq!(names, vec!["Ben", "Di", "Cy", "Ana"]);
+/// ```
+pub fn order_by<T>(
+    collection: &[T],
+    comparators: &[Comparator<'_, T>],
+    directions: &[SortDirection],
+) -> Vec<T>
+where
+    T: Clone,
+{
+    check_directions(comparators, directions);
+
+    let mut result = collection.to_vec();
+    result.sort_by(|a, b| compare_by_keys(a, b, comparators, directions));
+    result
+}
+
+/// Rejects directions without a comparator to apply to.
+pub(crate) fn check_directions<T>(comparators: &[Comparator<'_, T>], directions: &[SortDirection]) {
+    if directions.len() > comparators.len() {
+        panic!("Directions must not outnumber comparators");
+    }
+}
+
+/// Compares two elements by each comparator in turn, in its direction, until one tells them
+/// apart.
+pub(crate) fn compare_by_keys<T>(
+    a: &T,
+    b: &T,
+    comparators: &[Comparator<'_, T>],
+    directions: &[SortDirection],
+) -> Ordering {
+    comparators
+        .iter()
+        .enumerate()
+        .map(|(i, comparator)| {
+            let direction = directions.get(i).copied().unwrap_or_default();
+            direction.apply(comparator(a, b))
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row {
+        id: u32,
+        group: char,
+        value: i32,
+    }
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                id: 1,
+                group: 'b',
+                value: 3,
+            },
+            Row {
+                id: 2,
+                group: 'a',
+                value: 1,
+            },
+            Row {
+                id: 3,
+                group: 'b',
+                value: 1,
+            },
+            Row {
+                id: 4,
+                group: 'a',
+                value: 3,
+            },
+            Row {
+                id: 5,
+                group: 'a',
+                value: 1,
+            },
+        ]
+    }
+
+    fn ids(rows: &[Row]) -> Vec<u32> {
+        rows.iter().map(|row| row.id).collect()
+    }
+
+    #[test]
+    fn test_order_by_two_keys() {
+        let by_group = |a: &Row, b: &Row| a.group.cmp(&b.group);
+        let by_value = |a: &Row, b: &Row| a.value.cmp(&b.value);
+        let sorted = order_by(
+            &rows(),
+            &[&by_group, &by_value],
+            &[SortDirection::Asc, SortDirection::Desc],
+        );
+        assert_eq!(ids(&sorted), vec![4, 2, 5, 1, 3]);
+    }
+
+    #[test]
+    fn test_order_by_is_stable() {
+        let by_value = |a: &Row, b: &Row| a.value.cmp(&b.value);
+        let sorted = order_by(&rows(), &[&by_value], &[SortDirection::Asc]);
+        assert_eq!(ids(&sorted), vec![2, 3, 5, 1, 4]);
+        let sorted = order_by(&rows(), &[&by_value], &[SortDirection::Desc]);
+        assert_eq!(ids(&sorted), vec![1, 4, 2, 3, 5]);
+    }
+
+    #[test]
+    fn test_order_by_missing_directions_default_to_ascending() {
+        let by_group = |a: &Row, b: &Row| a.group.cmp(&b.group);
+        let by_value = |a: &Row, b: &Row| a.value.cmp(&b.value);
+        let sorted = order_by(&rows(), &[&by_group, &by_value], &[]);
+        assert_eq!(ids(&sorted), vec![2, 5, 4, 3, 1]);
+    }
+
+    #[test]
+    fn test_order_by_no_comparators_keeps_order() {
+        let sorted = order_by(&rows(), &[], &[]);
+        assert_eq!(sorted, rows());
+    }
+
+    #[test]
+    #[should_panic(expected = "Directions must not outnumber comparators")]
+    fn test_order_by_extra_directions() {
+        let by_value = |a: &Row, b: &Row| a.value.cmp(&b.value);
+        order_by(
+            &rows(),
+            &[&by_value],
+            &[SortDirection::Asc, SortDirection::Desc],
+        );
+    }
+
+    #[test]
+    fn test_order_by_empty() {
+        let empty: Vec<i32> = vec![];
+        let natural = |a: &i32, b: &i32| a.cmp(b);
+        assert!(order_by(&empty, &[&natural], &[]).is_empty());
+    }
+}
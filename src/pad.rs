@@ -0,0 +1,72 @@
+/// Pads both sides of a string to the given length in characters, centering it.
+///
+/// When the padding cannot be split evenly the extra character goes to the end. The pad string
+/// is repeated, and cut if needed, on each side. Lengths are counted in `char`s. If the string
+/// is already at least `length` characters long, or `pad_chars` is empty, it is returned
+/// unchanged.
+///
+/// # Arguments
+/// * `str_input` - The string to pad
+/// * `length` - The length of the result in characters
+/// * `pad_chars` - The string used as padding
+///
+/// # Returns
+/// * `String` - The centered string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::pad;
+///
+/// assert_eq!(pad("abc", 8, " "), "  abc   ");
+/// assert_eq!(pad("abc", 8, "_-"), "_-abc_-_");
+/// assert_eq!(pad("abc", 2, "_"), "abc");
+/// ```
+pub fn pad(str_input: &str, length: usize, pad_chars: &str) -> String {
+    let missing = length.saturating_sub(str_input.chars().count());
+    if missing == 0 || pad_chars.is_empty() {
+        return str_input.to_string();
+    }
+
+    let left = missing / 2;
+    let mut result: String = pad_chars.chars().cycle().take(left).collect();
+    result.push_str(str_input);
+    result.extend(pad_chars.chars().cycle().take(missing - left));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_even_padding() {
+        assert_eq!(pad("abc", 7, " "), "  abc  ");
+    }
+
+    #[test]
+    fn test_odd_padding_extra_at_end() {
+        assert_eq!(pad("abc", 8, " "), "  abc   ");
+        assert_eq!(pad("abc", 4, "*"), "abc*");
+    }
+
+    #[test]
+    fn test_pad_with_repeating_string() {
+        assert_eq!(pad("abc", 8, "_-"), "_-abc_-_");
+    }
+
+    #[test]
+    fn test_already_long_enough() {
+        assert_eq!(pad("abc", 3, "_"), "abc");
+        assert_eq!(pad("abc", 0, "_"), "abc");
+    }
+
+    #[test]
+    fn test_empty_pad_chars() {
+        assert_eq!(pad("abc", 8, ""), "abc");
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_eq!(pad("世", 5, "·"), "··世··");
+    }
+}
@@ -0,0 +1,64 @@
+/// Pads the end of a string to the given length in characters.
+///
+/// The pad string is repeated, and cut if needed, to fill the missing characters. Lengths are
+/// counted in `char`s. If the string is already at least `length` characters long, or
+/// `pad_chars` is empty, it is returned unchanged.
+///
+/// # Arguments
+/// * `str_input` - The string to pad
+/// * `length` - The length of the result in characters
+/// * `pad_chars` - The string used as padding
+///
+/// # Returns
+/// * `String` - The padded string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::pad_end;
+///
+/// assert_eq!(pad_end("abc", 6, " "), "abc   ");
+/// assert_eq!(pad_end("abc", 6, "_-"), "abc_-_");
+/// assert_eq!(pad_end("abc", 2, "_"), "abc");
+/// ```
+pub fn pad_end(str_input: &str, length: usize, pad_chars: &str) -> String {
+    let missing = length.saturating_sub(str_input.chars().count());
+    if missing == 0 || pad_chars.is_empty() {
+        return str_input.to_string();
+    }
+
+    let mut result = str_input.to_string();
+    result.extend(pad_chars.chars().cycle().take(missing));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad_with_space() {
+        assert_eq!(pad_end("abc", 6, " "), "abc   ");
+    }
+
+    #[test]
+    fn test_pad_with_repeating_string() {
+        assert_eq!(pad_end("abc", 6, "_-"), "abc_-_");
+        assert_eq!(pad_end("abc", 8, "123"), "abc12312");
+    }
+
+    #[test]
+    fn test_already_long_enough() {
+        assert_eq!(pad_end("abc", 3, "_"), "abc");
+        assert_eq!(pad_end("abcdef", 0, "_"), "abcdef");
+    }
+
+    #[test]
+    fn test_empty_pad_chars() {
+        assert_eq!(pad_end("abc", 6, ""), "abc");
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_eq!(pad_end("世界", 4, "·"), "世界··");
+    }
+}
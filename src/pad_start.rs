@@ -0,0 +1,71 @@
+/// Pads the start of a string to the given length in characters.
+///
+/// The pad string is repeated, and cut if needed, to fill the missing characters. Lengths are
+/// counted in `char`s. If the string is already at least `length` characters long, or
+/// `pad_chars` is empty, it is returned unchanged.
+///
+/// # Arguments
+/// * `str_input` - The string to pad
+/// * `length` - The length of the result in characters
+/// * `pad_chars` - The string used as padding
+///
+/// # Returns
+/// * `String` - The padded string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::pad_start;
+///
+/// assert_eq!(pad_start("abc", 6, " "), "   abc");
+/// assert_eq!(pad_start("abc", 6, "_-"), "_-_abc");
+/// assert_eq!(pad_start("42", 5, "0"), "00042");
+/// assert_eq!(pad_start("abc", 2, "_"), "abc");
+/// ```
+pub fn pad_start(str_input: &str, length: usize, pad_chars: &str) -> String {
+    let missing = length.saturating_sub(str_input.chars().count());
+    if missing == 0 || pad_chars.is_empty() {
+        return str_input.to_string();
+    }
+
+    let mut result: String = pad_chars.chars().cycle().take(missing).collect();
+    result.push_str(str_input);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad_with_space() {
+        assert_eq!(pad_start("abc", 6, " "), "   abc");
+    }
+
+    #[test]
+    fn test_pad_with_repeating_string() {
+        assert_eq!(pad_start("abc", 6, "_-"), "_-_abc");
+        assert_eq!(pad_start("abc", 9, "123"), "123123abc");
+    }
+
+    #[test]
+    fn test_already_long_enough() {
+        assert_eq!(pad_start("abc", 3, "_"), "abc");
+        assert_eq!(pad_start("abcdef", 3, "_"), "abcdef");
+    }
+
+    #[test]
+    fn test_empty_pad_chars() {
+        assert_eq!(pad_start("abc", 6, ""), "abc");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(pad_start("", 3, "ab"), "aba");
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_eq!(pad_start("世界", 4, "·"), "··世界");
+        assert_eq!(pad_start("é", 3, "✦"), "✦✦é");
+    }
+}
@@ -0,0 +1,459 @@
+//! Thread-parallel versions of the slice utilities.
+//!
+//! Each function splits the slice into one contiguous chunk per thread, runs the callback on the
+//! chunks concurrently with [`std::thread::scope`], and merges the partial results in chunk order.
+//! The output is therefore identical to the sequential function, and the indices passed to the
+//! callbacks are positions in the whole slice.
+//!
+//! The `threads` argument sets the number of worker threads. Pass `0` to use
+//! [`std::thread::available_parallelism`]. Slices shorter than the thread count use fewer threads,
+//! and a single thread runs on the calling thread without spawning.
+//!
+//! A panic inside a callback is propagated to the caller once all threads have finished.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::panic;
+use std::thread;
+
+/// Returns the number of threads used when `threads` is 0.
+///
+/// # Returns
+/// * `usize` - The available parallelism reported by the platform, or 1 if it is unknown.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// assert!(parallel::default_threads() >= 1);
+/// ```
+pub fn default_threads() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Splits `collection` into at most `threads` contiguous chunks and calls `work` on each chunk
+/// with the index of its first element, returning the results in chunk order.
+fn run_chunks<'a, T, R, F>(collection: &'a [T], threads: usize, work: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&'a [T], usize) -> R + Sync,
+{
+    let threads = if threads == 0 {
+        default_threads()
+    } else {
+        threads
+    };
+    let chunk_size = collection.len().div_ceil(threads).max(1);
+
+    if threads == 1 || collection.len() <= chunk_size {
+        return vec![work(collection, 0)];
+    }
+
+    let work = &work;
+    thread::scope(|scope| {
+        let handles: Vec<_> = collection
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(i, chunk)| scope.spawn(move || work(chunk, i * chunk_size)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+/// Applies a function to each item in a collection on several threads, like `map`.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be mapped.
+/// * `iteratee` - A function that takes an item and its index and returns a new value.
+/// * `threads` - The number of worker threads, or 0 to use `default_threads()`.
+///
+/// # Returns
+/// * `Vec<R>` - The mapped values, in the order of the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// let numbers: Vec<i32> = (1..=1000).collect();
+/// let result = parallel::map(&numbers, |x, _| x * 2, 4);
+/// assert_eq!(result, lowdash::map(&numbers, |x, _| x * 2));
+/// ```
+pub fn map<T, R, F>(collection: &[T], iteratee: F, threads: usize) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T, usize) -> R + Sync,
+{
+    let mut result = Vec::with_capacity(collection.len());
+    for part in run_chunks(collection, threads, |chunk, offset| {
+        chunk
+            .iter()
+            .enumerate()
+            .map(|(i, item)| iteratee(item, offset + i))
+            .collect::<Vec<R>>()
+    }) {
+        result.extend(part);
+    }
+    result
+}
+
+/// Filters a collection on several threads, like `filter`.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be filtered.
+/// * `predicate` - A function that takes an item and its index and returns whether to keep it.
+/// * `threads` - The number of worker threads, or 0 to use `default_threads()`.
+///
+/// # Returns
+/// * `Vec<&T>` - References to the items that satisfy the predicate, in the order of the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// let numbers: Vec<i32> = (1..=10).collect();
+/// let result = parallel::filter(&numbers, |x, _| x % 2 == 0, 3);
+/// assert_eq!(result, vec![&2, &4, &6, &8, &10]);
+/// ```
+pub fn filter<'a, T, F>(collection: &'a [T], predicate: F, threads: usize) -> Vec<&'a T>
+where
+    T: Sync,
+    F: Fn(&'a T, usize) -> bool + Sync,
+{
+    run_chunks(collection, threads, |chunk, offset| {
+        chunk
+            .iter()
+            .enumerate()
+            .filter(|(i, item)| predicate(item, offset + i))
+            .map(|(_, item)| item)
+            .collect::<Vec<&'a T>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Filters and maps a collection on several threads, like `filter_map`.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be processed.
+/// * `callback` - A function that takes an item and its index and returns the mapped value and
+///   whether to keep it.
+/// * `threads` - The number of worker threads, or 0 to use `default_threads()`.
+///
+/// # Returns
+/// * `Vec<R>` - The kept values, in the order of the collection.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// let numbers: Vec<i32> = (1..=6).collect();
+/// let result = parallel::filter_map(&numbers, |x, _| (x * 10, x % 2 == 1), 2);
+/// assert_eq!(result, vec![10, 30, 50]);
+/// ```
+pub fn filter_map<T, R, F>(collection: &[T], callback: F, threads: usize) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T, usize) -> (R, bool) + Sync,
+{
+    run_chunks(collection, threads, |chunk, offset| {
+        let mut result = Vec::new();
+        for (i, item) in chunk.iter().enumerate() {
+            let (mapped, include) = callback(item, offset + i);
+            if include {
+                result.push(mapped);
+            }
+        }
+        result
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Reduces a collection on several threads.
+///
+/// Every chunk is folded with `accumulator`, starting from a clone of `initial`, and the partial
+/// results are then merged from left to right with `combine`. For the result to match `reduce`,
+/// `initial` must be an identity for `combine` and `combine` must be associative.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be reduced.
+/// * `accumulator` - A function that takes the accumulated value, an item and its index.
+/// * `combine` - A function that merges the results of two adjacent chunks.
+/// * `initial` - The starting value of every chunk.
+/// * `threads` - The number of worker threads, or 0 to use `default_threads()`.
+///
+/// # Returns
+/// * `R` - The reduced value.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// let numbers: Vec<u64> = (1..=100).collect();
+/// let sum = parallel::reduce(&numbers, |acc, x, _| acc + x, |a, b| a + b, 0, 4);
+/// assert_eq!(sum, 5050);
+/// ```
+pub fn reduce<T, R, F, C>(
+    collection: &[T],
+    accumulator: F,
+    combine: C,
+    initial: R,
+    threads: usize,
+) -> R
+where
+    T: Sync,
+    R: Clone + Send + Sync,
+    F: Fn(R, &T, usize) -> R + Sync,
+    C: Fn(R, R) -> R,
+{
+    let mut parts = run_chunks(collection, threads, |chunk, offset| {
+        let mut acc = initial.clone();
+        for (i, item) in chunk.iter().enumerate() {
+            acc = accumulator(acc, item, offset + i);
+        }
+        acc
+    })
+    .into_iter();
+
+    let first = parts.next().unwrap_or(initial);
+    parts.fold(first, combine)
+}
+
+/// Groups a collection by a key on several threads, like `group_by`.
+///
+/// The items of every group keep the order they have in the collection.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be grouped.
+/// * `iteratee` - A function that takes an item and returns its key.
+/// * `threads` - The number of worker threads, or 0 to use `default_threads()`.
+///
+/// # Returns
+/// * `HashMap<U, Vec<T>>` - A map from each key to the items that share it.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// let numbers: Vec<i32> = (1..=10).collect();
+/// let grouped = parallel::group_by(&numbers, |x| x % 3, 4);
+/// assert_eq!(grouped.get(&0), Some(&vec![3, 6, 9]));
+/// assert_eq!(grouped.get(&1), Some(&vec![1, 4, 7, 10]));
+/// ```
+pub fn group_by<T, U, F>(collection: &[T], iteratee: F, threads: usize) -> HashMap<U, Vec<T>>
+where
+    T: Clone + Send + Sync,
+    U: Eq + Hash + Clone + Send,
+    F: Fn(&T) -> U + Sync,
+{
+    let mut result: HashMap<U, Vec<T>> = HashMap::new();
+    for part in run_chunks(collection, threads, |chunk, _| {
+        crate::group_by(chunk, &iteratee)
+    }) {
+        for (key, items) in part {
+            result.entry(key).or_default().extend(items);
+        }
+    }
+    result
+}
+
+/// Counts the occurrences of each value on several threads, like `count_values`.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be counted.
+/// * `threads` - The number of worker threads, or 0 to use `default_threads()`.
+///
+/// # Returns
+/// * `HashMap<T, usize>` - A map from each distinct value to its number of occurrences.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::parallel;
+///
+/// let words = vec!["a", "b", "a", "c", "a", "b"];
+/// let counts = parallel::count_values(&words, 3);
+/// assert_eq!(counts.get("a"), Some(&3));
+/// assert_eq!(counts.get("b"), Some(&2));
+/// assert_eq!(counts.get("c"), Some(&1));
+/// ```
+pub fn count_values<T>(collection: &[T], threads: usize) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone + Send + Sync,
+{
+    let mut result: HashMap<T, usize> = HashMap::new();
+    for part in run_chunks(collection, threads, |chunk, _| crate::count_values(chunk)) {
+        for (item, count) in part {
+            *result.entry(item).or_insert(0) += count;
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const THREAD_COUNTS: [usize; 6] = [0, 1, 2, 3, 8, 64];
+
+    #[test]
+    fn test_map_matches_sequential() {
+        let numbers: Vec<i32> = (0..1000).collect();
+        let expected = crate::map(&numbers, |x, i| x * 3 + i as i32);
+        for threads in THREAD_COUNTS {
+            assert_eq!(map(&numbers, |x, i| x * 3 + i as i32, threads), expected);
+        }
+    }
+
+    #[test]
+    fn test_map_empty() {
+        let numbers: Vec<i32> = vec![];
+        assert!(map(&numbers, |x, _| *x, 4).is_empty());
+    }
+
+    #[test]
+    fn test_map_more_threads_than_items() {
+        let numbers = vec![1, 2, 3];
+        assert_eq!(
+            map(&numbers, |x, i| (*x, i), 16),
+            vec![(1, 0), (2, 1), (3, 2)]
+        );
+    }
+
+    #[test]
+    fn test_map_uses_several_threads() {
+        let numbers: Vec<i32> = (0..64).collect();
+        let ids = map(&numbers, |_, _| thread::current().id(), 4);
+        let mut distinct = ids.clone();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 4);
+    }
+
+    #[test]
+    fn test_filter_matches_sequential() {
+        let numbers: Vec<i32> = (0..1000).collect();
+        let expected = crate::filter(&numbers, |x, i| x % 7 == 0 || i % 11 == 0);
+        for threads in THREAD_COUNTS {
+            assert_eq!(
+                filter(&numbers, |x, i| x % 7 == 0 || i % 11 == 0, threads),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_map_matches_sequential() {
+        let words: Vec<String> = (0..500).map(|i| format!("w{}", i)).collect();
+        let expected = crate::filter_map(&words, |w, i| (w.len() + i, i % 3 == 0));
+        for threads in THREAD_COUNTS {
+            assert_eq!(
+                filter_map(&words, |w, i| (w.len() + i, i % 3 == 0), threads),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_reduce_matches_sequential() {
+        let numbers: Vec<u64> = (1..=1000).collect();
+        let expected = crate::reduce(&numbers, |acc, x, i| acc + x * i as u64, 0);
+        for threads in THREAD_COUNTS {
+            assert_eq!(
+                reduce(
+                    &numbers,
+                    |acc, x, i| acc + x * i as u64,
+                    |a, b| a + b,
+                    0,
+                    threads
+                ),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_reduce_combines_in_order() {
+        let letters: Vec<char> = "abcdefghij".chars().collect();
+        let result = reduce(
+            &letters,
+            |mut acc: String, c, _| {
+                acc.push(*c);
+                acc
+            },
+            |a, b| a + &b,
+            String::new(),
+            4,
+        );
+        assert_eq!(result, "abcdefghij");
+    }
+
+    #[test]
+    fn test_reduce_empty_returns_initial() {
+        let numbers: Vec<i32> = vec![];
+        assert_eq!(reduce(&numbers, |acc, x, _| acc + x, |a, b| a + b, 0, 4), 0);
+    }
+
+    #[test]
+    fn test_group_by_matches_sequential() {
+        let numbers: Vec<i32> = (0..1000).collect();
+        let expected = crate::group_by(&numbers, |x| x % 13);
+        for threads in THREAD_COUNTS {
+            assert_eq!(group_by(&numbers, |x| x % 13, threads), expected);
+        }
+    }
+
+    #[test]
+    fn test_count_values_matches_sequential() {
+        let numbers: Vec<i32> = (0..1000).map(|i| i % 17).collect();
+        let expected = crate::count_values(&numbers);
+        for threads in THREAD_COUNTS {
+            assert_eq!(count_values(&numbers, threads), expected);
+        }
+    }
+
+    #[test]
+    fn test_callback_runs_once_per_item() {
+        let calls = AtomicUsize::new(0);
+        let numbers: Vec<i32> = (0..1000).collect();
+        let _ = filter(
+            &numbers,
+            |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                true
+            },
+            8,
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "bad item")]
+    fn test_panic_is_propagated() {
+        let numbers: Vec<i32> = (0..100).collect();
+        let _ = map(
+            &numbers,
+            |x, _| {
+                if *x == 70 {
+                    panic!("bad item");
+                }
+                *x
+            },
+            4,
+        );
+    }
+}
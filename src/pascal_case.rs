@@ -1,7 +1,10 @@
+use crate::{pascal_case_with, WordOptions};
+
 /// Converts a string to PascalCase.
 ///
-/// Splits the input string into words based on spaces, hyphens, and underscores,
-/// then capitalizes the first letter of each word and joins them together.
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then capitalizes each word and joins them together.
+/// Use [`pascal_case_with`] to configure the splitting.
 ///
 /// # Arguments
 /// * `str_input` - The input string to convert
@@ -18,27 +21,7 @@
 /// assert_eq!(pascal_case("lorem_ipsum"), "LoremIpsum");
 /// ```
 pub fn pascal_case(str_input: &str) -> String {
-    if str_input.is_empty() {
-        return String::new();
-    }
-
-    let mut result = String::with_capacity(str_input.len());
-    let mut in_word = false;
-
-    for c in str_input.chars() {
-        if c.is_whitespace() || c == '-' || c == '_' {
-            in_word = false;
-        } else {
-            if !in_word {
-                result.push(c.to_uppercase().next().unwrap_or(c));
-                in_word = true;
-            } else {
-                result.push(c);
-            }
-        }
-    }
-
-    result
+    pascal_case_with(str_input, &WordOptions::for_case())
 }
 
 #[cfg(test)]
@@ -92,16 +75,27 @@ mod tests {
 
     #[test]
     fn test_with_numbers() {
-        assert_eq!(pascal_case("hello2world"), "Hello2world");
+        assert_eq!(pascal_case("hello2world"), "Hello2World");
     }
 
     #[test]
     fn test_with_special_characters() {
-        assert_eq!(pascal_case("hello!world"), "Hello!world");
+        assert_eq!(pascal_case("hello!world"), "HelloWorld");
     }
 
     #[test]
     fn test_unicode_characters() {
         assert_eq!(pascal_case("hello_世界"), "Hello世界");
     }
+
+    #[test]
+    fn test_acronyms() {
+        assert_eq!(pascal_case("HTTPRequest"), "HttpRequest");
+        assert_eq!(pascal_case("XMLHttpRequest"), "XmlHttpRequest");
+    }
+
+    #[test]
+    fn test_apostrophes_removed() {
+        assert_eq!(pascal_case("don't stop"), "DontStop");
+    }
 }
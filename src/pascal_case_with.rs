@@ -0,0 +1,81 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to PascalCase, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then capitalizes each word and
+/// joins them together.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in PascalCase
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{pascal_case_with, WordOptions};
+///
+/// assert_eq!(pascal_case_with("xml_http_request", &WordOptions::default()), "XmlHttpRequest");
+///
+/// let options = WordOptions {
+///     separators: Some(vec![' ', '-', '_']),
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(pascal_case_with("v1.2 release", &options), "V1.2Release");
+/// ```
+pub fn pascal_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Pascal, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        let options = WordOptions::default();
+        assert_eq!(pascal_case_with("HTTP2Server", &options), "Http2Server");
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(pascal_case_with("HTTP2Server", &options), "Http2Server");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        let options = WordOptions {
+            acronyms: vec!["OAuth".to_string()],
+            ..WordOptions::default()
+        };
+        assert_eq!(pascal_case_with("getOAuthToken", &options), "GetOauthToken");
+    }
+
+    #[test]
+    fn test_apostrophes_kept() {
+        let options = WordOptions::default();
+        assert_eq!(pascal_case_with("don't stop", &options), "Don'tStop");
+    }
+
+    #[test]
+    fn test_custom_separators() {
+        let options = WordOptions {
+            separators: Some(vec![' ']),
+            ..WordOptions::default()
+        };
+        assert_eq!(pascal_case_with("hello world!", &options), "HelloWorld!");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(pascal_case_with("", &WordOptions::default()), "");
+        assert_eq!(pascal_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{path_case_with, WordOptions};
+
+/// Converts a string to path/case.
+///
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then converts each word to lowercase and joins them with slashes. Use
+/// [`path_case_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in path/case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::path_case;
+///
+/// assert_eq!(path_case("hello world"), "hello/world");
+/// assert_eq!(path_case("fooBarBaz"), "foo/bar/baz");
+/// assert_eq!(path_case("src_main_rs"), "src/main/rs");
+/// ```
+pub fn path_case(str_input: &str) -> String {
+    path_case_with(str_input, &WordOptions::for_case())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_space_separated() {
+        assert_eq!(path_case("hello world"), "hello/world");
+    }
+
+    #[test]
+    fn test_mixed_separators() {
+        assert_eq!(path_case("foo-bar_baz hello"), "foo/bar/baz/hello");
+    }
+
+    #[test]
+    fn test_camel_and_pascal_case() {
+        assert_eq!(path_case("fooBarBaz"), "foo/bar/baz");
+        assert_eq!(path_case("FooBarBaz"), "foo/bar/baz");
+    }
+
+    #[test]
+    fn test_already_path_case() {
+        assert_eq!(path_case("hello/world"), "hello/world");
+    }
+
+    #[test]
+    fn test_leading_and_trailing_slashes() {
+        assert_eq!(path_case("/usr/local/"), "usr/local");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(path_case(""), "");
+    }
+}
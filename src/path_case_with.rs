@@ -0,0 +1,56 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to path/case, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then converts each word to
+/// lowercase and joins them with slashes.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in path/case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{path_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(path_case_with("v2Api", &WordOptions::default()), "v/2/api");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(path_case_with("v2Api", &attached), "v2/api");
+/// ```
+pub fn path_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Path, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(path_case_with("v2Api", &WordOptions::default()), "v/2/api");
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(path_case_with("v2Api", &options), "v2/api");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(path_case_with("", &WordOptions::default()), "");
+        assert_eq!(path_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
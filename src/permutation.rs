@@ -1,5 +1,9 @@
+use crate::Permutations;
+
 /// Finds all permutations of k elements from a collection.
 ///
+/// Every permutation is collected into memory; use `permutations` to walk them lazily instead.
+///
 /// # Arguments
 /// * `items` - A slice of items to permute.
 /// * `k` - The number of elements in each permutation.
@@ -18,42 +22,11 @@
 /// assert!(result.contains(&vec![2, 1]));
 /// ```
 pub fn permutation<T: Clone>(items: &[T], k: usize) -> Vec<Vec<T>> {
-    fn backtrack<T: Clone>(
-        items: &[T],
-        k: usize,
-        buffer: &mut Vec<T>,
-        used: &mut Vec<bool>,
-        result: &mut Vec<Vec<T>>,
-    ) {
-        if buffer.len() == k {
-            result.push(buffer.clone());
-            return;
-        }
-        for i in 0..items.len() {
-            if used[i] {
-                continue;
-            }
-            used[i] = true;
-            buffer.push(items[i].clone());
-            backtrack(items, k, buffer, used, result);
-            buffer.pop();
-            used[i] = false;
-        }
-    }
-
-    if k == 0 {
-        return vec![vec![]];
-    }
-    if k > items.len() {
-        return vec![];
+    let mut permutations = Permutations::new(items.len(), k);
+    let mut result = Vec::with_capacity(permutations.len().unwrap_or(0));
+    while let Some(indices) = permutations.next_indices() {
+        result.push(indices.iter().map(|&index| items[index].clone()).collect());
     }
-
-    let n = items.len();
-    let count = (n - k + 1..=n).product::<usize>();
-    let mut result = Vec::with_capacity(count);
-    let mut buffer = Vec::with_capacity(k);
-    let mut used = vec![false; n];
-    backtrack(items, k, &mut buffer, &mut used, &mut result);
     result
 }
 
@@ -0,0 +1,99 @@
+use crate::common::checked_permutations;
+
+/// Returns the rank of a permutation of indices out of `0..n`, the inverse of `nth_permutation`.
+///
+/// Ranks follow the lexicographic order of `permutations` and `permutation`, so the permutation
+/// at position `r` of `permutations(n, k)` has rank `r`.
+///
+/// # Arguments
+/// * `n` - The number of items the permutation was chosen from.
+/// * `indices` - The indices of the permutation, each below `n` and none repeated.
+///
+/// # Returns
+/// * `Option<usize>` - The zero-based rank, or `None` if an index repeats or is not below `n`, or
+///   if the rank does not fit in a `usize`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{nth_permutation, permutation_rank};
+///
+/// assert_eq!(permutation_rank(3, &[0, 1, 2]), Some(0));
+/// assert_eq!(permutation_rank(3, &[1, 2, 0]), Some(3));
+/// assert_eq!(permutation_rank(3, &[2, 1]), Some(5));
+/// assert_eq!(permutation_rank(3, &[1, 1]), None);
+///
+/// let indices = nth_permutation(12, 12, 123_456_789).unwrap();
+/// assert_eq!(permutation_rank(12, &indices), Some(123_456_789));
+/// ```
+pub fn permutation_rank(n: usize, indices: &[usize]) -> Option<usize> {
+    let k = indices.len();
+    let mut rank: usize = 0;
+    for (position, &index) in indices.iter().enumerate() {
+        let chosen = &indices[..position];
+        if index >= n || chosen.contains(&index) {
+            return None;
+        }
+        // Every permutation that puts a smaller unused index here comes first.
+        let smaller = index - chosen.iter().filter(|&&taken| taken < index).count();
+        if smaller > 0 {
+            let block = checked_permutations(n - position - 1, k - position - 1)?;
+            rank = rank.checked_add(smaller.checked_mul(block)?)?;
+        }
+    }
+    Some(rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nth_permutation, permutations};
+
+    #[test]
+    fn test_permutation_rank_matches_enumeration() {
+        for n in 0..7 {
+            for k in 0..=n {
+                for (rank, indices) in permutations(n, k).enumerate() {
+                    assert_eq!(permutation_rank(n, &indices), Some(rank));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_permutation_rank_invalid() {
+        assert_eq!(permutation_rank(3, &[0, 0]), None);
+        assert_eq!(permutation_rank(3, &[3]), None);
+        assert_eq!(permutation_rank(2, &[0, 1, 0]), None);
+    }
+
+    #[test]
+    fn test_permutation_rank_empty() {
+        assert_eq!(permutation_rank(0, &[]), Some(0));
+        assert_eq!(permutation_rank(4, &[]), Some(0));
+    }
+
+    #[test]
+    fn test_permutation_rank_round_trip() {
+        for rank in [0, 1, 5_040, 479_001_599] {
+            let indices = nth_permutation(12, 12, rank).unwrap();
+            assert_eq!(permutation_rank(12, &indices), Some(rank));
+        }
+    }
+
+    #[test]
+    fn test_permutation_rank_overflow() {
+        let reversed: Vec<usize> = (0..30).rev().collect();
+        assert_eq!(permutation_rank(30, &reversed), None);
+        let mut almost_first: Vec<usize> = (0..30).collect();
+        almost_first.swap(28, 29);
+        assert_eq!(permutation_rank(30, &almost_first), Some(1));
+    }
+
+    #[test]
+    fn test_permutation_rank_large_n_small_k() {
+        let n = usize::MAX;
+        assert_eq!(permutation_rank(n, &[0, 1]), Some(0));
+        assert_eq!(permutation_rank(n, &[1, 0]), Some(n - 1));
+        assert_eq!(permutation_rank(n, &[5, 5]), None);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::common::checked_permutations;
+use std::iter::FusedIterator;
+
+/// Lazily generates the ordered arrangements of `k` distinct indices out of `0..n`, in
+/// lexicographic order.
+///
+/// Unlike `permutation`, nothing is materialised up front: the iterator keeps one arrangement of
+/// `0..n` and steps it in place, so memory use does not depend on how many permutations there are.
+/// Index the source slice with each permutation to get the items, or use
+/// `Permutations::next_into` to write them into a reused buffer.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items in each permutation.
+///
+/// # Returns
+/// * `Permutations` - An iterator over `Vec<usize>` index permutations. It yields one empty
+///   permutation when `k` is 0, and nothing when `k > n`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::permutations;
+///
+/// let all: Vec<Vec<usize>> = permutations(3, 2).collect();
+/// assert_eq!(all, vec![
+///     vec![0, 1], vec![0, 2], vec![1, 0],
+///     vec![1, 2], vec![2, 0], vec![2, 1],
+/// ]);
+///
+/// assert_eq!(permutations(20, 20).len(), Some(2_432_902_008_176_640_000));
+/// assert_eq!(permutations(21, 21).len(), None);
+///
+/// let letters = ['x', 'y', 'z'];
+/// let mut orders = permutations(letters.len(), 3);
+/// let mut buffer = Vec::new();
+/// let mut words = Vec::new();
+/// while orders.next_into(&letters, &mut buffer) {
+///     words.push(buffer.iter().collect::<String>());
+/// }
+/// assert_eq!(words, vec!["xyz", "xzy", "yxz", "yzx", "zxy", "zyx"]);
+/// ```
+pub fn permutations(n: usize, k: usize) -> Permutations {
+    Permutations::new(n, k)
+}
+
+/// Iterator returned by [`permutations`], yielding index permutations in lexicographic order.
+#[derive(Clone, Debug)]
+pub struct Permutations {
+    arrangement: Vec<usize>,
+    k: usize,
+    started: bool,
+    done: bool,
+    remaining: Option<usize>,
+}
+
+impl Permutations {
+    /// Creates an iterator over the permutations of `k` indices out of `0..n`.
+    pub fn new(n: usize, k: usize) -> Self {
+        Permutations {
+            arrangement: (0..n).collect(),
+            k,
+            started: false,
+            done: k > n,
+            remaining: checked_permutations(n, k),
+        }
+    }
+
+    /// The number of permutations not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every permutation has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.done
+    }
+
+    /// Advances to the next permutation and returns its indices, borrowed from the iterator's
+    /// own buffer so that no allocation happens per permutation.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.advance() {
+            return None;
+        }
+        Some(&self.arrangement[..self.k])
+    }
+
+    /// Advances to the next permutation and replaces the contents of `buffer` with the items of
+    /// `items` at its indices.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every permutation has been yielded.
+    ///
+    /// # Panics
+    /// Panics if `items` is shorter than the `n` the iterator was created with.
+    pub fn next_into<T: Clone>(&mut self, items: &[T], buffer: &mut Vec<T>) -> bool {
+        if !self.advance() {
+            return false;
+        }
+        buffer.clear();
+        buffer.extend(
+            self.arrangement[..self.k]
+                .iter()
+                .map(|&index| items[index].clone()),
+        );
+        true
+    }
+
+    /// The indices of the permutation yielded last.
+    pub(crate) fn indices(&self) -> &[usize] {
+        &self.arrangement[..self.k]
+    }
+
+    /// Moves `arrangement` so that its first `k` entries are the next permutation, returning
+    /// `false` when there is none.
+    fn advance(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        if self.started {
+            // The tail after the prefix is kept ascending. Reversing it makes it the largest
+            // arrangement of those indices, so the next full permutation changes the prefix.
+            self.arrangement[self.k..].reverse();
+            if !next_permutation(&mut self.arrangement) {
+                self.done = true;
+                return false;
+            }
+        }
+        self.started = true;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        if self.remaining == Some(0) {
+            self.done = true;
+        }
+        true
+    }
+}
+
+/// Rearranges `values` into the next permutation in lexicographic order, returning `false` if it
+/// was already the last one.
+fn next_permutation(values: &mut [usize]) -> bool {
+    let Some(pivot) = (1..values.len())
+        .rev()
+        .find(|&i| values[i - 1] < values[i])
+        .map(|i| i - 1)
+    else {
+        return false;
+    };
+    let successor = (pivot + 1..values.len())
+        .rev()
+        .find(|&i| values[i] > values[pivot])
+        .unwrap_or(pivot);
+    values.swap(pivot, successor);
+    values[pivot + 1..].reverse();
+    true
+}
+
+impl Iterator for Permutations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indices().map(<[usize]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for Permutations {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permutation;
+
+    #[test]
+    fn test_permutations_full() {
+        let all: Vec<Vec<usize>> = permutations(3, 3).collect();
+        assert_eq!(
+            all,
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_permutations_matches_permutation() {
+        let items = vec![1, 2, 3, 4, 5];
+        for k in 0..=6 {
+            let lazy: Vec<Vec<i32>> = permutations(items.len(), k)
+                .map(|indices| indices.iter().map(|&i| items[i]).collect())
+                .collect();
+            assert_eq!(lazy, permutation(&items, k), "k = {}", k);
+        }
+    }
+
+    #[test]
+    fn test_permutations_edge_cases() {
+        assert_eq!(
+            permutations(3, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(
+            permutations(0, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(permutations(2, 3).count(), 0);
+        assert_eq!(permutations(1, 1).collect::<Vec<_>>(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_permutations_len_counts_down() {
+        let mut iter = permutations(5, 3);
+        assert_eq!(iter.len(), Some(60));
+        assert_eq!(iter.size_hint(), (60, Some(60)));
+        iter.nth(9);
+        assert_eq!(iter.len(), Some(50));
+        assert_eq!(iter.by_ref().count(), 50);
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_permutations_len_overflow() {
+        let mut iter = permutations(30, 30);
+        assert_eq!(iter.len(), None);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.next(), Some((0..30).collect()));
+    }
+
+    #[test]
+    fn test_permutations_next_indices_and_into() {
+        let mut iter = permutations(3, 1);
+        assert_eq!(iter.next_indices(), Some(&[0][..]));
+
+        let items = ["a", "b", "c"];
+        let mut buffer = Vec::with_capacity(1);
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec!["b"]);
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec!["c"]);
+        assert!(!iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec!["c"]);
+    }
+}
@@ -0,0 +1,210 @@
+use std::iter::FusedIterator;
+
+/// Lazily generates every sequence of `k` indices out of `0..n` where an index may appear more
+/// than once, in lexicographic order.
+///
+/// This is the `k`-fold cartesian product of `0..n` with itself: the sequences count up like the
+/// digits of a base-`n` number, last position fastest.
+///
+/// # Arguments
+/// * `n` - The number of items to choose from.
+/// * `k` - The length of each sequence.
+///
+/// # Returns
+/// * `PermutationsWithRepetition` - An iterator over the nᵏ sequences, as `Vec<usize>` indices.
+///   It yields one empty sequence when `k` is 0, and nothing when `n` is 0 and `k` is not.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::permutations_with_repetition;
+///
+/// let all: Vec<Vec<usize>> = permutations_with_repetition(2, 2).collect();
+/// assert_eq!(all, vec![vec![0, 0], vec![0, 1], vec![1, 0], vec![1, 1]]);
+///
+/// let bits = ['0', '1'];
+/// let mut codes = permutations_with_repetition(bits.len(), 3);
+/// let mut buffer = Vec::new();
+/// let mut words = Vec::new();
+/// while codes.next_into(&bits, &mut buffer) {
+///     words.push(buffer.iter().collect::<String>());
+/// }
+/// assert_eq!(words, vec!["000", "001", "010", "011", "100", "101", "110", "111"]);
+/// ```
+pub fn permutations_with_repetition(n: usize, k: usize) -> PermutationsWithRepetition {
+    PermutationsWithRepetition::new(n, k)
+}
+
+/// Iterator returned by [`permutations_with_repetition`], yielding index sequences in
+/// lexicographic order.
+#[derive(Clone, Debug)]
+pub struct PermutationsWithRepetition {
+    n: usize,
+    indices: Vec<usize>,
+    started: bool,
+    done: bool,
+    remaining: Option<usize>,
+}
+
+impl PermutationsWithRepetition {
+    /// Creates an iterator over the sequences of `k` indices out of `0..n`.
+    pub fn new(n: usize, k: usize) -> Self {
+        let remaining = match n {
+            0 => Some(usize::from(k == 0)),
+            1 => Some(1),
+            _ => u32::try_from(k).ok().and_then(|k| n.checked_pow(k)),
+        };
+        PermutationsWithRepetition {
+            n,
+            indices: vec![0; k],
+            started: false,
+            done: n == 0 && k > 0,
+            remaining,
+        }
+    }
+
+    /// The number of sequences not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every sequence has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.done
+    }
+
+    /// Advances to the next sequence and returns its indices, borrowed from the iterator's own
+    /// buffer so that no allocation happens per sequence.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.advance() {
+            return None;
+        }
+        Some(&self.indices)
+    }
+
+    /// Advances to the next sequence and replaces the contents of `buffer` with the items of
+    /// `items` at its indices.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every sequence has been yielded.
+    ///
+    /// # Panics
+    /// Panics if `items` is shorter than the `n` the iterator was created with.
+    pub fn next_into<T: Clone>(&mut self, items: &[T], buffer: &mut Vec<T>) -> bool {
+        if !self.advance() {
+            return false;
+        }
+        buffer.clear();
+        buffer.extend(self.indices.iter().map(|&index| items[index].clone()));
+        true
+    }
+
+    /// Moves `indices` to the next sequence, returning `false` when there is none.
+    fn advance(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        if self.started {
+            match self.indices.iter().rposition(|&index| index + 1 < self.n) {
+                Some(i) => {
+                    self.indices[i] += 1;
+                    self.indices[i + 1..].fill(0);
+                }
+                None => {
+                    self.done = true;
+                    return false;
+                }
+            }
+        }
+        self.started = true;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        if self.remaining == Some(0) {
+            self.done = true;
+        }
+        true
+    }
+}
+
+impl Iterator for PermutationsWithRepetition {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indices().map(<[usize]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for PermutationsWithRepetition {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permutations_with_repetition_counts_in_base_n() {
+        let all: Vec<Vec<usize>> = permutations_with_repetition(3, 2).collect();
+        assert_eq!(all.len(), 9);
+        for (number, digits) in all.iter().enumerate() {
+            assert_eq!(digits[0] * 3 + digits[1], number);
+        }
+    }
+
+    #[test]
+    fn test_permutations_with_repetition_edge_cases() {
+        assert_eq!(
+            permutations_with_repetition(3, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(
+            permutations_with_repetition(0, 0).collect::<Vec<_>>(),
+            vec![Vec::<usize>::new()]
+        );
+        assert_eq!(permutations_with_repetition(0, 3).count(), 0);
+        assert_eq!(
+            permutations_with_repetition(1, 2).collect::<Vec<_>>(),
+            vec![vec![0, 0]]
+        );
+    }
+
+    #[test]
+    fn test_permutations_with_repetition_len() {
+        let mut iter = permutations_with_repetition(4, 3);
+        assert_eq!(iter.len(), Some(64));
+        assert_eq!(iter.size_hint(), (64, Some(64)));
+        iter.nth(9);
+        assert_eq!(iter.len(), Some(54));
+        assert_eq!(iter.by_ref().count(), 54);
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+        assert_eq!(permutations_with_repetition(0, 3).len(), Some(0));
+        assert_eq!(permutations_with_repetition(1, 1_000).len(), Some(1));
+        assert_eq!(permutations_with_repetition(2, 64).len(), None);
+    }
+
+    #[test]
+    fn test_permutations_with_repetition_overflow_still_iterates() {
+        let mut iter = permutations_with_repetition(2, 70);
+        assert_eq!(iter.len(), None);
+        let first = iter.next().unwrap();
+        assert!(first.iter().all(|&index| index == 0));
+        let second = iter.next_indices().unwrap();
+        assert_eq!(second[69], 1);
+    }
+
+    #[test]
+    fn test_permutations_with_repetition_next_into() {
+        let items = [true, false];
+        let mut iter = permutations_with_repetition(2, 1);
+        let mut buffer = Vec::new();
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec![true]);
+        assert!(iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec![false]);
+        assert!(!iter.next_into(&items, &mut buffer));
+        assert_eq!(buffer, vec![false]);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Filters a map by applying a predicate to its key-value pairs.
 ///
 /// Iterates over each key-value pair in the input map and includes it in the result map
@@ -8,7 +10,7 @@
 /// * `predicate` - A function that takes a key and value, and returns `true` if the pair should be included.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - A new map containing all key-value pairs that satisfy the predicate.
+/// * `M` - A new map of the same type as the input containing all key-value pairs that satisfy the predicate.
 ///
 /// # Examples
 /// ```rust
@@ -25,22 +27,18 @@
 /// assert!(result.contains_key("b"));
 /// assert!(result.contains_key("c"));
 /// ```
-pub fn pick_by<K, V, F>(
-    map: &std::collections::HashMap<K, V>,
-    predicate: F,
-) -> std::collections::HashMap<K, V>
+pub fn pick_by<M, K, V, F>(map: &M, predicate: F) -> M
 where
-    K: std::cmp::Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
     F: Fn(&K, &V) -> bool,
 {
-    let mut result = std::collections::HashMap::with_capacity(map.len());
-    for (k, v) in map.iter() {
-        if predicate(k, v) {
-            result.insert(k.clone(), v.clone());
-        }
-    }
-    result
+    MapLike::from_iter(
+        map.iter()
+            .filter(|(k, v)| predicate(k, v))
+            .map(|(k, v)| (k.clone(), v.clone())),
+    )
 }
 
 #[cfg(test)]
@@ -114,4 +112,37 @@ mod tests {
         assert!(result.contains_key(&2));
         assert!(result.contains_key(&3));
     }
+
+    #[test]
+    fn test_pick_by_btree_map() {
+        use std::collections::BTreeMap;
+
+        let mut map = BTreeMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        let result: BTreeMap<&str, i32> = pick_by(&map, |_, v| *v != 2);
+        assert_eq!(
+            result.into_iter().collect::<Vec<_>>(),
+            vec![("a", 1), ("c", 3)]
+        );
+    }
+
+    #[test]
+    fn test_pick_by_entry_vec() {
+        use crate::Entry;
+
+        let list = vec![
+            Entry { key: "b", value: 2 },
+            Entry { key: "a", value: 1 },
+            Entry { key: "c", value: 3 },
+        ];
+
+        let result = pick_by(&list, |_, v| *v != 1);
+        assert_eq!(
+            result,
+            vec![Entry { key: "b", value: 2 }, Entry { key: "c", value: 3 }]
+        );
+    }
 }
@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Filters a map by selecting only the specified keys.
 ///
 /// Iterates over the provided keys and includes the key-value pair in the result map
@@ -8,7 +10,7 @@
 /// * `keys` - A slice of keys to select from the map.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - A new map containing only the specified key-value pairs.
+/// * `M` - A new map of the same type as the input containing only the specified key-value pairs.
 ///
 /// # Examples
 /// ```rust
@@ -26,21 +28,16 @@
 /// assert!(result.contains_key("a"));
 /// assert!(result.contains_key("c"));
 /// ```
-pub fn pick_by_keys<K, V>(
-    map: &std::collections::HashMap<K, V>,
-    keys: &[K],
-) -> std::collections::HashMap<K, V>
+pub fn pick_by_keys<M, K, V>(map: &M, keys: &[K]) -> M
 where
-    K: std::cmp::Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
 {
-    let mut result = std::collections::HashMap::new();
-    for key in keys {
-        if let Some(value) = map.get(key) {
-            result.insert(key.clone(), value.clone());
-        }
-    }
-    result
+    MapLike::from_iter(
+        keys.iter()
+            .filter_map(|key| map.get(key).map(|value| (key.clone(), value.clone()))),
+    )
 }
 
 #[cfg(test)]
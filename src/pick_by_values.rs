@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Filters a map by selecting only the specified values.
 ///
 /// Iterates over each key-value pair in the input map and includes it in the result map
@@ -8,7 +10,7 @@
 /// * `values` - A slice of values to select from the map.
 ///
 /// # Returns
-/// * `HashMap<K, V>` - A new map containing only the key-value pairs that have values present in the values slice.
+/// * `M` - A new map of the same type as the input containing only the key-value pairs that have values present in the values slice.
 ///
 /// # Examples
 /// ```rust
@@ -26,22 +28,18 @@
 /// assert!(result.contains_key("a"));
 /// assert!(result.contains_key("c"));
 /// ```
-pub fn pick_by_values<K, V>(
-    map: &std::collections::HashMap<K, V>,
-    values: &[V],
-) -> std::collections::HashMap<K, V>
+pub fn pick_by_values<M, K, V>(map: &M, values: &[V]) -> M
 where
-    K: std::cmp::Eq + std::hash::Hash + Clone,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: std::cmp::Eq + std::hash::Hash + Clone,
 {
-    let value_set: std::collections::HashSet<V> = values.iter().cloned().collect();
-    let mut result = std::collections::HashMap::new();
-    for (k, v) in map.iter() {
-        if value_set.contains(v) {
-            result.insert(k.clone(), v.clone());
-        }
-    }
-    result
+    let value_set: std::collections::HashSet<&V> = values.iter().collect();
+    MapLike::from_iter(
+        map.iter()
+            .filter(|(_, v)| value_set.contains(v))
+            .map(|(k, v)| (k.clone(), v.clone())),
+    )
 }
 
 #[cfg(test)]
@@ -0,0 +1,186 @@
+use crate::Combinations;
+use std::iter::FusedIterator;
+
+/// Lazily generates every subset of the indices `0..n`, smallest subsets first.
+///
+/// Subsets of the same size come in lexicographic order, as produced by `combinations`, so the
+/// empty subset is first and `0..n` itself is last.
+///
+/// # Arguments
+/// * `n` - The number of items in the set.
+///
+/// # Returns
+/// * `PowerSet` - An iterator over the 2ⁿ subsets, as `Vec<usize>` indices.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::power_set;
+///
+/// let subsets: Vec<Vec<usize>> = power_set(3).collect();
+/// assert_eq!(subsets, vec![
+///     vec![],
+///     vec![0], vec![1], vec![2],
+///     vec![0, 1], vec![0, 2], vec![1, 2],
+///     vec![0, 1, 2],
+/// ]);
+///
+/// let flags = ["--verbose", "--quiet"];
+/// let mut subsets = power_set(flags.len());
+/// let mut buffer = Vec::new();
+/// let mut command_lines = Vec::new();
+/// while subsets.next_into(&flags, &mut buffer) {
+///     command_lines.push(buffer.join(" "));
+/// }
+/// assert_eq!(command_lines, vec!["", "--verbose", "--quiet", "--verbose --quiet"]);
+/// ```
+pub fn power_set(n: usize) -> PowerSet {
+    PowerSet::new(n)
+}
+
+/// Iterator returned by [`power_set`], yielding subsets by increasing size.
+#[derive(Clone, Debug)]
+pub struct PowerSet {
+    n: usize,
+    combinations: Combinations,
+    remaining: Option<usize>,
+}
+
+impl PowerSet {
+    /// Creates an iterator over the subsets of `0..n`.
+    pub fn new(n: usize) -> Self {
+        PowerSet {
+            n,
+            combinations: Combinations::new(n, 0),
+            remaining: u32::try_from(n)
+                .ok()
+                .and_then(|bits| 1usize.checked_shl(bits)),
+        }
+    }
+
+    /// The number of subsets not yet yielded, or `None` if it does not fit in a `usize`.
+    pub fn len(&self) -> Option<usize> {
+        self.remaining
+    }
+
+    /// Whether every subset has been yielded.
+    pub fn is_empty(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Advances to the next subset and returns its indices, borrowed from the iterator's own
+    /// buffer so that no allocation happens per subset.
+    pub fn next_indices(&mut self) -> Option<&[usize]> {
+        if !self.advance() {
+            return None;
+        }
+        // `advance` has just stepped `combinations`, whose buffer now holds the subset.
+        Some(self.combinations.indices())
+    }
+
+    /// Advances to the next subset and replaces the contents of `buffer` with the items of
+    /// `items` at its indices.
+    ///
+    /// Returns `false`, leaving `buffer` untouched, once every subset has been yielded.
+    ///
+    /// # Panics
+    /// Panics if `items` is shorter than the `n` the iterator was created with.
+    pub fn next_into<T: Clone>(&mut self, items: &[T], buffer: &mut Vec<T>) -> bool {
+        match self.next_indices() {
+            Some(indices) => {
+                buffer.clear();
+                buffer.extend(indices.iter().map(|&index| items[index].clone()));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Steps to the next subset, moving on to the next size when the current one is exhausted.
+    fn advance(&mut self) -> bool {
+        loop {
+            if self.combinations.next_indices().is_some() {
+                self.remaining = self.remaining.map(|remaining| remaining - 1);
+                return true;
+            }
+            let size = self.combinations.k();
+            if size >= self.n {
+                return false;
+            }
+            self.combinations = Combinations::new(self.n, size + 1);
+        }
+    }
+}
+
+impl Iterator for PowerSet {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indices().map(<[usize]>::to_vec)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for PowerSet {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_set_empty_set() {
+        assert_eq!(power_set(0).collect::<Vec<_>>(), vec![Vec::<usize>::new()]);
+    }
+
+    #[test]
+    fn test_power_set_count_and_uniqueness() {
+        let subsets: Vec<Vec<usize>> = power_set(6).collect();
+        assert_eq!(subsets.len(), 64);
+        let mut sorted = subsets.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 64);
+        assert!(subsets
+            .windows(2)
+            .all(|pair| pair[0].len() <= pair[1].len()));
+    }
+
+    #[test]
+    fn test_power_set_len_counts_down() {
+        let mut iter = power_set(4);
+        assert_eq!(iter.len(), Some(16));
+        assert_eq!(iter.size_hint(), (16, Some(16)));
+        iter.nth(4);
+        assert_eq!(iter.len(), Some(11));
+        assert_eq!(iter.by_ref().count(), 11);
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_power_set_len_overflow() {
+        let mut iter = power_set(usize::BITS as usize);
+        assert_eq!(iter.len(), None);
+        assert_eq!(iter.next(), Some(vec![]));
+        assert_eq!(iter.next(), Some(vec![0]));
+        assert_eq!(power_set(10).len(), Some(1024));
+    }
+
+    #[test]
+    fn test_power_set_next_into() {
+        let items = ['a', 'b'];
+        let mut iter = power_set(2);
+        let mut buffer = vec!['z'];
+        let mut seen = Vec::new();
+        while iter.next_into(&items, &mut buffer) {
+            seen.push(buffer.iter().collect::<String>());
+        }
+        assert_eq!(seen, vec!["", "a", "b", "ab"]);
+        assert_eq!(buffer, vec!['a', 'b']);
+    }
+}
@@ -0,0 +1,382 @@
+/// The interpolation method used by `quantiles`.
+///
+/// The variants follow the methods of numpy's `quantile`/`percentile`, which also cover the nine
+/// sample quantile definitions of Hyndman and Fan (1996) used by R's `quantile(type = ...)`.
+/// `Linear` is the default of both numpy and R and matches `percentile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QuantileMethod {
+    /// Linear interpolation between closest ranks (Hyndman–Fan type 7).
+    #[default]
+    Linear,
+    /// The closest rank at or below the quantile.
+    Lower,
+    /// The closest rank at or above the quantile.
+    Higher,
+    /// The closest rank, rounding halves to the even rank.
+    Nearest,
+    /// The average of the two closest ranks.
+    Midpoint,
+    /// Inverse of the empirical distribution function (Hyndman–Fan type 1).
+    InvertedCdf,
+    /// Inverse of the empirical distribution function, averaging at discontinuities
+    /// (Hyndman–Fan type 2).
+    AveragedInvertedCdf,
+    /// The closest observation, choosing the even one on ties (Hyndman–Fan type 3).
+    ClosestObservation,
+    /// Linear interpolation of the empirical distribution function (Hyndman–Fan type 4).
+    InterpolatedInvertedCdf,
+    /// Piecewise linear with nodes at the midpoints of the steps (Hyndman–Fan type 5).
+    Hazen,
+    /// Linear interpolation of the expectations of the order statistics (Hyndman–Fan type 6).
+    Weibull,
+    /// Approximately median-unbiased regardless of the distribution (Hyndman–Fan type 8).
+    MedianUnbiased,
+    /// Approximately unbiased for normally distributed data (Hyndman–Fan type 9).
+    NormalUnbiased,
+}
+
+impl QuantileMethod {
+    /// Returns the method for a Hyndman–Fan sample quantile type, as numbered by R.
+    ///
+    /// # Arguments
+    /// * `sample_type` - The type number, from 1 to 9.
+    ///
+    /// # Returns
+    /// * `Option<QuantileMethod>` - The matching method, or None if the number is not between 1 and 9.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::QuantileMethod;
+    ///
+    /// assert_eq!(QuantileMethod::hyndman_fan(7), Some(QuantileMethod::Linear));
+    /// assert_eq!(QuantileMethod::hyndman_fan(10), None);
+    /// ```
+    pub fn hyndman_fan(sample_type: u8) -> Option<Self> {
+        match sample_type {
+            1 => Some(QuantileMethod::InvertedCdf),
+            2 => Some(QuantileMethod::AveragedInvertedCdf),
+            3 => Some(QuantileMethod::ClosestObservation),
+            4 => Some(QuantileMethod::InterpolatedInvertedCdf),
+            5 => Some(QuantileMethod::Hazen),
+            6 => Some(QuantileMethod::Weibull),
+            7 => Some(QuantileMethod::Linear),
+            8 => Some(QuantileMethod::MedianUnbiased),
+            9 => Some(QuantileMethod::NormalUnbiased),
+            _ => None,
+        }
+    }
+}
+
+/// Calculates several percentiles of a collection with the chosen interpolation method.
+/// The collection is copied and sorted once, however many percentiles are requested.
+/// The results match numpy's `percentile(collection, percentiles, method=...)`.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values.
+/// * `percentiles` - The percentiles to compute, each between 0 and 100 inclusive.
+/// * `method` - The interpolation method.
+///
+/// # Returns
+/// * `Option<Vec<f64>>` - One value per requested percentile, in the same order, or None if the
+///   collection is empty or a percentile is out of range.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{quantiles, QuantileMethod};
+///
+/// let numbers = vec![7, 1, 3, 5];
+/// assert_eq!(
+///     quantiles(&numbers, &[25.0, 50.0, 75.0], QuantileMethod::Linear),
+///     Some(vec![2.5, 4.0, 5.5])
+/// );
+/// assert_eq!(
+///     quantiles(&numbers, &[25.0, 50.0, 75.0], QuantileMethod::Lower),
+///     Some(vec![1.0, 3.0, 5.0])
+/// );
+/// assert_eq!(
+///     quantiles(&numbers, &[50.0], QuantileMethod::InvertedCdf),
+///     Some(vec![3.0])
+/// );
+/// assert_eq!(quantiles(&numbers, &[101.0], QuantileMethod::Linear), None);
+/// ```
+pub fn quantiles<T>(
+    collection: &[T],
+    percentiles: &[f64],
+    method: QuantileMethod,
+) -> Option<Vec<f64>>
+where
+    T: Copy + Into<f64>,
+{
+    if collection.is_empty() || percentiles.iter().any(|p| !(0.0..=100.0).contains(p)) {
+        return None;
+    }
+
+    let mut sorted: Vec<f64> = collection.iter().map(|&x| x.into()).collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    Some(
+        percentiles
+            .iter()
+            .map(|&p| sorted_quantile(&sorted, p / 100.0, method))
+            .collect(),
+    )
+}
+
+fn sorted_quantile(sorted: &[f64], q: f64, method: QuantileMethod) -> f64 {
+    let n = sorted.len() as f64;
+    let last = sorted.len() - 1;
+    let at = |index: f64| sorted[index.clamp(0.0, last as f64) as usize];
+
+    match method {
+        QuantileMethod::Lower => at(((n - 1.0) * q).floor()),
+        QuantileMethod::Higher => at(((n - 1.0) * q).ceil()),
+        QuantileMethod::Nearest => at(((n - 1.0) * q).round_ties_even()),
+        QuantileMethod::Midpoint => {
+            let index = (n - 1.0) * q;
+            let gamma = if index.fract() == 0.0 { 0.0 } else { 0.5 };
+            interpolate(sorted, index, gamma)
+        }
+        QuantileMethod::InvertedCdf => {
+            let index = n * q - 1.0;
+            let previous = index.floor();
+            at(if index > previous {
+                previous + 1.0
+            } else {
+                previous
+            })
+        }
+        QuantileMethod::AveragedInvertedCdf => {
+            let index = n * q - 1.0;
+            let gamma = if index.fract() == 0.0 { 0.5 } else { 1.0 };
+            interpolate(sorted, index, gamma)
+        }
+        QuantileMethod::ClosestObservation => {
+            // Hyndman and Fan pick the even order statistic on ties; `previous` is 0-based, so an
+            // even `previous` is an odd order statistic and the next one is taken instead.
+            let index = n * q - 1.5;
+            let previous = index.floor();
+            let even = previous.rem_euclid(2.0) == 0.0;
+            at(if index > previous || even {
+                previous + 1.0
+            } else {
+                previous
+            })
+        }
+        QuantileMethod::Linear => {
+            let index = (n - 1.0) * q;
+            interpolate(sorted, index, index - index.floor())
+        }
+        QuantileMethod::InterpolatedInvertedCdf => continuous(sorted, q, 0.0, 1.0),
+        QuantileMethod::Hazen => continuous(sorted, q, 0.5, 0.5),
+        QuantileMethod::Weibull => continuous(sorted, q, 0.0, 0.0),
+        QuantileMethod::MedianUnbiased => continuous(sorted, q, 1.0 / 3.0, 1.0 / 3.0),
+        QuantileMethod::NormalUnbiased => continuous(sorted, q, 3.0 / 8.0, 3.0 / 8.0),
+    }
+}
+
+/// Continuous sample quantile with plotting positions `(k - alpha) / (n + 1 - alpha - beta)`.
+fn continuous(sorted: &[f64], q: f64, alpha: f64, beta: f64) -> f64 {
+    let n = sorted.len() as f64;
+    let index = n * q + (alpha + q * (1.0 - alpha - beta)) - 1.0;
+    interpolate(sorted, index, index - index.floor())
+}
+
+/// Interpolates between the values at `floor(index)` and the next rank by `gamma`, taking the first
+/// or last value when `index` falls outside the collection.
+fn interpolate(sorted: &[f64], index: f64, gamma: f64) -> f64 {
+    let last = sorted.len() - 1;
+    if index < 0.0 {
+        return sorted[0];
+    }
+    if index >= last as f64 {
+        return sorted[last];
+    }
+
+    let previous = index.floor() as usize;
+    let a = sorted[previous];
+    let b = sorted[previous + 1];
+    // The same two-sided form as numpy, so results agree to the last bit.
+    let diff = b - a;
+    if gamma >= 0.5 {
+        b - diff * (1.0 - gamma)
+    } else {
+        a + diff * gamma
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::percentile;
+
+    const PERCENTILES: [f64; 7] = [0.0, 10.0, 25.0, 50.0, 75.0, 90.0, 100.0];
+
+    fn check(method: QuantileMethod, expected: [f64; 7]) {
+        let data = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0, 3.0];
+        let result = quantiles(&data, &PERCENTILES, method).unwrap();
+        for (r, e) in result.iter().zip(expected.iter()) {
+            assert!(
+                (r - e).abs() < 1e-12,
+                "{:?}: {:?} != {:?}",
+                method,
+                result,
+                expected
+            );
+        }
+    }
+
+    // Expected values from R quantile(data, PERCENTILES / 100, type = ...), which numpy matches.
+    #[test]
+    fn test_quantiles_linear() {
+        check(QuantileMethod::Linear, [1.0, 1.0, 2.25, 3.5, 5.0, 6.3, 9.0]);
+    }
+
+    #[test]
+    fn test_quantiles_lower() {
+        check(QuantileMethod::Lower, [1.0, 1.0, 2.0, 3.0, 5.0, 6.0, 9.0]);
+    }
+
+    #[test]
+    fn test_quantiles_higher() {
+        check(QuantileMethod::Higher, [1.0, 1.0, 3.0, 4.0, 5.0, 9.0, 9.0]);
+    }
+
+    #[test]
+    fn test_quantiles_nearest() {
+        check(QuantileMethod::Nearest, [1.0, 1.0, 2.0, 3.0, 5.0, 6.0, 9.0]);
+    }
+
+    #[test]
+    fn test_quantiles_midpoint() {
+        check(
+            QuantileMethod::Midpoint,
+            [1.0, 1.0, 2.5, 3.5, 5.0, 7.5, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_inverted_cdf() {
+        check(
+            QuantileMethod::InvertedCdf,
+            [1.0, 1.0, 2.0, 3.0, 5.0, 6.0, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_averaged_inverted_cdf() {
+        check(
+            QuantileMethod::AveragedInvertedCdf,
+            [1.0, 1.0, 2.0, 3.5, 5.0, 7.5, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_closest_observation() {
+        check(
+            QuantileMethod::ClosestObservation,
+            [1.0, 1.0, 1.0, 3.0, 5.0, 6.0, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_interpolated_inverted_cdf() {
+        check(
+            QuantileMethod::InterpolatedInvertedCdf,
+            [1.0, 1.0, 1.5, 3.0, 5.0, 6.0, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_hazen() {
+        check(QuantileMethod::Hazen, [1.0, 1.0, 2.0, 3.5, 5.0, 7.5, 9.0]);
+    }
+
+    #[test]
+    fn test_quantiles_weibull() {
+        check(
+            QuantileMethod::Weibull,
+            [1.0, 1.0, 1.75, 3.5, 5.25, 8.7, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_median_unbiased() {
+        check(
+            QuantileMethod::MedianUnbiased,
+            [
+                1.0,
+                1.0,
+                1.9166666666666667,
+                3.5,
+                5.083333333333333,
+                7.9,
+                9.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_normal_unbiased() {
+        check(
+            QuantileMethod::NormalUnbiased,
+            [1.0, 1.0, 1.9375, 3.5, 5.0625, 7.8, 9.0],
+        );
+    }
+
+    #[test]
+    fn test_quantiles_linear_matches_percentile() {
+        let data: Vec<i32> = (0..37).map(|i| (i * 17) % 23).collect();
+        let ps: Vec<f64> = (0..=100).map(|p| p as f64).collect();
+        let result = quantiles(&data, &ps, QuantileMethod::default()).unwrap();
+        for (p, r) in ps.iter().zip(result) {
+            assert!((percentile(&data, *p).unwrap() - r).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_quantiles_single_element() {
+        for t in 1..=9 {
+            let method = QuantileMethod::hyndman_fan(t).unwrap();
+            assert_eq!(quantiles(&[4], &PERCENTILES, method), Some(vec![4.0; 7]));
+        }
+    }
+
+    #[test]
+    fn test_quantiles_empty_collection() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(quantiles(&empty, &[50.0], QuantileMethod::Linear), None);
+    }
+
+    #[test]
+    fn test_quantiles_no_percentiles() {
+        assert_eq!(
+            quantiles(&[1, 2], &[], QuantileMethod::Linear),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_quantiles_out_of_range() {
+        assert_eq!(
+            quantiles(&[1, 2], &[50.0, -1.0], QuantileMethod::Linear),
+            None
+        );
+        assert_eq!(
+            quantiles(&[1, 2], &[f64::NAN], QuantileMethod::Linear),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hyndman_fan() {
+        assert_eq!(
+            QuantileMethod::hyndman_fan(1),
+            Some(QuantileMethod::InvertedCdf)
+        );
+        assert_eq!(
+            QuantileMethod::hyndman_fan(9),
+            Some(QuantileMethod::NormalUnbiased)
+        );
+        assert_eq!(QuantileMethod::hyndman_fan(0), None);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::common;
+use crate::common::EntropyRng;
+use crate::random_string_with_rng;
 
 /// Generates a random string of a specified size using the provided charset.
 ///
@@ -33,42 +34,7 @@ pub fn random_string(size: usize, charset: &[char]) -> String {
         panic!("common::random_string: Charset parameter must not be empty");
     }
 
-    // Calculate the number of bits required to represent the charset
-    let charset_len = charset.len();
-    let letter_id_bits = common::ceil_log2(charset_len);
-    let letter_id_mask = (1 << letter_id_bits) - 1;
-    let letter_id_max = 63 / letter_id_bits;
-
-    let mut result = String::with_capacity(size);
-    let mut bits_remaining = 0;
-    let mut cache: u64 = 0;
-
-    for _ in 0..size {
-        if bits_remaining == 0 {
-            cache = common::random_u64();
-            bits_remaining = letter_id_max;
-        }
-
-        let idx = (cache & letter_id_mask as u64) as usize;
-        cache >>= letter_id_bits;
-        bits_remaining -= 1;
-
-        if idx < charset_len {
-            result.push(charset[idx]);
-        } else {
-            // If the index is out of range, retry with a new random number
-            let new_cache = common::random_u64();
-            let new_idx = (new_cache & letter_id_mask as u64) as usize;
-            if new_idx < charset_len {
-                result.push(charset[new_idx]);
-            } else {
-                // Fallback to the first character if all else fails
-                result.push(charset[0]);
-            }
-        }
-    }
-
-    result
+    random_string_with_rng(size, charset, &mut EntropyRng)
 }
 
 #[cfg(test)]
@@ -0,0 +1,125 @@
+use crate::common::{self, Rng};
+
+/// Generates a random string of a specified size using the provided charset and random number generator.
+///
+/// # Arguments
+///
+/// * `size` - The length of the generated string. Must be greater than 0.
+/// * `charset` - A slice of characters to use for generating the string. Must not be empty.
+/// * `rng` - The random number generator to draw from.
+///
+/// # Panics
+///
+/// * If `size` is less than or equal to 0.
+/// * If `charset` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use lowdash::common::{SeededRng, ALPHANUMERIC_CHARSET};
+/// use lowdash::random_string_with_rng;
+///
+/// let charset = ALPHANUMERIC_CHARSET;
+/// let first = random_string_with_rng(10, charset, &mut SeededRng::new(42));
+/// let second = random_string_with_rng(10, charset, &mut SeededRng::new(42));
+/// assert_eq!(first.len(), 10);
+/// assert_eq!(first, second);
+/// ```
+pub fn random_string_with_rng<R>(size: usize, charset: &[char], rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    if size == 0 {
+        panic!("random_string_with_rng: Size parameter must be greater than 0");
+    }
+    if charset.is_empty() {
+        panic!("random_string_with_rng: Charset parameter must not be empty");
+    }
+
+    // Calculate the number of bits required to represent the charset
+    let charset_len = charset.len();
+    let letter_id_bits = common::ceil_log2(charset_len);
+    let letter_id_mask = (1 << letter_id_bits) - 1;
+    let letter_id_max = 63 / letter_id_bits;
+
+    let mut result = String::with_capacity(size);
+    let mut bits_remaining = 0;
+    let mut cache: u64 = 0;
+    let mut generated = 0;
+
+    while generated < size {
+        if bits_remaining == 0 {
+            cache = rng.next_u64();
+            bits_remaining = letter_id_max;
+        }
+
+        let idx = (cache & letter_id_mask as u64) as usize;
+        cache >>= letter_id_bits;
+        bits_remaining -= 1;
+
+        // Indices past the charset are rejected rather than remapped, so every
+        // character stays equally likely
+        if idx < charset_len {
+            result.push(charset[idx]);
+            generated += 1;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::SeededRng;
+
+    #[test]
+    fn test_random_string_with_rng_is_reproducible() {
+        let charset = common::ALPHANUMERIC_CHARSET;
+        let first = random_string_with_rng(32, charset, &mut SeededRng::new(100));
+        let second = random_string_with_rng(32, charset, &mut SeededRng::new(100));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_string_with_rng_different_seeds() {
+        let charset = common::ALPHANUMERIC_CHARSET;
+        let first = random_string_with_rng(32, charset, &mut SeededRng::new(1));
+        let second = random_string_with_rng(32, charset, &mut SeededRng::new(2));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_random_string_with_rng_characters() {
+        let charset = common::SPECIAL_CHARSET;
+        let random_str = random_string_with_rng(64, charset, &mut SeededRng::new(3));
+        assert_eq!(random_str.chars().count(), 64);
+        for c in random_str.chars() {
+            assert!(charset.contains(&c));
+        }
+    }
+
+    #[test]
+    fn test_random_string_with_rng_is_unbiased() {
+        // Three characters need two bits per draw, so one in four draws is rejected
+        let charset = &['a', 'b', 'c'];
+        let random_str = random_string_with_rng(30_000, charset, &mut SeededRng::new(7));
+        for c in charset {
+            let count = random_str.chars().filter(|x| x == c).count();
+            assert!((9_000..11_000).contains(&count), "{c}: {count}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "random_string_with_rng: Size parameter must be greater than 0")]
+    fn test_random_string_with_rng_size_zero() {
+        let _ = random_string_with_rng(0, common::ALPHANUMERIC_CHARSET, &mut SeededRng::new(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "random_string_with_rng: Charset parameter must not be empty")]
+    fn test_random_string_with_rng_empty_charset() {
+        let charset: &[char] = &[];
+        let _ = random_string_with_rng(10, charset, &mut SeededRng::new(3));
+    }
+}
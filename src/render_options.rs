@@ -0,0 +1,21 @@
+/// Options for [`Template::render_with_options`](crate::Template::render_with_options).
+///
+/// The default options insert values unchanged, as [`Template::render`](crate::Template::render)
+/// does.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{RenderOptions, Template};
+///
+/// let template = Template::compile("<p>{{comment}}</p>").unwrap();
+/// let options = RenderOptions { escape_html: true };
+/// let rendered = template.render_with_options(|_| Some("<b>hi</b>"), &options);
+/// assert_eq!(rendered.unwrap(), "<p>&lt;b&gt;hi&lt;/b&gt;</p>");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RenderOptions {
+    /// Pass every substituted value through [`escape_html`](crate::escape_html), like lodash's
+    /// `<%- %>` delimiter. Literal text and placeholder defaults come from the template author and
+    /// are left unchanged.
+    pub escape_html: bool,
+}
@@ -1,4 +1,5 @@
-use crate::common;
+use crate::common::EntropyRng;
+use crate::sample_with_rng;
 
 /// Returns a pseudo-random element from the collection.
 /// If the collection is empty, returns the default value of T.
@@ -25,14 +26,7 @@ pub fn sample<T>(collection: &[T]) -> T
 where
     T: Clone + Default,
 {
-    let size = collection.len();
-    if size == 0 {
-        return T::default();
-    }
-
-    let index = common::random_usize(size);
-
-    collection[index].clone()
+    sample_with_rng(collection, &mut EntropyRng)
 }
 
 #[cfg(test)]
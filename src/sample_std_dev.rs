@@ -0,0 +1,68 @@
+use crate::sample_std_dev_by;
+
+/// Calculate the sample standard deviation of a collection.
+/// The sample standard deviation is the square root of the sample variance.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The sample standard deviation, or None if the collection has fewer than two elements
+///
+/// # Examples
+/// ```rust
+/// use lowdash::sample_std_dev;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// let result = sample_std_dev(&numbers);
+/// assert!((result.unwrap() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+/// ```
+pub fn sample_std_dev<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    sample_std_dev_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_std_dev_integers() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let result = sample_std_dev(&numbers);
+        assert!((result.unwrap() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_std_dev_floats() {
+        let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let result = sample_std_dev(&numbers);
+        assert!((result.unwrap() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_std_dev_is_shift_invariant() {
+        let numbers: Vec<f64> = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|x| x + 1e9)
+            .collect();
+        let result = sample_std_dev(&numbers);
+        assert!((result.unwrap() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sample_std_dev_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(sample_std_dev(&numbers), None);
+    }
+
+    #[test]
+    fn test_sample_std_dev_single() {
+        let numbers: Vec<f64> = vec![5.0];
+        assert_eq!(sample_std_dev(&numbers), None);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::moments::Moments;
+
+/// Calculate the sample standard deviation of the values produced by applying an iteratee to each element
+/// of a collection.
+/// The sample standard deviation is the square root of the sample variance.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The sample standard deviation, or None if the collection has fewer than two elements
+///
+/// # Examples
+/// ```rust
+/// use lowdash::sample_std_dev_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// let result = sample_std_dev_by(&people, |p| p.age as f64);
+/// assert!((result.unwrap() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+/// ```
+pub fn sample_std_dev_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    Moments::from_values(collection.iter().map(iteratee))
+        .sample_variance()
+        .map(f64::sqrt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sample_variance_by;
+
+    #[test]
+    fn test_sample_std_dev_by_is_root_of_sample_variance() {
+        let lengths = vec!["a", "abc", "abcd", "abcdefg"];
+        let std_dev = sample_std_dev_by(&lengths, |w| w.len() as f64).unwrap();
+        let variance = sample_variance_by(&lengths, |w| w.len() as f64).unwrap();
+        assert!((std_dev * std_dev - variance).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_std_dev_by_two_elements() {
+        // Two values a and b have a sample standard deviation of |a - b| / sqrt(2)
+        let pairs = vec![(1.0, 'x'), (5.0, 'y')];
+        let result = sample_std_dev_by(&pairs, |&(v, _)| v).unwrap();
+        assert!((result - 4.0 / 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_std_dev_by_needs_two_elements() {
+        assert_eq!(sample_std_dev_by(&["only"], |w| w.len() as f64), None);
+        let empty: Vec<&str> = vec![];
+        assert_eq!(sample_std_dev_by(&empty, |w| w.len() as f64), None);
+    }
+}
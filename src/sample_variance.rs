@@ -0,0 +1,70 @@
+use crate::sample_variance_by;
+
+/// Calculate the sample variance of a collection.
+/// The sample variance divides the sum of squared differences from the mean by `n - 1`
+/// (Bessel's correction), giving an unbiased estimate of the variance of the population the
+/// sample was drawn from.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The sample variance, or None if the collection has fewer than two elements
+///
+/// # Examples
+/// ```rust
+/// use lowdash::sample_variance;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// let result = sample_variance(&numbers);
+/// assert!((result.unwrap() - 32.0 / 7.0).abs() < 1e-12);
+/// ```
+pub fn sample_variance<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    sample_variance_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_variance_integers() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let result = sample_variance(&numbers);
+        assert!((result.unwrap() - 32.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_variance_floats() {
+        let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let result = sample_variance(&numbers);
+        assert!((result.unwrap() - 32.0 / 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_variance_is_shift_invariant() {
+        let numbers: Vec<f64> = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|x| x + 1e9)
+            .collect();
+        let result = sample_variance(&numbers);
+        assert!((result.unwrap() - 32.0 / 7.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sample_variance_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(sample_variance(&numbers), None);
+    }
+
+    #[test]
+    fn test_sample_variance_single() {
+        let numbers: Vec<f64> = vec![5.0];
+        assert_eq!(sample_variance(&numbers), None);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::moments::Moments;
+
+/// Calculate the sample variance of the values produced by applying an iteratee to each element
+/// of a collection.
+/// The sample variance divides the sum of squared differences from the mean by `n - 1`
+/// (Bessel's correction), giving an unbiased estimate of the variance of the population the
+/// sample was drawn from.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The sample variance, or None if the collection has fewer than two elements
+///
+/// # Examples
+/// ```rust
+/// use lowdash::sample_variance_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// let result = sample_variance_by(&people, |p| p.age as f64);
+/// assert!((result.unwrap() - 32.0 / 7.0).abs() < 1e-12);
+/// ```
+pub fn sample_variance_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    Moments::from_values(collection.iter().map(iteratee)).sample_variance()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variance_by;
+
+    #[test]
+    fn test_sample_variance_by_applies_bessel_correction() {
+        let points = vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+        let sample = sample_variance_by(&points, |&(x, _)| x as f64).unwrap();
+        let population = variance_by(&points, |&(x, _)| x as f64).unwrap();
+        assert!((sample - 5.0 / 3.0).abs() < 1e-12);
+        assert!((sample - population * 4.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sample_variance_by_two_elements() {
+        let points = vec![(3.0, 0.0), (7.0, 0.0)];
+        assert_eq!(sample_variance_by(&points, |&(x, _)| x), Some(8.0));
+    }
+
+    #[test]
+    fn test_sample_variance_by_needs_two_elements() {
+        let single = vec![(5, 'a')];
+        assert_eq!(sample_variance_by(&single, |&(x, _)| x as f64), None);
+        assert_eq!(variance_by(&single, |&(x, _)| x as f64), Some(0.0));
+
+        let empty: Vec<(i32, char)> = vec![];
+        assert_eq!(sample_variance_by(&empty, |&(x, _)| x as f64), None);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::common::Rng;
+
+/// Returns a pseudo-random element from the collection using the provided random number generator.
+/// If the collection is empty, returns the default value of T.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `rng` - The random number generator to draw from
+///
+/// # Returns
+/// * `T` - A pseudo-randomly selected item from the collection or the default value if empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::SeededRng;
+/// use lowdash::sample_with_rng;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let result = sample_with_rng(&numbers, &mut SeededRng::new(42));
+/// assert!(numbers.contains(&result));
+/// assert_eq!(result, sample_with_rng(&numbers, &mut SeededRng::new(42)));
+///
+/// let empty: Vec<i32> = vec![];
+/// let result = sample_with_rng(&empty, &mut SeededRng::new(42));
+/// assert_eq!(result, 0); // i32::default()
+/// ```
+pub fn sample_with_rng<T, R>(collection: &[T], rng: &mut R) -> T
+where
+    T: Clone + Default,
+    R: Rng + ?Sized,
+{
+    let size = collection.len();
+    if size == 0 {
+        return T::default();
+    }
+
+    let index = rng.next_usize(size);
+
+    collection[index].clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::SeededRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sample_with_rng_is_reproducible() {
+        let collection: Vec<i32> = (0..100).collect();
+        let mut a = SeededRng::new(9);
+        let mut b = SeededRng::new(9);
+        for _ in 0..20 {
+            assert_eq!(
+                sample_with_rng(&collection, &mut a),
+                sample_with_rng(&collection, &mut b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sample_with_rng_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        let result = sample_with_rng(&collection, &mut SeededRng::new(9));
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn test_sample_with_rng_single_element() {
+        let collection = vec![42];
+        let result = sample_with_rng(&collection, &mut SeededRng::new(9));
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn test_sample_with_rng_covers_collection() {
+        let collection = vec!["apple", "banana", "cherry"];
+        let mut rng = SeededRng::new(1);
+        let results: HashSet<_> = (0..100)
+            .map(|_| sample_with_rng(&collection, &mut rng))
+            .collect();
+        assert_eq!(results.len(), 3);
+    }
+}
@@ -1,6 +1,5 @@
-use std::time::SystemTime;
-
-use crate::common;
+use crate::common::EntropyRng;
+use crate::samples_with_rng;
 
 /// Returns a slice of pseudo-randomly selected elements from the collection.
 /// The elements are selected without replacement (no duplicates).
@@ -25,35 +24,7 @@ pub fn samples<T>(collection: &[T], count: usize) -> Vec<T>
 where
     T: Clone,
 {
-    let size = collection.len();
-    let sample_size = size.min(count);
-
-    if sample_size == 0 {
-        return Vec::new();
-    }
-
-    // Fisher-Yates shuffle on indices to avoid cloning all elements
-    let mut indices: Vec<usize> = (0..size).collect();
-    let mut results = Vec::with_capacity(sample_size);
-
-    for i in 0..sample_size {
-        let bound = size - i;
-
-        let seed1 = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos() as u64;
-
-        let seed2 = std::time::Instant::now().elapsed().as_nanos() as u64;
-
-        let seed = (seed1 ^ seed2).wrapping_add(i as u64);
-
-        let idx = common::random_usize_with_seed(bound, seed);
-        results.push(collection[indices[idx]].clone());
-        indices.swap(idx, bound - 1);
-    }
-
-    results
+    samples_with_rng(collection, count, &mut EntropyRng)
 }
 
 #[cfg(test)]
@@ -0,0 +1,94 @@
+use crate::common::Rng;
+
+/// Returns a slice of pseudo-randomly selected elements from the collection using the provided
+/// random number generator. The elements are selected without replacement (no duplicates).
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `count` - Number of elements to sample
+/// * `rng` - The random number generator to draw from
+///
+/// # Returns
+/// * `Vec<T>` - A vector containing the sampled elements
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::SeededRng;
+/// use lowdash::samples_with_rng;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let result = samples_with_rng(&numbers, 3, &mut SeededRng::new(42));
+/// assert_eq!(result.len(), 3);
+/// assert!(result.iter().all(|x| numbers.contains(x)));
+/// assert_eq!(result, samples_with_rng(&numbers, 3, &mut SeededRng::new(42)));
+/// ```
+pub fn samples_with_rng<T, R>(collection: &[T], count: usize, rng: &mut R) -> Vec<T>
+where
+    T: Clone,
+    R: Rng + ?Sized,
+{
+    let size = collection.len();
+    let sample_size = size.min(count);
+
+    if sample_size == 0 {
+        return Vec::new();
+    }
+
+    // Fisher-Yates shuffle on indices to avoid cloning all elements
+    let mut indices: Vec<usize> = (0..size).collect();
+    let mut results = Vec::with_capacity(sample_size);
+
+    for i in 0..sample_size {
+        let bound = size - i;
+        let idx = rng.next_usize(bound);
+        results.push(collection[indices[idx]].clone());
+        indices.swap(idx, bound - 1);
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::SeededRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_samples_with_rng_is_reproducible() {
+        let collection: Vec<i32> = (0..100).collect();
+        let first = samples_with_rng(&collection, 10, &mut SeededRng::new(5));
+        let second = samples_with_rng(&collection, 10, &mut SeededRng::new(5));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_samples_with_rng_no_duplicates() {
+        let collection: Vec<i32> = (0..100).collect();
+        let result = samples_with_rng(&collection, 50, &mut SeededRng::new(5));
+        let unique: HashSet<_> = result.iter().collect();
+        assert_eq!(result.len(), unique.len());
+    }
+
+    #[test]
+    fn test_samples_with_rng_count_larger_than_collection() {
+        let collection = vec![1, 2, 3];
+        let mut result = samples_with_rng(&collection, 5, &mut SeededRng::new(5));
+        result.sort();
+        assert_eq!(result, collection);
+    }
+
+    #[test]
+    fn test_samples_with_rng_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        let result = samples_with_rng(&collection, 3, &mut SeededRng::new(5));
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_samples_with_rng_count_zero() {
+        let collection = vec![1, 2, 3];
+        let result = samples_with_rng(&collection, 0, &mut SeededRng::new(5));
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{sentence_case_with, WordOptions};
+
+/// Converts a string to Sentence case.
+///
+/// Splits the input string into words with [`words`](crate::words), then capitalizes the first
+/// word, converts the others to lowercase, and joins them with spaces. Apostrophes inside words are
+/// kept. Use [`sentence_case_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in Sentence case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::sentence_case;
+///
+/// assert_eq!(sentence_case("helloWorld"), "Hello world");
+/// assert_eq!(sentence_case("FOO_BAR"), "Foo bar");
+/// assert_eq!(sentence_case("don't stop me"), "Don't stop me");
+/// ```
+pub fn sentence_case(str_input: &str) -> String {
+    sentence_case_with(str_input, &WordOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(sentence_case("helloWorld"), "Hello world");
+    }
+
+    #[test]
+    fn test_constant_case() {
+        assert_eq!(sentence_case("FOO_BAR"), "Foo bar");
+    }
+
+    #[test]
+    fn test_apostrophes_kept() {
+        assert_eq!(sentence_case("don't stop me"), "Don't stop me");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        assert_eq!(sentence_case("XMLHttpRequest"), "Xml http request");
+    }
+
+    #[test]
+    fn test_single_word() {
+        assert_eq!(sentence_case("hello"), "Hello");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(sentence_case(""), "");
+        assert_eq!(sentence_case("___"), "");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to Sentence case, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then capitalizes the first word,
+/// converts the others to lowercase, and joins them with spaces. Apostrophes inside words are kept.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in Sentence case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{sentence_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(sentence_case_with("v2Release", &WordOptions::default()), "V 2 release");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(sentence_case_with("v2Release", &attached), "V2 release");
+/// ```
+pub fn sentence_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Sentence, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(
+            sentence_case_with("v2Release", &WordOptions::default()),
+            "V 2 release"
+        );
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(sentence_case_with("v2Release", &options), "V2 release");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(sentence_case_with("", &WordOptions::default()), "");
+        assert_eq!(sentence_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
@@ -0,0 +1,97 @@
+use std::borrow::Cow;
+
+/// Quotes a string for use as a single word in a POSIX shell command.
+///
+/// Strings made only of letters, digits and `_ @ % + = : , . / -` are safe as they are and are
+/// borrowed. Anything else is wrapped in single quotes, inside which the shell treats every
+/// character literally, and each single quote is written as `'\''`. The empty string becomes
+/// `''`.
+///
+/// # Arguments
+/// * `str_input` - The string to quote
+///
+/// # Returns
+/// * `Cow<str>` - The quoted string, borrowed from the input when no quoting is needed
+///
+/// # Examples
+/// ```rust
+/// use lowdash::shell_quote;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(shell_quote("my file.txt"), "'my file.txt'");
+/// assert_eq!(shell_quote("it's"), "'it'\\''s'");
+/// assert_eq!(shell_quote("$(rm -rf ~)"), "'$(rm -rf ~)'");
+/// assert_eq!(shell_quote(""), "''");
+/// assert!(matches!(shell_quote("/usr/bin/env"), Cow::Borrowed("/usr/bin/env")));
+///
+/// let command = ["grep", "-r", "hello world", "src/"].map(shell_quote).join(" ");
+/// assert_eq!(command, "grep -r 'hello world' src/");
+/// ```
+pub fn shell_quote(str_input: &str) -> Cow<'_, str> {
+    if !str_input.is_empty() && str_input.chars().all(is_safe) {
+        return Cow::Borrowed(str_input);
+    }
+
+    let mut result = String::with_capacity(str_input.len() + 2);
+    result.push('\'');
+    for c in str_input.chars() {
+        if c == '\'' {
+            result.push_str("'\\''");
+        } else {
+            result.push(c);
+        }
+    }
+    result.push('\'');
+    Cow::Owned(result)
+}
+
+fn is_safe(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(c, '_' | '@' | '%' | '+' | '=' | ':' | ',' | '.' | '/' | '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_words_are_borrowed() {
+        for word in [
+            "ls",
+            "-la",
+            "--color=auto",
+            "a/b/c.txt",
+            "user@host:/tmp",
+            "50%",
+            "a,b+c",
+        ] {
+            assert!(matches!(shell_quote(word), Cow::Borrowed(_)), "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_special_characters_are_quoted() {
+        for word in [
+            "a b", "a\tb", "a\nb", "$HOME", "`id`", "a;b", "a&b", "a|b", "a>b", "*", "?", "[a]",
+            "{a,b}", "!", "#", "~", "\"", "\\",
+        ] {
+            assert_eq!(shell_quote(word), format!("'{}'", word), "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_single_quotes() {
+        assert_eq!(shell_quote("'"), "''\\'''");
+        assert_eq!(shell_quote("don't"), "'don'\\''t'");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    fn test_non_ascii_is_quoted() {
+        assert_eq!(shell_quote("café"), "'café'");
+    }
+}
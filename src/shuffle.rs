@@ -1,4 +1,5 @@
-use crate::common::random_usize;
+use crate::common::EntropyRng;
+use crate::shuffle_with_rng;
 
 /// Shuffle a collection, returning a new vector with the elements in random order.
 ///
@@ -8,7 +9,7 @@ use crate::common::random_usize;
 ///
 /// **Note:** This implementation relies on the random functions provided in `common.rs` and is not
 /// suitable for cryptographic purposes. For more robust randomness, consider using external crates
-/// like `rand`.
+/// like `rand`. Use `shuffle_with_rng` when the output must be reproducible.
 ///
 /// **Time Complexity:**  
 /// O(n), where n is the number of elements in the collection.
@@ -43,19 +44,7 @@ pub fn shuffle<T>(collection: &[T]) -> Vec<T>
 where
     T: Clone,
 {
-    let mut shuffled = collection.to_vec();
-    let len = shuffled.len();
-
-    if len <= 1 {
-        return shuffled;
-    }
-
-    for i in (1..len).rev() {
-        let j = random_usize(i + 1);
-        shuffled.swap(i, j);
-    }
-
-    shuffled
+    shuffle_with_rng(collection, &mut EntropyRng)
 }
 
 #[cfg(test)]
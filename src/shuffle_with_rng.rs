@@ -0,0 +1,114 @@
+use crate::common::Rng;
+
+/// Shuffle a collection using the provided random number generator.
+///
+/// This function behaves like `shuffle`, but draws its randomness from `rng` instead of the
+/// entropy-based generator in `common.rs`. Passing a `SeededRng` created from the same seed
+/// always yields the same permutation, which makes the output reproducible across runs.
+///
+/// **Time Complexity:**
+/// O(n), where n is the number of elements in the collection.
+///
+/// # Arguments
+///
+/// * `collection` - A slice of items to be shuffled.
+/// * `rng` - The random number generator to draw from.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the collection. Must implement `Clone`.
+/// * `R` - The random number generator. Must implement `Rng`.
+///
+/// # Returns
+///
+/// * `Vec<T>` - A new vector containing all elements from the input collection in shuffled order.
+///
+/// # Examples
+///
+/// ```rust
+/// use lowdash::common::SeededRng;
+/// use lowdash::shuffle_with_rng;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let first = shuffle_with_rng(&numbers, &mut SeededRng::new(42));
+/// let second = shuffle_with_rng(&numbers, &mut SeededRng::new(42));
+/// assert_eq!(first, second);
+/// assert_eq!(first.len(), numbers.len());
+/// ```
+pub fn shuffle_with_rng<T, R>(collection: &[T], rng: &mut R) -> Vec<T>
+where
+    T: Clone,
+    R: Rng + ?Sized,
+{
+    let mut shuffled = collection.to_vec();
+    let len = shuffled.len();
+
+    if len <= 1 {
+        return shuffled;
+    }
+
+    // Fisher-Yates
+    for i in (1..len).rev() {
+        let j = rng.next_usize(i + 1);
+        shuffled.swap(i, j);
+    }
+
+    shuffled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::SeededRng;
+
+    #[test]
+    fn test_shuffle_with_rng_is_reproducible() {
+        let numbers: Vec<i32> = (0..50).collect();
+        let first = shuffle_with_rng(&numbers, &mut SeededRng::new(7));
+        let second = shuffle_with_rng(&numbers, &mut SeededRng::new(7));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_shuffle_with_rng_different_seeds() {
+        let numbers: Vec<i32> = (0..50).collect();
+        let first = shuffle_with_rng(&numbers, &mut SeededRng::new(1));
+        let second = shuffle_with_rng(&numbers, &mut SeededRng::new(2));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_shuffle_with_rng_preserves_elements() {
+        let numbers = vec![10, 20, 30, 40, 50];
+        let mut shuffled = shuffle_with_rng(&numbers, &mut SeededRng::new(3));
+        shuffled.sort();
+        assert_eq!(shuffled, numbers);
+    }
+
+    #[test]
+    fn test_shuffle_with_rng_empty_collection() {
+        let empty: Vec<i32> = vec![];
+        let shuffled = shuffle_with_rng(&empty, &mut SeededRng::new(3));
+        assert!(shuffled.is_empty());
+    }
+
+    #[test]
+    fn test_shuffle_with_rng_single_element() {
+        let single = vec![42];
+        let shuffled = shuffle_with_rng(&single, &mut SeededRng::new(3));
+        assert_eq!(shuffled, single);
+    }
+
+    #[test]
+    fn test_shuffle_with_rng_continues_sequence() {
+        let numbers: Vec<i32> = (0..20).collect();
+        let mut rng = SeededRng::new(11);
+        let first = shuffle_with_rng(&numbers, &mut rng);
+        let second = shuffle_with_rng(&numbers, &mut rng);
+        assert_ne!(first, second);
+
+        let mut replay = SeededRng::new(11);
+        assert_eq!(shuffle_with_rng(&numbers, &mut replay), first);
+        assert_eq!(shuffle_with_rng(&numbers, &mut replay), second);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::skewness_by;
+
+/// Calculate the population skewness of a collection.
+/// Skewness measures the asymmetry of the distribution around its mean. It is positive when the
+/// right tail is longer and negative when the left tail is longer.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The population skewness, or None if the collection is empty or all values are equal
+///
+/// # Examples
+/// ```rust
+/// use lowdash::skewness;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// let result = skewness(&numbers);
+/// assert!((result.unwrap() - 0.65625).abs() < 1e-12);
+/// ```
+pub fn skewness<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    skewness_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skewness_integers() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let result = skewness(&numbers);
+        assert!((result.unwrap() - 0.65625).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_skewness_floats() {
+        let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let result = skewness(&numbers);
+        assert!((result.unwrap() - 0.65625).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_skewness_is_shift_invariant() {
+        let numbers: Vec<f64> = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|x| x + 1e9)
+            .collect();
+        let result = skewness(&numbers);
+        assert!((result.unwrap() - 0.65625).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_skewness_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(skewness(&numbers), None);
+    }
+
+    #[test]
+    fn test_skewness_constant() {
+        let numbers: Vec<f64> = vec![3.0, 3.0, 3.0];
+        assert_eq!(skewness(&numbers), None);
+    }
+}
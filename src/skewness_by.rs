@@ -0,0 +1,70 @@
+use crate::moments::Moments;
+
+/// Calculate the population skewness of the values produced by applying an iteratee to each element
+/// of a collection.
+/// Skewness measures the asymmetry of the distribution around its mean. It is positive when the
+/// right tail is longer and negative when the left tail is longer.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The population skewness, or None if the collection is empty or all values are equal
+///
+/// # Examples
+/// ```rust
+/// use lowdash::skewness_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// let result = skewness_by(&people, |p| p.age as f64);
+/// assert!((result.unwrap() - 0.65625).abs() < 1e-12);
+/// ```
+pub fn skewness_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    Moments::from_values(collection.iter().map(iteratee)).skewness()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skewness_by_long_right_tail_is_positive() {
+        let orders = vec![(1, 10.0), (2, 12.0), (3, 11.0), (4, 13.0), (5, 95.0)];
+        assert!(skewness_by(&orders, |&(_, total)| total).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_skewness_by_negated_values_flip_sign() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let right = skewness_by(&numbers, |&x| x as f64).unwrap();
+        let left = skewness_by(&numbers, |&x| -(x as f64)).unwrap();
+        assert!((right - 0.65625).abs() < 1e-12);
+        assert!((left + right).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_skewness_by_symmetric_is_zero() {
+        let numbers = vec![1, 2, 3, 4, 5];
+        let result = skewness_by(&numbers, |&x| x as f64).unwrap();
+        assert!(result.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_skewness_by_constant_key() {
+        let words = vec!["red", "tan", "sky"];
+        assert_eq!(skewness_by(&words, |w| w.len() as f64), None);
+    }
+}
@@ -0,0 +1,34 @@
+/// Options for [`slugify_with`](crate::slugify_with).
+///
+/// The default options join words with `-` and do not limit the length of the slug.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{slugify_with, SlugOptions};
+///
+/// let options = SlugOptions {
+///     separator: "_".to_string(),
+///     max_length: Some(20),
+/// };
+/// assert_eq!(
+///     slugify_with("The Quick Brown Fox Jumps", &options),
+///     "the_quick_brown_fox"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugOptions {
+    /// The text placed between words.
+    pub separator: String,
+    /// The maximum length of the slug in characters. Only whole words are kept, unless the first
+    /// word alone is too long, in which case it is cut.
+    pub max_length: Option<usize>,
+}
+
+impl Default for SlugOptions {
+    fn default() -> Self {
+        SlugOptions {
+            separator: "-".to_string(),
+            max_length: None,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{slugify_with, SlugOptions};
+
+/// Converts a string to a URL slug.
+///
+/// The string is transliterated to ASCII with [`fold_ascii`](crate::fold_ascii), split into
+/// words and joined in lowercase with `-`, like [`kebab_case`](crate::kebab_case). Digits stay
+/// attached to the word they follow and apostrophes are dropped. Use
+/// [`slugify_with`](crate::slugify_with) for another separator or a maximum length.
+///
+/// # Arguments
+/// * `str_input` - The string to convert, such as a title
+///
+/// # Returns
+/// * `String` - The slug
+///
+/// # Examples
+/// ```rust
+/// use lowdash::slugify;
+///
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// assert_eq!(slugify("Crème Brûlée: A Recipe"), "creme-brulee-a-recipe");
+/// assert_eq!(slugify("Don't Panic — It's Only HTTP2"), "dont-panic-its-only-http2");
+/// assert_eq!(slugify("Привет, мир"), "privet-mir");
+/// ```
+pub fn slugify(str_input: &str) -> String {
+    slugify_with(str_input, &SlugOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_titles() {
+        assert_eq!(slugify("Hello World"), "hello-world");
+        assert_eq!(
+            slugify("  10 Tips for Writing Better Rust!  "),
+            "10-tips-for-writing-better-rust"
+        );
+        assert_eq!(slugify("What's new in v2.0?"), "whats-new-in-v2-0");
+    }
+
+    #[test]
+    fn test_transliterates() {
+        assert_eq!(slugify("Ångström & Straße"), "angstrom-strasse");
+        assert_eq!(slugify("Tiếng Việt"), "tieng-viet");
+        assert_eq!(slugify("“Quoted” — text…"), "quoted-text");
+    }
+
+    #[test]
+    fn test_collapses_separators() {
+        assert_eq!(slugify("a -- b __ c...d"), "a-b-c-d");
+        assert_eq!(slugify("already-a-slug"), "already-a-slug");
+    }
+
+    #[test]
+    fn test_splits_camel_case_like_kebab_case() {
+        assert_eq!(slugify("fooBar"), "foo-bar");
+        assert_eq!(slugify("XMLHttpRequest"), "xml-http-request");
+    }
+
+    #[test]
+    fn test_keeps_characters_without_ascii_spelling() {
+        assert_eq!(slugify("東京 Tower"), "東京-tower");
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(slugify(""), "");
+        assert_eq!(slugify("!!!"), "");
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{fold_ascii, kebab_case_with, Apostrophes, DigitGrouping, SlugOptions, WordOptions};
+
+/// Converts a string to a URL slug, with the separator and maximum length of the given
+/// [`SlugOptions`].
+///
+/// The string is transliterated with [`fold_ascii`](crate::fold_ascii) and converted with
+/// [`kebab_case_with`](crate::kebab_case_with), keeping digits attached to the word they follow
+/// and dropping apostrophes. The words are then joined with the separator, stopping before the
+/// first word that would exceed the maximum length.
+///
+/// # Arguments
+/// * `str_input` - The string to convert, such as a title
+/// * `options` - The separator and maximum length
+///
+/// # Returns
+/// * `String` - The slug
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{slugify_with, SlugOptions};
+///
+/// let options = SlugOptions {
+///     max_length: Some(24),
+///     ..SlugOptions::default()
+/// };
+/// assert_eq!(
+///     slugify_with("Ten Things I Learned Building a Rust Crate", &options),
+///     "ten-things-i-learned"
+/// );
+///
+/// let options = SlugOptions {
+///     separator: ".".to_string(),
+///     ..SlugOptions::default()
+/// };
+/// assert_eq!(slugify_with("Release Notes 2.0", &options), "release.notes.2.0");
+/// ```
+pub fn slugify_with(str_input: &str, options: &SlugOptions) -> String {
+    let word_options = WordOptions {
+        digits: DigitGrouping::Attached,
+        apostrophes: Apostrophes::Remove,
+        ..WordOptions::default()
+    };
+    let kebab = kebab_case_with(&fold_ascii(str_input), &word_options);
+
+    let separator_len = options.separator.chars().count();
+    let mut slug = String::with_capacity(kebab.len());
+    let mut slug_len = 0;
+
+    for word in kebab.split('-').filter(|word| !word.is_empty()) {
+        let word_len = word.chars().count();
+        let added = if slug.is_empty() {
+            word_len
+        } else {
+            separator_len + word_len
+        };
+
+        if let Some(max_length) = options.max_length {
+            if slug_len + added > max_length {
+                if slug.is_empty() {
+                    slug.extend(word.chars().take(max_length));
+                }
+                break;
+            }
+        }
+
+        if !slug.is_empty() {
+            slug.push_str(&options.separator);
+        }
+        slug.push_str(word);
+        slug_len += added;
+    }
+
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_max_length(max_length: usize) -> SlugOptions {
+        SlugOptions {
+            max_length: Some(max_length),
+            ..SlugOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_custom_separator() {
+        let options = SlugOptions {
+            separator: "_".to_string(),
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("Hello, World!", &options), "hello_world");
+
+        let options = SlugOptions {
+            separator: "".to_string(),
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("Hello, World!", &options), "helloworld");
+
+        let options = SlugOptions {
+            separator: "--".to_string(),
+            ..SlugOptions::default()
+        };
+        assert_eq!(slugify_with("a b c", &options), "a--b--c");
+    }
+
+    #[test]
+    fn test_max_length_keeps_whole_words() {
+        let title = "The quick brown fox";
+        assert_eq!(
+            slugify_with(title, &with_max_length(19)),
+            "the-quick-brown-fox"
+        );
+        assert_eq!(slugify_with(title, &with_max_length(18)), "the-quick-brown");
+        assert_eq!(slugify_with(title, &with_max_length(15)), "the-quick-brown");
+        assert_eq!(slugify_with(title, &with_max_length(14)), "the-quick");
+        assert_eq!(slugify_with(title, &with_max_length(3)), "the");
+    }
+
+    #[test]
+    fn test_max_length_cuts_long_first_word() {
+        assert_eq!(
+            slugify_with("Supercalifragilistic day", &with_max_length(5)),
+            "super"
+        );
+        assert_eq!(slugify_with("anything", &with_max_length(0)), "");
+    }
+
+    #[test]
+    fn test_max_length_counts_separator_chars() {
+        let options = SlugOptions {
+            separator: "·".to_string(),
+            max_length: Some(3),
+        };
+        assert_eq!(slugify_with("a b c", &options), "a·b");
+    }
+}
@@ -1,7 +1,10 @@
+use crate::{snake_case_with, WordOptions};
+
 /// Converts a string to snake_case.
 ///
-/// Splits the input string into words based on spaces, hyphens, underscores, and capitalization,
-/// then converts each word to lowercase and joins them with underscores.
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then converts each word to lowercase and joins them with underscores.
+/// Use [`snake_case_with`] to configure the splitting.
 ///
 /// # Arguments
 /// * `str_input` - The input string to convert
@@ -20,51 +23,7 @@
 /// assert_eq!(snake_case("fooBarBazHello"), "foo_bar_baz_hello");
 /// ```
 pub fn snake_case(str_input: &str) -> String {
-    if str_input.is_empty() {
-        return String::new();
-    }
-
-    let mut result = String::with_capacity(str_input.len());
-    let mut current_word = String::new();
-    let mut prev_char = ' ';
-
-    for c in str_input.chars() {
-        if c.is_uppercase()
-            && (prev_char.is_lowercase()
-                || prev_char == ' '
-                || prev_char == '-'
-                || prev_char == '_')
-        {
-            if !current_word.is_empty() {
-                if !result.is_empty() {
-                    result.push('_');
-                }
-                result.push_str(&current_word.to_lowercase());
-                current_word.clear();
-            }
-            current_word.push(c);
-        } else if c.is_whitespace() || c == '-' || c == '_' {
-            if !current_word.is_empty() {
-                if !result.is_empty() {
-                    result.push('_');
-                }
-                result.push_str(&current_word.to_lowercase());
-                current_word.clear();
-            }
-        } else {
-            current_word.push(c);
-        }
-        prev_char = c;
-    }
-
-    if !current_word.is_empty() {
-        if !result.is_empty() {
-            result.push('_');
-        }
-        result.push_str(&current_word.to_lowercase());
-    }
-
-    result
+    snake_case_with(str_input, &WordOptions::for_case())
 }
 
 #[cfg(test)]
@@ -128,12 +87,12 @@ mod tests {
 
     #[test]
     fn test_with_numbers() {
-        assert_eq!(snake_case("hello2world"), "hello2world");
+        assert_eq!(snake_case("hello2world"), "hello_2_world");
     }
 
     #[test]
     fn test_with_special_characters() {
-        assert_eq!(snake_case("hello!world"), "hello!world");
+        assert_eq!(snake_case("hello!world"), "hello_world");
     }
 
     #[test]
@@ -159,4 +118,15 @@ mod tests {
         assert_eq!(snake_case("hello_World"), "hello_world");
         assert_eq!(snake_case("hello World"), "hello_world");
     }
+
+    #[test]
+    fn test_acronyms() {
+        assert_eq!(snake_case("HTTPRequest"), "http_request");
+        assert_eq!(snake_case("XMLHttpRequest"), "xml_http_request");
+    }
+
+    #[test]
+    fn test_apostrophes_removed() {
+        assert_eq!(snake_case("don't stop"), "dont_stop");
+    }
 }
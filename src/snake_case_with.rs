@@ -0,0 +1,84 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to snake_case, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then converts each word to
+/// lowercase and joins them with underscores.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in snake_case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{snake_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(snake_case_with("HTTP2Server", &WordOptions::default()), "http_2_server");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(snake_case_with("HTTP2Server", &attached), "http2_server");
+/// ```
+pub fn snake_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Snake, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        let options = WordOptions::default();
+        assert_eq!(snake_case_with("HTTP2Server", &options), "http_2_server");
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(snake_case_with("HTTP2Server", &options), "http2_server");
+    }
+
+    #[test]
+    fn test_acronyms() {
+        let options = WordOptions {
+            acronyms: vec!["OAuth".to_string()],
+            ..WordOptions::default()
+        };
+        assert_eq!(
+            snake_case_with("getOAuthToken", &options),
+            "get_oauth_token"
+        );
+    }
+
+    #[test]
+    fn test_apostrophes_kept() {
+        let options = WordOptions::default();
+        assert_eq!(snake_case_with("don't stop", &options), "don't_stop");
+    }
+
+    #[test]
+    fn test_custom_separators() {
+        let options = WordOptions {
+            separators: Some(vec![' ']),
+            ..WordOptions::default()
+        };
+        assert_eq!(snake_case_with("hello world!", &options), "hello_world!");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(snake_case_with("", &WordOptions::default()), "");
+        assert_eq!(snake_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
@@ -0,0 +1,77 @@
+/// Sort a collection by a key that is computed only once per element.
+///
+/// This is the Schwartzian transform: every key is computed up front, the elements are sorted by
+/// their keys, and the keys are dropped. It pays off when the key is expensive, such as a
+/// lowercased copy of a string or a parsed field, which a plain comparison sort would recompute
+/// O(n log n) times. The sort is stable.
+///
+/// # Arguments
+/// * `collection` - A slice of items to sort.
+/// * `iteratee` - A function that takes an item and returns its sort key.
+///
+/// # Returns
+/// * `Vec<T>` - The elements in ascending order of their keys.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::sort_by_key_cached;
+///
+/// let words = vec!["banana", "Apple", "cherry", "apple"];
+/// let sorted = sort_by_key_cached(&words, |word| word.to_lowercase());
+/// assert_eq!(sorted, vec!["Apple", "apple", "banana", "cherry"]);
+/// ```
+pub fn sort_by_key_cached<T, K, F>(collection: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    let mut result = collection.to_vec();
+    result.sort_by_cached_key(|item| iteratee(item));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_sort_by_key_cached_calls_iteratee_once_per_element() {
+        let calls = Cell::new(0);
+        let numbers: Vec<i32> = (0..100).rev().collect();
+        let sorted = sort_by_key_cached(&numbers, |x| {
+            calls.set(calls.get() + 1);
+            *x
+        });
+        assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+        assert_eq!(calls.get(), 100);
+    }
+
+    #[test]
+    fn test_sort_by_key_cached_is_stable() {
+        let pairs = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        assert_eq!(
+            sort_by_key_cached(&pairs, |pair| pair.0),
+            vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_key_cached_parsed_key() {
+        let versions = vec!["1.10.0", "1.2.3", "1.9.12"];
+        let sorted = sort_by_key_cached(&versions, |version| {
+            version
+                .split('.')
+                .map(|part| part.parse::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(sorted, vec!["1.2.3", "1.9.12", "1.10.0"]);
+    }
+
+    #[test]
+    fn test_sort_by_key_cached_empty() {
+        let empty: Vec<String> = vec![];
+        assert!(sort_by_key_cached(&empty, |s| s.len()).is_empty());
+    }
+}
@@ -0,0 +1,20 @@
+/// The direction in which [`order_by`](crate::order_by) and
+/// [`is_sorted_by_keys`](crate::is_sorted_by_keys) apply a comparator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortDirection {
+    /// Smallest first, following the comparator as it is.
+    #[default]
+    Asc,
+    /// Largest first, reversing the comparator.
+    Desc,
+}
+
+impl SortDirection {
+    /// Applies the direction to an ordering produced by an ascending comparator.
+    pub(crate) fn apply(self, ordering: std::cmp::Ordering) -> std::cmp::Ordering {
+        match self {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{start_case_with, WordOptions};
+
+/// Converts a string to Start Case.
+///
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then converts the first character of each word to uppercase, leaving
+/// the rest of the word unchanged, and joins them with spaces, like lodash's `startCase`. Use
+/// [`start_case_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in Start Case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::start_case;
+///
+/// assert_eq!(start_case("--foo-bar--"), "Foo Bar");
+/// assert_eq!(start_case("fooBar"), "Foo Bar");
+/// assert_eq!(start_case("__FOO_BAR__"), "FOO BAR");
+/// ```
+pub fn start_case(str_input: &str) -> String {
+    start_case_with(str_input, &WordOptions::for_case())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lodash_examples() {
+        assert_eq!(start_case("--foo-bar--"), "Foo Bar");
+        assert_eq!(start_case("fooBar"), "Foo Bar");
+        assert_eq!(start_case("__FOO_BAR__"), "FOO BAR");
+    }
+
+    #[test]
+    fn test_acronyms_kept() {
+        assert_eq!(start_case("XMLHttpRequest"), "XML Http Request");
+    }
+
+    #[test]
+    fn test_mixed_separators() {
+        assert_eq!(start_case("foo-bar_baz hello"), "Foo Bar Baz Hello");
+    }
+
+    #[test]
+    fn test_with_numbers() {
+        assert_eq!(start_case("version2Release"), "Version 2 Release");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(start_case(""), "");
+    }
+}
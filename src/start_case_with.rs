@@ -0,0 +1,60 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to Start Case, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then converts the first
+/// character of each word to uppercase, leaving the rest of the word unchanged, and joins them with
+/// spaces, like lodash's `startCase`.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in Start Case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{start_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(start_case_with("HTTP2Server", &WordOptions::default()), "HTTP 2 Server");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(start_case_with("HTTP2Server", &attached), "HTTP2 Server");
+/// ```
+pub fn start_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Start, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(
+            start_case_with("HTTP2Server", &WordOptions::default()),
+            "HTTP 2 Server"
+        );
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(start_case_with("HTTP2Server", &options), "HTTP2 Server");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(start_case_with("", &WordOptions::default()), "");
+        assert_eq!(start_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
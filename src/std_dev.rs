@@ -0,0 +1,67 @@
+use crate::std_dev_by;
+
+/// Calculate the population standard deviation of a collection.
+/// The population standard deviation is the square root of the population variance.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The population standard deviation, or None if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::std_dev;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// let result = std_dev(&numbers);
+/// assert!((result.unwrap() - 2.0).abs() < 1e-12);
+/// ```
+pub fn std_dev<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    std_dev_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_std_dev_integers() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let result = std_dev(&numbers);
+        assert!((result.unwrap() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_std_dev_floats() {
+        let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let result = std_dev(&numbers);
+        assert!((result.unwrap() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_std_dev_is_shift_invariant() {
+        let numbers: Vec<f64> = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|x| x + 1e9)
+            .collect();
+        let result = std_dev(&numbers);
+        assert!((result.unwrap() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_std_dev_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(std_dev(&numbers), None);
+    }
+
+    #[test]
+    fn test_std_dev_single() {
+        assert_eq!(std_dev(&[5]), Some(0.0));
+    }
+}
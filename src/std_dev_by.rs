@@ -0,0 +1,75 @@
+use crate::moments::Moments;
+
+/// Calculate the population standard deviation of the values produced by applying an iteratee to each element
+/// of a collection.
+/// The population standard deviation is the square root of the population variance.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The population standard deviation, or None if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::std_dev_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// let result = std_dev_by(&people, |p| p.age as f64);
+/// assert!((result.unwrap() - 2.0).abs() < 1e-12);
+/// ```
+pub fn std_dev_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    Moments::from_values(collection.iter().map(iteratee))
+        .variance()
+        .map(f64::sqrt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_std_dev_by_scales_with_values() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let base = std_dev_by(&numbers, |&x| x as f64).unwrap();
+        let scaled = std_dev_by(&numbers, |&x| 3.0 * x as f64).unwrap();
+        assert!((base - 2.0).abs() < 1e-12);
+        assert!((scaled - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_std_dev_by_ignores_offset() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let shifted = std_dev_by(&numbers, |&x| x as f64 - 100.0).unwrap();
+        assert!((shifted - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_std_dev_by_with_struct() {
+        struct Player {
+            score: u32,
+        }
+
+        let players = vec![Player { score: 10 }, Player { score: 20 }];
+        assert_eq!(std_dev_by(&players, |p| p.score as f64), Some(5.0));
+    }
+
+    #[test]
+    fn test_std_dev_by_empty() {
+        let empty: Vec<u32> = vec![];
+        assert_eq!(std_dev_by(&empty, |&x| x as f64), None);
+    }
+}
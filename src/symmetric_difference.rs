@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements that are in exactly one of two collections, like lodash's `xor`.
+/// The result has no duplicates; the elements of `collection` come first, then those of `other`,
+/// each in order of first appearance.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - Another slice of items.
+///
+/// # Returns
+/// * `Vec<T>` - The distinct items found in only one of the slices.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::symmetric_difference;
+///
+/// let result = symmetric_difference(&[2, 1, 1], &[2, 3, 3]);
+/// assert_eq!(result, vec![1, 3]);
+/// ```
+pub fn symmetric_difference<T>(collection: &[T], other: &[T]) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    let left: HashSet<&T> = collection.iter().collect();
+    let right: HashSet<&T> = other.iter().collect();
+    let mut seen = HashSet::new();
+
+    let only_left = collection.iter().filter(|item| !right.contains(item));
+    let only_right = other.iter().filter(|item| !left.contains(item));
+    only_left
+        .chain(only_right)
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_difference() {
+        assert_eq!(
+            symmetric_difference(&[1, 2, 3], &[3, 4, 5]),
+            vec![1, 2, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_symmetric_difference_is_symmetric_as_a_set() {
+        let mut a = symmetric_difference(&[1, 2, 3], &[2, 5]);
+        let mut b = symmetric_difference(&[2, 5], &[1, 2, 3]);
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_symmetric_difference_identical() {
+        assert!(symmetric_difference(&[1, 2, 2], &[2, 1]).is_empty());
+    }
+
+    #[test]
+    fn test_symmetric_difference_empty() {
+        let empty: Vec<i32> = vec![];
+        assert_eq!(symmetric_difference(&empty, &[1, 1, 2]), vec![1, 2]);
+        assert_eq!(symmetric_difference(&[3, 3], &empty), vec![3]);
+    }
+}
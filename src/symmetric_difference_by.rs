@@ -0,0 +1,76 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements whose key, generated by the iteratee function, belongs to exactly one of
+/// two collections, like lodash's `xorBy`.
+/// One element is kept per key; the elements of `collection` come first, then those of `other`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - Another slice of items.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `Vec<T>` - One item per key found in only one of the slices.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::symmetric_difference_by;
+///
+/// let result = symmetric_difference_by(&[2.1, 1.2], &[2.3, 3.4], |x: &f64| x.floor() as i64);
+/// assert_eq!(result, vec![1.2, 3.4]);
+/// ```
+pub fn symmetric_difference_by<T, U, F>(collection: &[T], other: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let left_keys: Vec<U> = collection.iter().map(&iteratee).collect();
+    let right_keys: Vec<U> = other.iter().map(&iteratee).collect();
+    let left: HashSet<&U> = left_keys.iter().collect();
+    let right: HashSet<&U> = right_keys.iter().collect();
+    let mut seen = HashSet::new();
+
+    let only_left = collection
+        .iter()
+        .zip(&left_keys)
+        .filter(|(_, key)| !right.contains(key));
+    let only_right = other
+        .iter()
+        .zip(&right_keys)
+        .filter(|(_, key)| !left.contains(key));
+    only_left
+        .chain(only_right)
+        .filter(|(_, key)| seen.insert(*key))
+        .map(|(item, _)| item.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_difference_by_key() {
+        let result = symmetric_difference_by(&["a", "bb", "ccc"], &["dd", "eeee"], |s| s.len());
+        assert_eq!(result, vec!["a", "ccc", "eeee"]);
+    }
+
+    #[test]
+    fn test_symmetric_difference_by_one_per_key() {
+        let result = symmetric_difference_by(&[1, 3, 5], &[2, 4], |x| x % 3);
+        assert_eq!(result, vec![3]);
+    }
+
+    #[test]
+    fn test_symmetric_difference_by_iteratee_runs_once_per_item() {
+        use std::cell::Cell;
+        let calls = Cell::new(0);
+        symmetric_difference_by(&[1, 2, 3], &[4, 5], |x| {
+            calls.set(calls.get() + 1);
+            *x
+        });
+        assert_eq!(calls.get(), 5);
+    }
+}
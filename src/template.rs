@@ -0,0 +1,574 @@
+use crate::{escape_html, RenderOptions, TemplateError};
+use std::collections::HashMap;
+
+/// A piece of a compiled template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Placeholder {
+        key: String,
+        default: Option<String>,
+        offset: usize,
+    },
+}
+
+/// A compiled string template, reusable across renders.
+///
+/// Placeholders are written `${name}` or `{{name}}`, with optional whitespace around the name. A
+/// default value follows a `|`, as in `{{name|anonymous}}`, and is used when the key has no value.
+/// Names are made of letters, digits, `_`, `-` and `.`.
+///
+/// A backslash right before an opening delimiter escapes it, so `\${` and `\{{` render as `${`
+/// and `{{`; write `\\` before a placeholder to keep a backslash in front of its value. Other
+/// backslashes are ordinary characters.
+///
+/// Values are inserted unchanged; render with [`RenderOptions::escape_html`] to HTML-escape them.
+///
+/// Compiling reports malformed placeholders and rendering reports keys without a value, each as a
+/// [`TemplateError`] giving the position of the placeholder.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::Template;
+/// use std::collections::HashMap;
+///
+/// let greeting = Template::compile("Hello, {{name|stranger}}! You have ${count} messages.").unwrap();
+///
+/// let mut values = HashMap::new();
+/// values.insert("name".to_string(), "Ada".to_string());
+/// values.insert("count".to_string(), "3".to_string());
+/// assert_eq!(greeting.render(&values).unwrap(), "Hello, Ada! You have 3 messages.");
+///
+/// let rendered = greeting.render_with(|key| (key == "count").then_some("no")).unwrap();
+/// assert_eq!(rendered, "Hello, stranger! You have no messages.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    source: String,
+    parts: Vec<Part>,
+}
+
+impl Template {
+    /// Compiles a template, checking every placeholder.
+    ///
+    /// # Arguments
+    /// * `source` - The template text
+    ///
+    /// # Returns
+    /// * `Ok(Template)` - The compiled template
+    /// * `Err(TemplateError::MalformedPlaceholder)` - If a placeholder is not closed, or its name
+    ///   is empty or contains invalid characters
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::Template;
+    ///
+    /// assert!(Template::compile("Hello, {{ name }}").is_ok());
+    /// assert!(Template::compile("Hello, {{first name}}").is_err());
+    /// ```
+    pub fn compile(source: &str) -> Result<Template, TemplateError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut i = 0;
+
+        while i < source.len() {
+            let rest = &source[i..];
+
+            if let Some(after) = rest.strip_prefix('\\') {
+                if starts_with_opener(after) {
+                    literal.push_str(&after[..2]);
+                    i += 3;
+                    continue;
+                }
+                if after.starts_with('\\') && starts_with_opener(&after[1..]) {
+                    literal.push('\\');
+                    i += 2;
+                    continue;
+                }
+            }
+
+            if starts_with_opener(rest) {
+                let close = if rest.starts_with('$') { "}" } else { "}}" };
+                let body_start = i + 2;
+                let body_len = source[body_start..]
+                    .find(close)
+                    .ok_or_else(|| malformed(source, i, "Unclosed placeholder"))?;
+                let body = &source[body_start..body_start + body_len];
+
+                let (key, default) = match body.split_once('|') {
+                    Some((key, default)) => (key.trim(), Some(default.trim().to_string())),
+                    None => (body.trim(), None),
+                };
+                if key.is_empty() {
+                    return Err(malformed(source, i, "Empty placeholder name"));
+                }
+                if !key.chars().all(is_key_char) {
+                    return Err(malformed(
+                        source,
+                        i,
+                        "Invalid character in placeholder name",
+                    ));
+                }
+
+                if !literal.is_empty() {
+                    parts.push(Part::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(Part::Placeholder {
+                    key: key.to_string(),
+                    default,
+                    offset: i,
+                });
+                i = body_start + body_len + close.len();
+                continue;
+            }
+
+            let c = rest.chars().next().unwrap_or_default();
+            literal.push(c);
+            i += c.len_utf8();
+        }
+
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Template {
+            source: source.to_string(),
+            parts,
+        })
+    }
+
+    /// Renders the template with values from a map.
+    ///
+    /// # Arguments
+    /// * `values` - The value of each key
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The rendered text
+    /// * `Err(TemplateError::UnknownKey)` - If a placeholder without a default names a key that is
+    ///   not in `values`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::Template;
+    /// use std::collections::HashMap;
+    ///
+    /// let template = Template::compile("${greeting}, ${name}!").unwrap();
+    /// let mut values = HashMap::new();
+    /// values.insert("greeting".to_string(), "Hi".to_string());
+    /// assert_eq!(
+    ///     template.render(&values).unwrap_err().to_string(),
+    ///     "template: Unknown key `name` at line 1, column 14"
+    /// );
+    ///
+    /// values.insert("name".to_string(), "Bob".to_string());
+    /// assert_eq!(template.render(&values).unwrap(), "Hi, Bob!");
+    /// ```
+    pub fn render(&self, values: &HashMap<String, String>) -> Result<String, TemplateError> {
+        self.render_with(|key| values.get(key))
+    }
+
+    /// Renders the template, asking a closure for the value of each placeholder.
+    ///
+    /// The closure is called once per placeholder, in order, and returns `None` for keys without
+    /// a value.
+    ///
+    /// # Arguments
+    /// * `resolve` - Returns the value of a key, or `None`
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The rendered text
+    /// * `Err(TemplateError::UnknownKey)` - If `resolve` returns `None` for a placeholder without a
+    ///   default
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::Template;
+    ///
+    /// let template = Template::compile("{{a}} + {{b}} = {{c|?}}").unwrap();
+    /// let rendered = template.render_with(|key| match key {
+    ///     "a" => Some("1".to_string()),
+    ///     "b" => Some("2".to_string()),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(rendered.unwrap(), "1 + 2 = ?");
+    /// ```
+    pub fn render_with<F, S>(&self, resolve: F) -> Result<String, TemplateError>
+    where
+        F: FnMut(&str) -> Option<S>,
+        S: AsRef<str>,
+    {
+        self.render_with_options(resolve, &RenderOptions::default())
+    }
+
+    /// Renders the template like [`Template::render_with`], applying the given [`RenderOptions`]
+    /// to each value.
+    ///
+    /// # Arguments
+    /// * `resolve` - Returns the value of a key, or `None`
+    /// * `options` - How values are inserted
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The rendered text
+    /// * `Err(TemplateError::UnknownKey)` - If `resolve` returns `None` for a placeholder without a
+    ///   default
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::{RenderOptions, Template};
+    /// use std::collections::HashMap;
+    ///
+    /// let template = Template::compile(r#"<a title="{{title}}">{{label|<none>}}</a>"#).unwrap();
+    /// let mut values = HashMap::new();
+    /// values.insert("title", "Tom's \"tips\"");
+    ///
+    /// let options = RenderOptions { escape_html: true };
+    /// let rendered = template.render_with_options(|key| values.get(key), &options);
+    /// assert_eq!(
+    ///     rendered.unwrap(),
+    ///     r#"<a title="Tom&#39;s &quot;tips&quot;"><none></a>"#
+    /// );
+    /// ```
+    pub fn render_with_options<F, S>(
+        &self,
+        mut resolve: F,
+        options: &RenderOptions,
+    ) -> Result<String, TemplateError>
+    where
+        F: FnMut(&str) -> Option<S>,
+        S: AsRef<str>,
+    {
+        let mut result = String::with_capacity(self.source.len());
+
+        for part in &self.parts {
+            match part {
+                Part::Literal(text) => result.push_str(text),
+                Part::Placeholder {
+                    key,
+                    default,
+                    offset,
+                } => match (resolve(key), default) {
+                    (Some(value), _) if options.escape_html => {
+                        result.push_str(&escape_html(value.as_ref()))
+                    }
+                    (Some(value), _) => result.push_str(value.as_ref()),
+                    (None, Some(default)) => result.push_str(default),
+                    (None, None) => {
+                        let (line, column) = line_and_column(&self.source, *offset);
+                        return Err(TemplateError::UnknownKey {
+                            key: key.clone(),
+                            offset: *offset,
+                            line,
+                            column,
+                        });
+                    }
+                },
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the keys of the placeholders, in the order they appear.
+    ///
+    /// # Returns
+    /// * `Vec<&str>` - The key of every placeholder, including repeated ones
+    ///
+    /// # Examples
+    /// ```rust
+    /// use lowdash::Template;
+    ///
+    /// let template = Template::compile("${a}{{ b | x }}${a}").unwrap();
+    /// assert_eq!(template.keys(), vec!["a", "b", "a"]);
+    /// ```
+    pub fn keys(&self) -> Vec<&str> {
+        self.parts
+            .iter()
+            .filter_map(|part| match part {
+                Part::Placeholder { key, .. } => Some(key.as_str()),
+                Part::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the text the template was compiled from.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+/// Renders a template once with values from a map.
+///
+/// Compiles `source` as a [`Template`] and renders it. Compile the template once with
+/// [`Template::compile`] instead when rendering it many times.
+///
+/// # Arguments
+/// * `source` - The template text, with `${name}` or `{{name|default}}` placeholders
+/// * `values` - The value of each key
+///
+/// # Returns
+/// * `Ok(String)` - The rendered text
+/// * `Err(TemplateError)` - If a placeholder is malformed or names a key without a value
+///
+/// # Examples
+/// ```rust
+/// use lowdash::template;
+/// use std::collections::HashMap;
+///
+/// let mut values = HashMap::new();
+/// values.insert("user".to_string(), "ada".to_string());
+///
+/// assert_eq!(
+///     template("Welcome back, ${user}! Theme: {{theme|light}}", &values).unwrap(),
+///     "Welcome back, ada! Theme: light"
+/// );
+/// assert_eq!(template(r"Literal \${user}", &values).unwrap(), "Literal ${user}");
+/// assert!(template("Hello, {{user", &values).is_err());
+/// ```
+pub fn template(source: &str, values: &HashMap<String, String>) -> Result<String, TemplateError> {
+    Template::compile(source)?.render(values)
+}
+
+fn starts_with_opener(text: &str) -> bool {
+    text.starts_with("${") || text.starts_with("{{")
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-' || c == '.'
+}
+
+fn malformed(source: &str, offset: usize, reason: &'static str) -> TemplateError {
+    let (line, column) = line_and_column(source, offset);
+    TemplateError::MalformedPlaceholder {
+        reason,
+        offset,
+        line,
+        column,
+    }
+}
+
+/// The 1-based line and column, in characters, of a byte offset.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_both_placeholder_styles() {
+        let values = values(&[("a", "1"), ("b", "2")]);
+        assert_eq!(template("${a} and {{b}}", &values).unwrap(), "1 and 2");
+        assert_eq!(template("${a}{{b}}${a}", &values).unwrap(), "121");
+    }
+
+    #[test]
+    fn test_whitespace_around_names() {
+        let values = values(&[("name", "Ada")]);
+        assert_eq!(
+            template("{{ name }}|${ name }", &values).unwrap(),
+            "Ada|Ada"
+        );
+    }
+
+    #[test]
+    fn test_default_values() {
+        let empty = values(&[]);
+        assert_eq!(template("{{name|anon}}", &empty).unwrap(), "anon");
+        assert_eq!(template("${ name | anon }", &empty).unwrap(), "anon");
+        assert_eq!(template("[{{name|}}]", &empty).unwrap(), "[]");
+        assert_eq!(template("{{url|a|b}}", &empty).unwrap(), "a|b");
+
+        let set = values(&[("name", "Ada")]);
+        assert_eq!(template("{{name|anon}}", &set).unwrap(), "Ada");
+    }
+
+    #[test]
+    fn test_default_ends_at_first_closing_delimiter() {
+        let empty = values(&[]);
+        // A lone `}` does not close a `{{` placeholder, so it can appear in the default.
+        assert_eq!(template("{{x|a}b}}", &empty).unwrap(), "a}b");
+        // The default is `{`; the third `}` is literal text after the placeholder.
+        assert_eq!(template("{{json|{}}}", &empty).unwrap(), "{}");
+        assert_eq!(template("{{json|{a}}}", &empty).unwrap(), "{a}");
+        // `${` placeholders close at the first `}`.
+        assert_eq!(template("${x|{y}}", &empty).unwrap(), "{y}");
+        assert_eq!(template("${x|a}b}", &empty).unwrap(), "ab}");
+    }
+
+    #[test]
+    fn test_escaped_openers() {
+        let values = values(&[("x", "1")]);
+        assert_eq!(template(r"\${x} \{{x}}", &values).unwrap(), "${x} {{x}}");
+        assert_eq!(template(r"\\${x}", &values).unwrap(), r"\1");
+        assert_eq!(template(r"C:\dir\${x}", &values).unwrap(), r"C:\dir${x}");
+        assert_eq!(template(r"a\b", &values).unwrap(), r"a\b");
+    }
+
+    #[test]
+    fn test_literal_text_without_placeholders() {
+        let empty = values(&[]);
+        assert_eq!(template("", &empty).unwrap(), "");
+        assert_eq!(
+            template("no placeholders } }}", &empty).unwrap(),
+            "no placeholders } }}"
+        );
+        assert_eq!(template("$ { {", &empty).unwrap(), "$ { {");
+    }
+
+    #[test]
+    fn test_unicode_text_and_keys() {
+        let values = values(&[("名前", "世界")]);
+        assert_eq!(
+            template("こんにちは、{{名前}}！", &values).unwrap(),
+            "こんにちは、世界！"
+        );
+    }
+
+    #[test]
+    fn test_unknown_key_position() {
+        let template = Template::compile("line one\né ${missing}").unwrap();
+        assert_eq!(
+            template.render(&values(&[])),
+            Err(TemplateError::UnknownKey {
+                key: "missing".to_string(),
+                offset: 12,
+                line: 2,
+                column: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_first_unknown_key_is_reported() {
+        let template = Template::compile("{{a}} {{b}}").unwrap();
+        assert!(matches!(
+            template.render(&values(&[])),
+            Err(TemplateError::UnknownKey { ref key, offset: 0, .. }) if key == "a"
+        ));
+    }
+
+    #[test]
+    fn test_unclosed_placeholder() {
+        assert_eq!(
+            Template::compile("abc ${name"),
+            Err(TemplateError::MalformedPlaceholder {
+                reason: "Unclosed placeholder",
+                offset: 4,
+                line: 1,
+                column: 5,
+            })
+        );
+        assert!(Template::compile("{{name}").is_err());
+    }
+
+    #[test]
+    fn test_empty_placeholder_name() {
+        for source in ["{{}}", "${ }", "{{|default}}"] {
+            assert!(
+                matches!(
+                    Template::compile(source),
+                    Err(TemplateError::MalformedPlaceholder {
+                        reason: "Empty placeholder name",
+                        ..
+                    })
+                ),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_placeholder_name() {
+        for source in ["{{first name}}", "${a{b}", "{{{a}}}", "{{a}b}}"] {
+            assert!(
+                matches!(
+                    Template::compile(source),
+                    Err(TemplateError::MalformedPlaceholder {
+                        reason: "Invalid character in placeholder name",
+                        ..
+                    })
+                ),
+                "{}",
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_key_characters() {
+        let values = values(&[("user.first_name-2", "x")]);
+        assert_eq!(template("{{user.first_name-2}}", &values).unwrap(), "x");
+    }
+
+    #[test]
+    fn test_compiled_template_is_reusable() {
+        let template = Template::compile("<{{tag}}>").unwrap();
+        for tag in ["a", "b", "c"] {
+            assert_eq!(
+                template.render(&values(&[("tag", tag)])).unwrap(),
+                format!("<{}>", tag)
+            );
+        }
+    }
+
+    #[test]
+    fn test_render_with_closure() {
+        let template = Template::compile("${x}-${y}-${x}").unwrap();
+        let mut calls = Vec::new();
+        let rendered = template.render_with(|key| {
+            calls.push(key.to_string());
+            Some(key.to_uppercase())
+        });
+        assert_eq!(rendered.unwrap(), "X-Y-X");
+        assert_eq!(calls, vec!["x", "y", "x"]);
+    }
+
+    #[test]
+    fn test_keys_and_source() {
+        let template = Template::compile("{{a}} ${b|c} \\${d}").unwrap();
+        assert_eq!(template.keys(), vec!["a", "b"]);
+        assert_eq!(template.source(), "{{a}} ${b|c} \\${d}");
+    }
+
+    #[test]
+    fn test_render_with_options_escapes_values() {
+        let template = Template::compile("<td>${name}</td><td>{{note|<empty>}}</td>").unwrap();
+        let values = values(&[("name", "Tom & \"Jerry\"")]);
+
+        let plain = template.render(&values).unwrap();
+        assert_eq!(plain, "<td>Tom & \"Jerry\"</td><td><empty></td>");
+
+        let options = RenderOptions { escape_html: true };
+        let escaped = template
+            .render_with_options(|key| values.get(key), &options)
+            .unwrap();
+        assert_eq!(
+            escaped,
+            "<td>Tom &amp; &quot;Jerry&quot;</td><td><empty></td>"
+        );
+    }
+
+    #[test]
+    fn test_render_with_options_default_matches_render_with() {
+        let template = Template::compile("${a}<${b}>").unwrap();
+        let resolve = |key: &str| Some(format!("<{key}>"));
+        assert_eq!(
+            template.render_with_options(resolve, &RenderOptions::default()),
+            template.render_with(resolve)
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error type returned when compiling or rendering a [`Template`](crate::Template).
+///
+/// Every variant records where the offending placeholder starts in the template source: the byte
+/// `offset` of its opening delimiter, and the 1-based `line` and `column`, with the column
+/// counted in characters.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{Template, TemplateError};
+///
+/// let error = Template::compile("Hello,\n  {{name").unwrap_err();
+/// assert_eq!(
+///     error,
+///     TemplateError::MalformedPlaceholder {
+///         reason: "Unclosed placeholder",
+///         offset: 9,
+///         line: 2,
+///         column: 3,
+///     }
+/// );
+/// assert_eq!(
+///     error.to_string(),
+///     "template: Unclosed placeholder at line 2, column 3"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A placeholder without a default named a key that has no value.
+    UnknownKey {
+        /// The key that could not be resolved.
+        key: String,
+        /// The byte offset of the placeholder in the template source.
+        offset: usize,
+        /// The line of the placeholder, starting at 1.
+        line: usize,
+        /// The column of the placeholder in characters, starting at 1.
+        column: usize,
+    },
+    /// A placeholder is not closed, or its name is empty or contains invalid characters.
+    MalformedPlaceholder {
+        /// What is wrong with the placeholder.
+        reason: &'static str,
+        /// The byte offset of the placeholder in the template source.
+        offset: usize,
+        /// The line of the placeholder, starting at 1.
+        line: usize,
+        /// The column of the placeholder in characters, starting at 1.
+        column: usize,
+    },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UnknownKey {
+                key, line, column, ..
+            } => write!(
+                f,
+                "template: Unknown key `{}` at line {}, column {}",
+                key, line, column
+            ),
+            TemplateError::MalformedPlaceholder {
+                reason,
+                line,
+                column,
+                ..
+            } => write!(
+                f,
+                "template: {} at line {}, column {}",
+                reason, line, column
+            ),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_unknown_key() {
+        let error = TemplateError::UnknownKey {
+            key: "name".to_string(),
+            offset: 6,
+            line: 1,
+            column: 7,
+        };
+        assert_eq!(
+            error.to_string(),
+            "template: Unknown key `name` at line 1, column 7"
+        );
+    }
+
+    #[test]
+    fn test_display_malformed_placeholder() {
+        let error = TemplateError::MalformedPlaceholder {
+            reason: "Empty placeholder name",
+            offset: 0,
+            line: 1,
+            column: 1,
+        };
+        assert_eq!(
+            error.to_string(),
+            "template: Empty placeholder name at line 1, column 1"
+        );
+    }
+
+    #[test]
+    fn test_is_std_error() {
+        let error: Box<dyn Error> = Box::new(TemplateError::MalformedPlaceholder {
+            reason: "Unclosed placeholder",
+            offset: 3,
+            line: 2,
+            column: 1,
+        });
+        assert_eq!(
+            error.to_string(),
+            "template: Unclosed placeholder at line 2, column 1"
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{Template, TemplateError};
+
+/// Renders a template once, asking a closure for the value of each placeholder.
+///
+/// Compiles `source` as a [`Template`] and renders it with
+/// [`Template::render_with`]. The closure returns `None` for keys without a value.
+///
+/// # Arguments
+/// * `source` - The template text, with `${name}` or `{{name|default}}` placeholders
+/// * `resolve` - Returns the value of a key, or `None`
+///
+/// # Returns
+/// * `Ok(String)` - The rendered text
+/// * `Err(TemplateError)` - If a placeholder is malformed, or `resolve` returns `None` for a
+///   placeholder without a default
+///
+/// # Examples
+/// ```rust
+/// use lowdash::template_with;
+///
+/// let rendered = template_with("HOME=${HOME|/root} USER={{USER|nobody}}", |key| {
+///     (key == "USER").then(|| "ada".to_string())
+/// });
+/// assert_eq!(rendered.unwrap(), "HOME=/root USER=ada");
+///
+/// let error = template_with("${missing}", |_| None::<String>).unwrap_err();
+/// assert_eq!(error.to_string(), "template: Unknown key `missing` at line 1, column 1");
+/// ```
+pub fn template_with<F, S>(source: &str, resolve: F) -> Result<String, TemplateError>
+where
+    F: FnMut(&str) -> Option<S>,
+    S: AsRef<str>,
+{
+    Template::compile(source)?.render_with(resolve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_with_closure() {
+        let rendered = template_with("{{a}}{{b}}", |key| Some(key.repeat(2)));
+        assert_eq!(rendered.unwrap(), "aabb");
+    }
+
+    #[test]
+    fn test_borrowed_values() {
+        let names = ["zero", "one", "two"];
+        let rendered = template_with("${1} ${2}", |key| {
+            key.parse::<usize>()
+                .ok()
+                .and_then(|i| names.get(i).copied())
+        });
+        assert_eq!(rendered.unwrap(), "one two");
+    }
+
+    #[test]
+    fn test_malformed_placeholder_is_reported_before_resolving() {
+        let mut called = false;
+        let result = template_with("${a} ${", |_| {
+            called = true;
+            Some("x")
+        });
+        assert!(matches!(
+            result,
+            Err(TemplateError::MalformedPlaceholder { offset: 5, .. })
+        ));
+        assert!(!called);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::common::TITLE_CASE_SMALL_WORDS;
+use crate::{title_case_with, WordOptions};
+
+/// Converts a string to Title Case.
+///
+/// Splits the input string into words with [`words`](crate::words), capitalizes each word and
+/// joins them with spaces. Articles, conjunctions and short prepositions listed in
+/// [`TITLE_CASE_SMALL_WORDS`] stay lowercase unless they are the first or last word. Use
+/// [`title_case_with`] to choose the small words or configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in Title Case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::title_case;
+///
+/// assert_eq!(title_case("the lord of the rings"), "The Lord of the Rings");
+/// assert_eq!(title_case("war_and_peace"), "War and Peace");
+/// assert_eq!(title_case("whatItIsMadeOf"), "What It Is Made Of");
+/// ```
+pub fn title_case(str_input: &str) -> String {
+    title_case_with(str_input, &WordOptions::default(), TITLE_CASE_SMALL_WORDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_words_lowercase() {
+        assert_eq!(title_case("the lord of the rings"), "The Lord of the Rings");
+        assert_eq!(title_case("a tale of two cities"), "A Tale of Two Cities");
+        assert_eq!(
+            title_case("THE WIND IN THE WILLOWS"),
+            "The Wind in the Willows"
+        );
+    }
+
+    #[test]
+    fn test_first_and_last_words_capitalized() {
+        assert_eq!(title_case("of mice and men"), "Of Mice and Men");
+        assert_eq!(title_case("what it is made of"), "What It Is Made Of");
+        assert_eq!(title_case("of"), "Of");
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(title_case("war_and_peace"), "War and Peace");
+        assert_eq!(title_case("lordOfTheRings"), "Lord of the Rings");
+        assert_eq!(title_case("XMLHttpRequest"), "Xml Http Request");
+    }
+
+    #[test]
+    fn test_apostrophes_kept() {
+        assert_eq!(title_case("don't stop believing"), "Don't Stop Believing");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(title_case(""), "");
+        assert_eq!(title_case("  "), "");
+    }
+}
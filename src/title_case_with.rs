@@ -0,0 +1,84 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to Title Case with the given small words and [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), capitalizes each word and joins
+/// them with spaces. Words found in `small_words`, compared case-insensitively, stay lowercase
+/// unless they are the first or last word.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+/// * `small_words` - The words to keep in lowercase inside the title
+///
+/// # Returns
+/// * `String` - The converted string in Title Case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{title_case_with, WordOptions};
+///
+/// let options = WordOptions::default();
+/// assert_eq!(
+///     title_case_with("the lord of the rings", &options, &["of"]),
+///     "The Lord of The Rings"
+/// );
+/// assert_eq!(
+///     title_case_with("the lord of the rings", &options, &[]),
+///     "The Lord Of The Rings"
+/// );
+/// ```
+pub fn title_case_with(str_input: &str, options: &WordOptions, small_words: &[&str]) -> String {
+    case_string(str_input, Case::Title, options, small_words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::TITLE_CASE_SMALL_WORDS;
+
+    #[test]
+    fn test_default_small_words() {
+        let options = WordOptions::default();
+        assert_eq!(
+            title_case_with("the lord of the rings", &options, TITLE_CASE_SMALL_WORDS),
+            "The Lord of the Rings"
+        );
+    }
+
+    #[test]
+    fn test_custom_small_words() {
+        let options = WordOptions::default();
+        assert_eq!(
+            title_case_with("le petit prince et la rose", &options, &["le", "la", "et"]),
+            "Le Petit Prince et la Rose"
+        );
+    }
+
+    #[test]
+    fn test_small_words_case_insensitive() {
+        let options = WordOptions::default();
+        assert_eq!(
+            title_case_with("tom AND jerry", &options, &["And"]),
+            "Tom and Jerry"
+        );
+    }
+
+    #[test]
+    fn test_no_small_words() {
+        let options = WordOptions::default();
+        assert_eq!(
+            title_case_with("the lord of the rings", &options, &[]),
+            "The Lord Of The Rings"
+        );
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(
+            title_case_with("", &WordOptions::default(), TITLE_CASE_SMALL_WORDS),
+            ""
+        );
+    }
+}
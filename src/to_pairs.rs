@@ -1,6 +1,6 @@
 use crate::entries;
 use crate::entries::Entry;
-use std::collections::HashMap;
+use crate::MapLike;
 
 /// Collects all entries from a map into a vector of `Entry` structs.
 ///
@@ -36,9 +36,10 @@ use std::collections::HashMap;
 ///
 /// assert_eq!(sorted_result, sorted_expected);
 /// ```
-pub fn to_pairs<K, V>(map: &HashMap<K, V>) -> Vec<Entry<K, V>>
+pub fn to_pairs<M, K, V>(map: &M) -> Vec<Entry<K, V>>
 where
-    K: Clone + std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K, Value = V>,
+    K: Clone,
     V: Clone,
 {
     entries::entries(map)
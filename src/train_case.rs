@@ -0,0 +1,61 @@
+use crate::{train_case_with, WordOptions};
+
+/// Converts a string to Train-Case.
+///
+/// Splits the input string into words the same way as [`words`](crate::words), except that
+/// apostrophes are dropped, then capitalizes each word and joins them with hyphens. Use
+/// [`train_case_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+///
+/// # Returns
+/// * `String` - The converted string in Train-Case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::train_case;
+///
+/// assert_eq!(train_case("hello world"), "Hello-World");
+/// assert_eq!(train_case("content_type"), "Content-Type");
+/// assert_eq!(train_case("XMLHttpRequest"), "Xml-Http-Request");
+/// ```
+pub fn train_case(str_input: &str) -> String {
+    train_case_with(str_input, &WordOptions::for_case())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_space_separated() {
+        assert_eq!(train_case("hello world"), "Hello-World");
+    }
+
+    #[test]
+    fn test_mixed_separators() {
+        assert_eq!(train_case("foo-bar_baz hello"), "Foo-Bar-Baz-Hello");
+    }
+
+    #[test]
+    fn test_camel_and_pascal_case() {
+        assert_eq!(train_case("fooBarBaz"), "Foo-Bar-Baz");
+        assert_eq!(train_case("FooBarBaz"), "Foo-Bar-Baz");
+    }
+
+    #[test]
+    fn test_uppercase_words() {
+        assert_eq!(train_case("CONTENT_TYPE"), "Content-Type");
+    }
+
+    #[test]
+    fn test_already_train_case() {
+        assert_eq!(train_case("Hello-World"), "Hello-World");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(train_case(""), "");
+    }
+}
@@ -0,0 +1,59 @@
+use crate::case_writer::case_string;
+use crate::{Case, WordOptions};
+
+/// Converts a string to Train-Case, splitting it into words with the given [`WordOptions`].
+///
+/// Splits the input string with [`words_with`](crate::words_with), then capitalizes each word and
+/// joins them with hyphens.
+///
+/// # Arguments
+/// * `str_input` - The input string to convert
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `String` - The converted string in Train-Case
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{train_case_with, DigitGrouping, WordOptions};
+///
+/// assert_eq!(train_case_with("HTTP2Server", &WordOptions::default()), "Http-2-Server");
+///
+/// let attached = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(train_case_with("HTTP2Server", &attached), "Http2-Server");
+/// ```
+pub fn train_case_with(str_input: &str, options: &WordOptions) -> String {
+    case_string(str_input, Case::Train, options, &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    #[test]
+    fn test_default_options() {
+        assert_eq!(
+            train_case_with("HTTP2Server", &WordOptions::default()),
+            "Http-2-Server"
+        );
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        assert_eq!(train_case_with("HTTP2Server", &options), "Http2-Server");
+    }
+
+    #[test]
+    fn test_empty_string() {
+        assert_eq!(train_case_with("", &WordOptions::default()), "");
+        assert_eq!(train_case_with("-_ ", &WordOptions::default()), "");
+    }
+}
@@ -0,0 +1,55 @@
+/// Removes the given characters from both ends of a string.
+///
+/// Characters are removed from each end for as long as they belong to `chars`; characters in the
+/// middle of the string are kept. An empty set leaves the string unchanged.
+///
+/// # Arguments
+/// * `str_input` - The string to trim
+/// * `chars` - The characters to remove
+///
+/// # Returns
+/// * `String` - The trimmed string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::trim_chars;
+///
+/// assert_eq!(trim_chars("-_-abc-_-", &['_', '-']), "abc");
+/// assert_eq!(trim_chars("  abc  ", &[' ']), "abc");
+/// assert_eq!(trim_chars("xxhello worldxx", &['x']), "hello world");
+/// ```
+pub fn trim_chars(str_input: &str, chars: &[char]) -> String {
+    str_input.trim_matches(chars).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_both_ends() {
+        assert_eq!(trim_chars("-_-abc-_-", &['_', '-']), "abc");
+    }
+
+    #[test]
+    fn test_keeps_inner_characters() {
+        assert_eq!(trim_chars("--a-b--", &['-']), "a-b");
+    }
+
+    #[test]
+    fn test_empty_set() {
+        assert_eq!(trim_chars("  abc  ", &[]), "  abc  ");
+    }
+
+    #[test]
+    fn test_all_characters_trimmed() {
+        assert_eq!(trim_chars("----", &['-']), "");
+        assert_eq!(trim_chars("", &['-']), "");
+    }
+
+    #[test]
+    fn test_unicode_characters() {
+        assert_eq!(trim_chars("¡¡hola!!", &['¡', '!']), "hola");
+        assert_eq!(trim_chars("「世界」", &['「', '」']), "世界");
+    }
+}
@@ -0,0 +1,47 @@
+/// Removes the given characters from the end of a string.
+///
+/// Characters are removed for as long as they belong to `chars`. An empty set leaves the string
+/// unchanged.
+///
+/// # Arguments
+/// * `str_input` - The string to trim
+/// * `chars` - The characters to remove
+///
+/// # Returns
+/// * `String` - The trimmed string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::trim_end_chars;
+///
+/// assert_eq!(trim_end_chars("-_-abc-_-", &['_', '-']), "-_-abc");
+/// assert_eq!(trim_end_chars("path/to/dir///", &['/']), "path/to/dir");
+/// ```
+pub fn trim_end_chars(str_input: &str, chars: &[char]) -> String {
+    str_input.trim_end_matches(chars).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_end() {
+        assert_eq!(trim_end_chars("-_-abc-_-", &['_', '-']), "-_-abc");
+    }
+
+    #[test]
+    fn test_trailing_punctuation() {
+        assert_eq!(trim_end_chars("Hello!!!?", &['!', '?']), "Hello");
+    }
+
+    #[test]
+    fn test_empty_set() {
+        assert_eq!(trim_end_chars("abc  ", &[]), "abc  ");
+    }
+
+    #[test]
+    fn test_unicode_characters() {
+        assert_eq!(trim_end_chars("··世界··", &['·']), "··世界");
+    }
+}
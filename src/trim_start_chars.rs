@@ -0,0 +1,48 @@
+/// Removes the given characters from the start of a string.
+///
+/// Characters are removed for as long as they belong to `chars`. An empty set leaves the string
+/// unchanged.
+///
+/// # Arguments
+/// * `str_input` - The string to trim
+/// * `chars` - The characters to remove
+///
+/// # Returns
+/// * `String` - The trimmed string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::trim_start_chars;
+///
+/// assert_eq!(trim_start_chars("-_-abc-_-", &['_', '-']), "abc-_-");
+/// assert_eq!(trim_start_chars("0042", &['0']), "42");
+/// ```
+pub fn trim_start_chars(str_input: &str, chars: &[char]) -> String {
+    str_input.trim_start_matches(chars).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_start() {
+        assert_eq!(trim_start_chars("-_-abc-_-", &['_', '-']), "abc-_-");
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        assert_eq!(trim_start_chars("000", &['0']), "");
+        assert_eq!(trim_start_chars("0100", &['0']), "100");
+    }
+
+    #[test]
+    fn test_empty_set() {
+        assert_eq!(trim_start_chars("  abc", &[]), "  abc");
+    }
+
+    #[test]
+    fn test_unicode_characters() {
+        assert_eq!(trim_start_chars("··世界··", &['·']), "世界··");
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{truncate_with, TruncateOptions};
+
+/// Shortens a string to at most `max_width` terminal columns, replacing the end with `"..."`.
+///
+/// Unlike [`ellipsis`](crate::ellipsis), which counts `char`s, the width is measured with
+/// [`display_width`](crate::display_width): wide East Asian characters and emoji count as two
+/// columns, combining marks as none, and the string is only cut between grapheme clusters. The
+/// `"..."` counts toward the limit. Use [`truncate_with`] to cut the start or the middle, change
+/// the omission or cut at word boundaries.
+///
+/// # Arguments
+/// * `str_input` - The string to shorten
+/// * `max_width` - The maximum display width of the result
+///
+/// # Returns
+/// * `String` - The string, shortened if it was wider than `max_width`
+///
+/// # Examples
+/// ```rust
+/// use lowdash::truncate;
+///
+/// assert_eq!(truncate("Hello, World!", 10), "Hello, ...");
+/// assert_eq!(truncate("Short", 10), "Short");
+/// assert_eq!(truncate("こんにちは世界", 8), "こん...");
+/// assert_eq!(truncate("cafe\u{301} au lait", 7), "cafe\u{301}...");
+/// ```
+pub fn truncate(str_input: &str, max_width: usize) -> String {
+    truncate_with(str_input, max_width, &TruncateOptions::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display_width;
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(truncate("Hello, World!", 10), "Hello, ...");
+        assert_eq!(truncate("ExactLength", 11), "ExactLength");
+        assert_eq!(truncate("", 5), "");
+    }
+
+    #[test]
+    fn test_wide_characters() {
+        assert_eq!(truncate("こんにちは世界", 8), "こん...");
+        assert_eq!(truncate("こんにちは世界", 7), "こん...");
+        assert_eq!(truncate("hello世界", 8), "hello...");
+    }
+
+    #[test]
+    fn test_emoji() {
+        assert_eq!(truncate("😀😃😄😁😆", 7), "😀😃...");
+        assert_eq!(truncate("👍🏽👍🏽👍🏽", 5), "👍🏽...");
+    }
+
+    #[test]
+    fn test_combining_marks_stay_with_base() {
+        let result = truncate("ne\u{301}e\u{301}e\u{301}e\u{301}x", 5);
+        assert_eq!(result, "ne\u{301}...");
+        assert_eq!(display_width(&result), 5);
+    }
+
+    #[test]
+    fn test_small_widths() {
+        assert_eq!(truncate("Hello", 3), "...");
+        assert_eq!(truncate("Hello", 1), ".");
+    }
+}
@@ -0,0 +1,48 @@
+/// Where [`truncate_with`](crate::truncate_with) removes text from a string that is too wide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TruncatePosition {
+    /// Keep the beginning and replace the end: `"Hello, wo..."`.
+    #[default]
+    End,
+    /// Keep both ends and replace the middle: `"Hello...world"`.
+    Middle,
+    /// Keep the end and replace the beginning: `"...o, world"`.
+    Start,
+}
+
+/// Options for [`truncate_with`](crate::truncate_with).
+///
+/// The default options cut the end of the string and mark the cut with `"..."`, anywhere
+/// between two grapheme clusters.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{truncate_with, TruncateOptions, TruncatePosition};
+///
+/// let options = TruncateOptions {
+///     position: TruncatePosition::Middle,
+///     omission: "…".to_string(),
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(truncate_with("src/components/Button.tsx", 15, &options), "src/com…ton.tsx");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TruncateOptions {
+    /// Which part of the string is removed.
+    pub position: TruncatePosition,
+    /// The text that replaces the removed part. Its width counts toward the limit.
+    pub omission: String,
+    /// Cut only at whitespace when the kept text contains any, so no word is split. Text without
+    /// whitespace, such as CJK, is still cut between grapheme clusters.
+    pub word_boundary: bool,
+}
+
+impl Default for TruncateOptions {
+    fn default() -> Self {
+        TruncateOptions {
+            position: TruncatePosition::End,
+            omission: "...".to_string(),
+            word_boundary: false,
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use crate::unicode::{grapheme_width, graphemes};
+use crate::{display_width, TruncateOptions, TruncatePosition};
+
+/// Shortens a string to at most `max_width` terminal columns with the given [`TruncateOptions`].
+///
+/// The string is measured with [`display_width`] and only ever cut between grapheme clusters, so
+/// wide characters, combining marks and emoji sequences are never split. If the string already
+/// fits it is returned unchanged; otherwise the omission replaces the removed text and the result,
+/// omission included, is no wider than `max_width`. When the omission itself is wider than
+/// `max_width`, as much of it as fits is returned.
+///
+/// # Arguments
+/// * `str_input` - The string to shorten
+/// * `max_width` - The maximum display width of the result
+/// * `options` - Where to cut, what to replace the cut with and whether to cut at whitespace
+///
+/// # Returns
+/// * `String` - The string, shortened if it was wider than `max_width`
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{truncate_with, TruncateOptions, TruncatePosition};
+///
+/// let options = TruncateOptions {
+///     position: TruncatePosition::Start,
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(truncate_with("Hello, World!", 10, &options), "... World!");
+///
+/// let options = TruncateOptions {
+///     word_boundary: true,
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(truncate_with("The quick brown fox", 15, &options), "The quick...");
+///
+/// let options = TruncateOptions {
+///     position: TruncatePosition::Middle,
+///     omission: "…".to_string(),
+///     ..TruncateOptions::default()
+/// };
+/// assert_eq!(truncate_with("こんにちは世界", 9, &options), "こん…世界");
+/// ```
+pub fn truncate_with(str_input: &str, max_width: usize, options: &TruncateOptions) -> String {
+    if display_width(str_input) <= max_width {
+        return str_input.to_string();
+    }
+
+    let omission_width = display_width(&options.omission);
+    if omission_width > max_width {
+        let omission: Vec<&str> = graphemes(&options.omission).collect();
+        let end = fit_prefix(&omission, max_width, false);
+        return omission[..end].concat();
+    }
+
+    let budget = max_width - omission_width;
+    let clusters: Vec<&str> = graphemes(str_input).collect();
+    let word_boundary = options.word_boundary;
+
+    match options.position {
+        TruncatePosition::End => {
+            let end = fit_prefix(&clusters, budget, word_boundary);
+            clusters[..end].concat() + &options.omission
+        }
+        TruncatePosition::Start => {
+            let start = fit_suffix(&clusters, budget, word_boundary);
+            options.omission.clone() + &clusters[start..].concat()
+        }
+        TruncatePosition::Middle => {
+            let end = fit_prefix(&clusters, budget - budget / 2, word_boundary);
+            let used: usize = clusters[..end].iter().map(|c| grapheme_width(c)).sum();
+            let start = end + fit_suffix(&clusters[end..], budget - used, word_boundary);
+            clusters[..end].concat() + &options.omission + &clusters[start..].concat()
+        }
+    }
+}
+
+fn is_space(cluster: &str) -> bool {
+    cluster.chars().next().is_some_and(char::is_whitespace)
+}
+
+/// The number of leading clusters that fit in `budget` columns.
+fn fit_prefix(clusters: &[&str], budget: usize, word_boundary: bool) -> usize {
+    let mut width = 0;
+    let mut end = 0;
+    for cluster in clusters {
+        let cluster_width = grapheme_width(cluster);
+        if width + cluster_width > budget {
+            break;
+        }
+        width += cluster_width;
+        end += 1;
+    }
+
+    if word_boundary {
+        let splits_word = end < clusters.len()
+            && !is_space(clusters[end])
+            && end > 0
+            && !is_space(clusters[end - 1]);
+        if splits_word {
+            if let Some(space) = clusters[..end].iter().rposition(|c| is_space(c)) {
+                end = space;
+            }
+        }
+        while end > 0 && is_space(clusters[end - 1]) {
+            end -= 1;
+        }
+    }
+    end
+}
+
+/// The index of the first of the trailing clusters that fit in `budget` columns.
+fn fit_suffix(clusters: &[&str], budget: usize, word_boundary: bool) -> usize {
+    let mut width = 0;
+    let mut start = clusters.len();
+    while start > 0 {
+        let cluster_width = grapheme_width(clusters[start - 1]);
+        if width + cluster_width > budget {
+            break;
+        }
+        width += cluster_width;
+        start -= 1;
+    }
+
+    if word_boundary {
+        let splits_word = start > 0
+            && !is_space(clusters[start - 1])
+            && start < clusters.len()
+            && !is_space(clusters[start]);
+        if splits_word {
+            if let Some(space) = clusters[start..].iter().position(|c| is_space(c)) {
+                start += space + 1;
+            }
+        }
+        while start < clusters.len() && is_space(clusters[start]) {
+            start += 1;
+        }
+    }
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_position(position: TruncatePosition) -> TruncateOptions {
+        TruncateOptions {
+            position,
+            ..TruncateOptions::default()
+        }
+    }
+
+    fn with_words(position: TruncatePosition) -> TruncateOptions {
+        TruncateOptions {
+            position,
+            word_boundary: true,
+            ..TruncateOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_fits_unchanged() {
+        let options = TruncateOptions::default();
+        assert_eq!(truncate_with("Short", 10, &options), "Short");
+        assert_eq!(truncate_with("世界", 4, &options), "世界");
+        assert_eq!(truncate_with("", 0, &options), "");
+    }
+
+    #[test]
+    fn test_positions() {
+        let text = "Hello, World!";
+        assert_eq!(
+            truncate_with(text, 10, &with_position(TruncatePosition::End)),
+            "Hello, ..."
+        );
+        assert_eq!(
+            truncate_with(text, 10, &with_position(TruncatePosition::Start)),
+            "... World!"
+        );
+        assert_eq!(
+            truncate_with(text, 10, &with_position(TruncatePosition::Middle)),
+            "Hell...ld!"
+        );
+    }
+
+    #[test]
+    fn test_wide_characters() {
+        let text = "こんにちは世界";
+        assert_eq!(
+            truncate_with(text, 8, &with_position(TruncatePosition::End)),
+            "こん..."
+        );
+        assert_eq!(
+            truncate_with(text, 8, &with_position(TruncatePosition::Start)),
+            "...世界"
+        );
+        assert_eq!(
+            truncate_with(text, 9, &with_position(TruncatePosition::Middle)),
+            "こ...世界"
+        );
+    }
+
+    #[test]
+    fn test_never_splits_grapheme_clusters() {
+        let options = TruncateOptions::default();
+        let family = "👨\u{200D}👩\u{200D}👧";
+        let text = family.repeat(3);
+        assert_eq!(truncate_with(&text, 5, &options), format!("{}...", family));
+        assert_eq!(
+            truncate_with("cafe\u{301} au lait", 7, &options),
+            "cafe\u{301}..."
+        );
+        assert_eq!(truncate_with("🇯🇵🇺🇸🇫🇷", 5, &options), "🇯🇵...");
+    }
+
+    #[test]
+    fn test_custom_omission() {
+        let options = TruncateOptions {
+            omission: " [more]".to_string(),
+            ..TruncateOptions::default()
+        };
+        assert_eq!(
+            truncate_with("Lorem ipsum dolor sit amet", 18, &options),
+            "Lorem ipsum [more]"
+        );
+
+        let options = TruncateOptions {
+            omission: String::new(),
+            ..TruncateOptions::default()
+        };
+        assert_eq!(truncate_with("Lorem ipsum", 5, &options), "Lorem");
+    }
+
+    #[test]
+    fn test_omission_wider_than_limit() {
+        let options = TruncateOptions::default();
+        assert_eq!(truncate_with("Hello", 2, &options), "..");
+        assert_eq!(truncate_with("Hello", 0, &options), "");
+        assert_eq!(truncate_with("Hello", 3, &options), "...");
+    }
+
+    #[test]
+    fn test_word_boundary() {
+        let text = "The quick brown fox";
+        assert_eq!(
+            truncate_with(text, 15, &with_words(TruncatePosition::End)),
+            "The quick..."
+        );
+        assert_eq!(
+            truncate_with(text, 14, &with_words(TruncatePosition::Start)),
+            "...brown fox"
+        );
+        assert_eq!(
+            truncate_with(text, 16, &with_words(TruncatePosition::Middle)),
+            "The...brown fox"
+        );
+    }
+
+    #[test]
+    fn test_word_boundary_cut_at_space() {
+        let options = with_words(TruncatePosition::End);
+        assert_eq!(
+            truncate_with("The quick brown fox", 12, &options),
+            "The quick..."
+        );
+        assert_eq!(
+            truncate_with("The quick brown fox", 13, &options),
+            "The quick..."
+        );
+    }
+
+    #[test]
+    fn test_word_boundary_without_whitespace() {
+        let options = with_words(TruncatePosition::End);
+        assert_eq!(
+            truncate_with("Supercalifragilistic", 10, &options),
+            "Superca..."
+        );
+        assert_eq!(truncate_with("こんにちは世界", 8, &options), "こん...");
+    }
+
+    #[test]
+    fn test_result_never_exceeds_width() {
+        let inputs = [
+            "Hello, World!",
+            "こんにちは世界",
+            "e\u{301}e\u{301}e\u{301}e\u{301}e\u{301}",
+            "👍🏽 thumbs 👍🏽 up 👍🏽",
+            "mixed 世界 text with 😀 emoji",
+        ];
+        for position in [
+            TruncatePosition::End,
+            TruncatePosition::Middle,
+            TruncatePosition::Start,
+        ] {
+            for word_boundary in [false, true] {
+                let options = TruncateOptions {
+                    position,
+                    word_boundary,
+                    omission: "…".to_string(),
+                };
+                for input in inputs {
+                    for max_width in 0..=display_width(input) + 1 {
+                        let result = truncate_with(input, max_width, &options);
+                        assert!(
+                            display_width(&result) <= max_width,
+                            "{:?} {} {:?} -> {:?}",
+                            input,
+                            max_width,
+                            options,
+                            result
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{chunk, LowdashError};
+
+/// Divide a collection into smaller chunks of a specified size, returning an error instead of
+/// panicking when `size` is 0.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be divided into chunks.
+/// * `size` - The maximum number of elements each chunk should contain.
+///
+/// # Returns
+/// * `Ok(Vec<Vec<T>>)` - The chunks, as returned by `chunk`.
+/// * `Err(LowdashError::InvalidArgument)` - If `size` is 0.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_chunk;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// assert_eq!(try_chunk(&numbers, 2), Ok(vec![vec![1, 2], vec![3, 4], vec![5]]));
+/// assert!(try_chunk(&numbers, 0).is_err());
+/// ```
+pub fn try_chunk<T>(collection: &[T], size: usize) -> Result<Vec<Vec<T>>, LowdashError>
+where
+    T: Clone,
+{
+    if size == 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "chunk",
+            reason: "Size parameter must be greater than 0",
+        });
+    }
+
+    Ok(chunk(collection, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_chunk_ok() {
+        let numbers = vec![1, 2, 3, 4, 5, 6, 7];
+        let result = try_chunk(&numbers, 3);
+        assert_eq!(result, Ok(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]));
+    }
+
+    #[test]
+    fn test_try_chunk_zero_size() {
+        let numbers = vec![1, 2, 3];
+        let result = try_chunk(&numbers, 0);
+        assert_eq!(
+            result,
+            Err(LowdashError::InvalidArgument {
+                function: "chunk",
+                reason: "Size parameter must be greater than 0",
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_chunk_empty_collection() {
+        let empty: Vec<i32> = vec![];
+        assert_eq!(try_chunk(&empty, 2), Ok(vec![]));
+    }
+}
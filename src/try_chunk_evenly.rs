@@ -0,0 +1,82 @@
+use crate::chunk_evenly::push_chunks_evenly;
+use crate::LowdashError;
+
+/// Divide a collection into `parts` near-equal chunks like `chunk_evenly`, returning an error
+/// instead of panicking when `parts` is 0 or too large for the chunks to be allocated.
+///
+/// # Arguments
+/// * `collection` - A slice of items to be divided.
+/// * `parts` - The number of chunks to produce.
+///
+/// # Returns
+/// * `Ok(Vec<Vec<T>>)` - The chunks, as returned by `chunk_evenly`.
+/// * `Err(LowdashError::InvalidArgument)` - If `parts` is 0, or the `parts` chunks cannot be
+///   allocated.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_chunk_evenly;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// assert_eq!(try_chunk_evenly(&numbers, 2), Ok(vec![vec![1, 2, 3], vec![4, 5]]));
+/// assert!(try_chunk_evenly(&numbers, 0).is_err());
+/// assert!(try_chunk_evenly(&numbers, usize::MAX).is_err());
+/// ```
+pub fn try_chunk_evenly<T>(collection: &[T], parts: usize) -> Result<Vec<Vec<T>>, LowdashError>
+where
+    T: Clone,
+{
+    if parts == 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "chunk_evenly",
+            reason: "Parts parameter must be greater than 0",
+        });
+    }
+
+    let mut result = Vec::new();
+    if result.try_reserve_exact(parts).is_err() {
+        return Err(LowdashError::InvalidArgument {
+            function: "chunk_evenly",
+            reason: "Parts parameter is too large to allocate",
+        });
+    }
+    push_chunks_evenly(collection, parts, &mut result);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_chunk_evenly_ok() {
+        let numbers = vec![1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(
+            try_chunk_evenly(&numbers, 3),
+            Ok(vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]])
+        );
+    }
+
+    #[test]
+    fn test_try_chunk_evenly_zero_parts() {
+        assert_eq!(
+            try_chunk_evenly(&[1, 2, 3], 0),
+            Err(LowdashError::InvalidArgument {
+                function: "chunk_evenly",
+                reason: "Parts parameter must be greater than 0",
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_chunk_evenly_parts_too_large() {
+        assert_eq!(
+            try_chunk_evenly(&[1], usize::MAX),
+            Err(LowdashError::InvalidArgument {
+                function: "chunk_evenly",
+                reason: "Parts parameter is too large to allocate",
+            })
+        );
+    }
+}
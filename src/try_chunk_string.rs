@@ -0,0 +1,55 @@
+use crate::{chunk_string, LowdashError};
+
+/// Splits a string into chunks of specified size, returning an error instead of panicking when
+/// `size` is less than or equal to 0.
+///
+/// # Arguments
+/// * `str_input` - The input string to be chunked
+/// * `size` - The size of each chunk
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - The chunked strings, as returned by `chunk_string`
+/// * `Err(LowdashError::InvalidArgument)` - If `size` is less than or equal to 0
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_chunk_string;
+///
+/// assert_eq!(try_chunk_string("hello", 2), Ok(vec!["he".to_string(), "ll".to_string(), "o".to_string()]));
+/// assert!(try_chunk_string("hello", 0).is_err());
+/// ```
+pub fn try_chunk_string(str_input: &str, size: i32) -> Result<Vec<String>, LowdashError> {
+    if size <= 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "chunk_string",
+            reason: "Size parameter must be greater than 0",
+        });
+    }
+
+    Ok(chunk_string(str_input, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_chunk_string_ok() {
+        let result = try_chunk_string("hello world", 3).unwrap();
+        assert_eq!(result, vec!["hel", "lo ", "wor", "ld"]);
+    }
+
+    #[test]
+    fn test_try_chunk_string_zero_size() {
+        let result = try_chunk_string("hello", 0);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "chunk_string: Size parameter must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn test_try_chunk_string_negative_size() {
+        assert!(try_chunk_string("hello", -3).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{clamp, LowdashError};
+
+/// Clamps a value between a minimum and maximum value, returning an error when the bounds are
+/// inverted or not comparable instead of silently picking one of them.
+///
+/// # Arguments
+/// * `value` - The value to clamp.
+/// * `min` - The minimum allowable value.
+/// * `max` - The maximum allowable value.
+///
+/// # Returns
+/// * `Ok(T)` - The clamped value.
+/// * `Err(LowdashError::InvalidArgument)` - If `min` is greater than `max`, or either is NaN.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_clamp;
+///
+/// assert_eq!(try_clamp(15, 0, 10), Ok(10));
+/// assert_eq!(try_clamp(-5, 0, 10), Ok(0));
+/// assert!(try_clamp(5, 10, 0).is_err());
+/// assert!(try_clamp(0.5, f64::NAN, 1.0).is_err());
+/// ```
+pub fn try_clamp<T: PartialOrd>(value: T, min: T, max: T) -> Result<T, LowdashError> {
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    if !(min <= max) {
+        return Err(LowdashError::InvalidArgument {
+            function: "clamp",
+            reason: "Min must not be greater than max",
+        });
+    }
+
+    Ok(clamp(value, min, max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_clamp_ok() {
+        assert_eq!(try_clamp(5, 0, 10), Ok(5));
+        assert_eq!(try_clamp(-5, 0, 10), Ok(0));
+        assert_eq!(try_clamp(15.5, 0.0, 10.0), Ok(10.0));
+        assert_eq!(try_clamp(7, 7, 7), Ok(7));
+    }
+
+    #[test]
+    fn test_try_clamp_inverted_bounds() {
+        assert_eq!(
+            try_clamp(5, 10, 0),
+            Err(LowdashError::InvalidArgument {
+                function: "clamp",
+                reason: "Min must not be greater than max",
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_clamp_nan_bounds() {
+        assert!(try_clamp(1.0, f64::NAN, 2.0).is_err());
+        assert!(try_clamp(1.0, 0.0, f64::NAN).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{latest, LowdashError};
+use std::time::SystemTime;
+
+/// Returns the latest `SystemTime` from the provided arguments, or an error if no arguments are
+/// provided instead of `SystemTime::UNIX_EPOCH`.
+///
+/// # Arguments
+/// * `times` - A slice of `SystemTime` instances.
+///
+/// # Returns
+/// * `Ok(SystemTime)` - The latest `SystemTime` among the provided arguments.
+/// * `Err(LowdashError::EmptyCollection)` - If `times` is empty.
+///
+/// # Examples
+/// ```rust
+/// use std::time::{SystemTime, Duration};
+/// use lowdash::try_latest;
+///
+/// let now = SystemTime::now();
+/// let later = now + Duration::new(10, 0);
+/// assert_eq!(try_latest(&[now, later]), Ok(later));
+/// assert!(try_latest(&[]).is_err());
+/// ```
+pub fn try_latest(times: &[SystemTime]) -> Result<SystemTime, LowdashError> {
+    if times.is_empty() {
+        return Err(LowdashError::EmptyCollection { function: "latest" });
+    }
+
+    Ok(latest(times))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_try_latest_ok() {
+        let t1 = UNIX_EPOCH + Duration::new(100, 0);
+        let t2 = UNIX_EPOCH + Duration::new(300, 0);
+        let t3 = UNIX_EPOCH + Duration::new(200, 0);
+        assert_eq!(try_latest(&[t1, t2, t3]), Ok(t2));
+    }
+
+    #[test]
+    fn test_try_latest_epoch_is_not_an_error() {
+        assert_eq!(try_latest(&[UNIX_EPOCH]), Ok(UNIX_EPOCH));
+    }
+
+    #[test]
+    fn test_try_latest_empty() {
+        assert_eq!(
+            try_latest(&[]),
+            Err(LowdashError::EmptyCollection { function: "latest" })
+        );
+    }
+}
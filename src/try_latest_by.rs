@@ -0,0 +1,105 @@
+use crate::LowdashError;
+use std::time::SystemTime;
+
+/// Returns the item from the collection for which the iteratee returns the latest `SystemTime`,
+/// or an error if the collection is empty instead of the default value of `T`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `iteratee` - A function that takes a reference to an item and returns a `SystemTime`.
+///
+/// # Returns
+/// * `Ok(T)` - The item with the latest `SystemTime` as determined by the iteratee.
+/// * `Err(LowdashError::EmptyCollection)` - If the collection is empty.
+///
+/// # Examples
+/// ```rust
+/// use std::time::{SystemTime, Duration};
+/// use lowdash::try_latest_by;
+///
+/// let records = vec![
+///     (1, SystemTime::UNIX_EPOCH + Duration::new(100, 0)),
+///     (2, SystemTime::UNIX_EPOCH + Duration::new(200, 0)),
+/// ];
+/// assert_eq!(try_latest_by(&records, |r| r.1).unwrap().0, 2);
+///
+/// let empty: Vec<(i32, SystemTime)> = vec![];
+/// assert!(try_latest_by(&empty, |r| r.1).is_err());
+/// ```
+pub fn try_latest_by<T, F>(collection: &[T], iteratee: F) -> Result<T, LowdashError>
+where
+    F: Fn(&T) -> SystemTime,
+    T: Clone,
+{
+    let mut items = collection.iter();
+    let first = items.next().ok_or(LowdashError::EmptyCollection {
+        function: "latest_by",
+    })?;
+
+    let mut latest_item = first;
+    let mut latest_time = iteratee(first);
+    for item in items {
+        let item_time = iteratee(item);
+        if item_time > latest_time {
+            latest_item = item;
+            latest_time = item_time;
+        }
+    }
+
+    Ok(latest_item.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::latest_by;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[derive(Debug, Clone, PartialEq, Default)]
+    struct Record {
+        id: u32,
+        timestamp: u64,
+    }
+
+    fn time_of(record: &Record) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(record.timestamp)
+    }
+
+    #[test]
+    fn test_try_latest_by_matches_latest_by() {
+        let records = vec![
+            Record {
+                id: 1,
+                timestamp: 100,
+            },
+            Record {
+                id: 2,
+                timestamp: 300,
+            },
+            Record {
+                id: 3,
+                timestamp: 300,
+            },
+            Record {
+                id: 4,
+                timestamp: 200,
+            },
+        ];
+        assert_eq!(
+            try_latest_by(&records, time_of),
+            Ok(latest_by(&records, time_of))
+        );
+        assert_eq!(try_latest_by(&records, time_of).unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_try_latest_by_empty() {
+        let records: Vec<Record> = vec![];
+        assert_eq!(
+            try_latest_by(&records, time_of),
+            Err(LowdashError::EmptyCollection {
+                function: "latest_by"
+            })
+        );
+    }
+}
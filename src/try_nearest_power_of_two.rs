@@ -0,0 +1,52 @@
+use crate::{nearest_power_of_two, LowdashError};
+
+/// Calculates the smallest power of two greater than or equal to the given capacity, returning an
+/// error instead of silently capping the result at 2^30.
+///
+/// # Arguments
+/// * `cap` - The capacity for which to find the nearest power of two.
+///
+/// # Returns
+/// * `Ok(usize)` - The smallest power of two greater than or equal to `cap`.
+/// * `Err(LowdashError::InvalidArgument)` - If `cap` is greater than 2^30.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_nearest_power_of_two;
+///
+/// assert_eq!(try_nearest_power_of_two(100), Ok(128));
+/// assert!(try_nearest_power_of_two((1 << 30) + 1).is_err());
+/// ```
+pub fn try_nearest_power_of_two(cap: usize) -> Result<usize, LowdashError> {
+    if cap > 1 << 30 {
+        return Err(LowdashError::InvalidArgument {
+            function: "nearest_power_of_two",
+            reason: "Capacity must not be greater than 2^30",
+        });
+    }
+
+    Ok(nearest_power_of_two(cap))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_nearest_power_of_two_ok() {
+        assert_eq!(try_nearest_power_of_two(0), Ok(1));
+        assert_eq!(try_nearest_power_of_two(5), Ok(8));
+        assert_eq!(try_nearest_power_of_two(1 << 30), Ok(1 << 30));
+    }
+
+    #[test]
+    fn test_try_nearest_power_of_two_too_large() {
+        assert_eq!(
+            try_nearest_power_of_two((1 << 30) + 1),
+            Err(LowdashError::InvalidArgument {
+                function: "nearest_power_of_two",
+                reason: "Capacity must not be greater than 2^30",
+            })
+        );
+    }
+}
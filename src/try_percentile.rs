@@ -0,0 +1,84 @@
+use crate::{percentile, LowdashError};
+
+/// Calculates the percentile of a collection, reporting why the percentile could not be computed
+/// instead of returning `None`.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values.
+/// * `p` - The percentile to compute, between 0 and 100 inclusive.
+///
+/// # Returns
+/// * `Ok(f64)` - The calculated percentile.
+/// * `Err(LowdashError::EmptyCollection)` - If the collection is empty.
+/// * `Err(LowdashError::InvalidArgument)` - If `p` is outside 0 to 100 or is NaN.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{try_percentile, LowdashError};
+///
+/// let numbers = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// assert_eq!(try_percentile(&numbers, 50.0), Ok(3.0));
+/// assert_eq!(
+///     try_percentile(&numbers, 150.0),
+///     Err(LowdashError::InvalidArgument {
+///         function: "percentile",
+///         reason: "Percentile must be between 0 and 100",
+///     })
+/// );
+/// ```
+pub fn try_percentile<T>(collection: &[T], p: f64) -> Result<f64, LowdashError>
+where
+    T: Copy + Into<f64> + PartialOrd,
+{
+    if collection.is_empty() {
+        return Err(LowdashError::EmptyCollection {
+            function: "percentile",
+        });
+    }
+
+    let invalid = LowdashError::InvalidArgument {
+        function: "percentile",
+        reason: "Percentile must be between 0 and 100",
+    };
+    if !(0.0..=100.0).contains(&p) {
+        return Err(invalid);
+    }
+
+    percentile(collection, p).ok_or(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_percentile_ok() {
+        let numbers = vec![1, 2, 3, 4, 5];
+        assert_eq!(try_percentile(&numbers, 0.0), Ok(1.0));
+        assert_eq!(try_percentile(&numbers, 50.0), Ok(3.0));
+        assert_eq!(try_percentile(&numbers, 100.0), Ok(5.0));
+    }
+
+    #[test]
+    fn test_try_percentile_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(
+            try_percentile(&numbers, 50.0),
+            Err(LowdashError::EmptyCollection {
+                function: "percentile"
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_percentile_out_of_range() {
+        let numbers = vec![1.0, 2.0];
+        let expected = Err(LowdashError::InvalidArgument {
+            function: "percentile",
+            reason: "Percentile must be between 0 and 100",
+        });
+        assert_eq!(try_percentile(&numbers, -1.0), expected);
+        assert_eq!(try_percentile(&numbers, 100.5), expected);
+        assert_eq!(try_percentile(&numbers, f64::NAN), expected);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::common::EntropyRng;
+use crate::{random_string_with_rng, LowdashError};
+
+/// Generates a random string of a specified size using the provided charset, returning an error
+/// instead of panicking on invalid arguments.
+///
+/// # Arguments
+///
+/// * `size` - The length of the generated string.
+/// * `charset` - A slice of characters to use for generating the string.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated string.
+/// * `Err(LowdashError::InvalidArgument)` - If `size` is 0 or `charset` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use lowdash::common::ALPHANUMERIC_CHARSET;
+/// use lowdash::try_random_string;
+///
+/// let random_str = try_random_string(10, ALPHANUMERIC_CHARSET).unwrap();
+/// assert_eq!(random_str.len(), 10);
+/// assert!(try_random_string(0, ALPHANUMERIC_CHARSET).is_err());
+/// assert!(try_random_string(10, &[]).is_err());
+/// ```
+pub fn try_random_string(size: usize, charset: &[char]) -> Result<String, LowdashError> {
+    if size == 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "random_string",
+            reason: "Size parameter must be greater than 0",
+        });
+    }
+    if charset.is_empty() {
+        return Err(LowdashError::InvalidArgument {
+            function: "random_string",
+            reason: "Charset parameter must not be empty",
+        });
+    }
+
+    Ok(random_string_with_rng(size, charset, &mut EntropyRng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common;
+
+    #[test]
+    fn test_try_random_string_ok() {
+        let charset = common::ALPHANUMERIC_CHARSET;
+        let random_str = try_random_string(16, charset).unwrap();
+        assert_eq!(random_str.len(), 16);
+        assert!(random_str.chars().all(|c| charset.contains(&c)));
+    }
+
+    #[test]
+    fn test_try_random_string_size_zero() {
+        let result = try_random_string(0, common::ALPHANUMERIC_CHARSET);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "random_string: Size parameter must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn test_try_random_string_empty_charset() {
+        let result = try_random_string(10, &[]);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "random_string: Charset parameter must not be empty"
+        );
+    }
+}
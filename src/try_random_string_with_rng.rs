@@ -0,0 +1,88 @@
+use crate::common::Rng;
+use crate::{random_string_with_rng, LowdashError};
+
+/// Generates a random string of a specified size using the provided charset and random number
+/// generator, returning an error instead of panicking on invalid arguments.
+///
+/// # Arguments
+///
+/// * `size` - The length of the generated string.
+/// * `charset` - A slice of characters to use for generating the string.
+/// * `rng` - The random number generator to draw from.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The generated string.
+/// * `Err(LowdashError::InvalidArgument)` - If `size` is 0 or `charset` is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use lowdash::common::{SeededRng, ALPHANUMERIC_CHARSET};
+/// use lowdash::try_random_string_with_rng;
+///
+/// let mut rng = SeededRng::new(42);
+/// let random_str = try_random_string_with_rng(10, ALPHANUMERIC_CHARSET, &mut rng).unwrap();
+/// assert_eq!(random_str.len(), 10);
+/// assert!(try_random_string_with_rng(0, ALPHANUMERIC_CHARSET, &mut rng).is_err());
+/// ```
+pub fn try_random_string_with_rng<R>(
+    size: usize,
+    charset: &[char],
+    rng: &mut R,
+) -> Result<String, LowdashError>
+where
+    R: Rng + ?Sized,
+{
+    if size == 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "random_string_with_rng",
+            reason: "Size parameter must be greater than 0",
+        });
+    }
+    if charset.is_empty() {
+        return Err(LowdashError::InvalidArgument {
+            function: "random_string_with_rng",
+            reason: "Charset parameter must not be empty",
+        });
+    }
+
+    Ok(random_string_with_rng(size, charset, rng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{SeededRng, ALPHANUMERIC_CHARSET};
+
+    #[test]
+    fn test_try_random_string_with_rng_matches_panicking_version() {
+        let result = try_random_string_with_rng(20, ALPHANUMERIC_CHARSET, &mut SeededRng::new(8));
+        let expected = random_string_with_rng(20, ALPHANUMERIC_CHARSET, &mut SeededRng::new(8));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_try_random_string_with_rng_size_zero() {
+        let result = try_random_string_with_rng(0, ALPHANUMERIC_CHARSET, &mut SeededRng::new(8));
+        assert_eq!(
+            result,
+            Err(LowdashError::InvalidArgument {
+                function: "random_string_with_rng",
+                reason: "Size parameter must be greater than 0",
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_random_string_with_rng_empty_charset() {
+        let result = try_random_string_with_rng(5, &[], &mut SeededRng::new(8));
+        assert_eq!(
+            result,
+            Err(LowdashError::InvalidArgument {
+                function: "random_string_with_rng",
+                reason: "Charset parameter must not be empty",
+            })
+        );
+    }
+}
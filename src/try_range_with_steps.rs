@@ -0,0 +1,106 @@
+use crate::common::CheckedAdd;
+use crate::LowdashError;
+
+/// Creates a range of numbers from start to end (exclusive) with the specified step, returning an
+/// error for a step that can never reach `end` instead of an empty vector.
+///
+/// Unlike `range_with_steps`, the values are produced with checked addition: the range stops at
+/// the last value before the next step would overflow the type, rather than panicking. A float
+/// step too small to change the current value also ends the range.
+///
+/// # Arguments
+/// * `start` - The starting value of the range.
+/// * `end` - The ending value of the range (exclusive).
+/// * `step` - The step size between values.
+///
+/// # Returns
+/// * `Ok(Vec<T>)` - The values in the range.
+/// * `Err(LowdashError::InvalidArgument)` - If `step` is zero, or its sign disagrees with
+///   `end - start`.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_range_with_steps;
+///
+/// assert_eq!(try_range_with_steps(0, 10, 3), Ok(vec![0, 3, 6, 9]));
+/// assert_eq!(try_range_with_steps(250u8, 255, 10), Ok(vec![250]));
+/// assert!(try_range_with_steps(0, 10, 0).is_err());
+/// assert!(try_range_with_steps(0, 10, -1).is_err());
+/// ```
+pub fn try_range_with_steps<T>(start: T, end: T, step: T) -> Result<Vec<T>, LowdashError>
+where
+    T: Copy + PartialOrd + Default + CheckedAdd,
+{
+    let zero = T::default();
+    if step == zero {
+        return Err(LowdashError::InvalidArgument {
+            function: "range_with_steps",
+            reason: "Step must not be zero",
+        });
+    }
+    if (start < end && step < zero) || (start > end && step > zero) {
+        return Err(LowdashError::InvalidArgument {
+            function: "range_with_steps",
+            reason: "Step must point from start towards end",
+        });
+    }
+
+    let ascending = step > zero;
+    let mut result = Vec::new();
+    let mut current = start;
+    while (ascending && current < end) || (!ascending && current > end) {
+        result.push(current);
+        match current.checked_add(step) {
+            // A float step too small to move `current` would otherwise loop forever
+            Some(next) if next != current => current = next,
+            _ => break,
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_range_with_steps_ok() {
+        assert_eq!(try_range_with_steps(10, 0, -4), Ok(vec![10, 6, 2]));
+        assert_eq!(try_range_with_steps(0.0, 1.0, 0.5), Ok(vec![0.0, 0.5]));
+        assert_eq!(try_range_with_steps(3, 3, -1), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_try_range_with_steps_zero_step() {
+        assert_eq!(
+            try_range_with_steps(0, 10, 0),
+            Err(LowdashError::InvalidArgument {
+                function: "range_with_steps",
+                reason: "Step must not be zero",
+            })
+        );
+        assert!(try_range_with_steps(0.0, 1.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_try_range_with_steps_wrong_sign() {
+        let expected = Err(LowdashError::InvalidArgument {
+            function: "range_with_steps",
+            reason: "Step must point from start towards end",
+        });
+        assert_eq!(try_range_with_steps(0, 10, -1), expected);
+        assert_eq!(try_range_with_steps(10, 0, 1), expected);
+    }
+
+    #[test]
+    fn test_try_range_with_steps_stops_before_overflow() {
+        assert_eq!(try_range_with_steps(250u8, 255, 10), Ok(vec![250]));
+        assert_eq!(try_range_with_steps(-120i8, -128, -5), Ok(vec![-120, -125]));
+        assert_eq!(
+            try_range_with_steps(f64::MAX / 2.0, f64::INFINITY, f64::MAX),
+            Ok(vec![f64::MAX / 2.0])
+        );
+        assert_eq!(try_range_with_steps(1e20, 2e20, 1.0), Ok(vec![1e20]));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::common::EntropyRng;
+use crate::{try_sample_with_rng, LowdashError};
+
+/// Returns a pseudo-random element from the collection, or an error if the collection is empty
+/// instead of the default value of T.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+///
+/// # Returns
+/// * `Ok(T)` - A pseudo-randomly selected item from the collection
+/// * `Err(LowdashError::EmptyCollection)` - If the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_sample;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let result = try_sample(&numbers).unwrap();
+/// assert!(numbers.contains(&result));
+///
+/// let empty: Vec<i32> = vec![];
+/// assert!(try_sample(&empty).is_err());
+/// ```
+pub fn try_sample<T>(collection: &[T]) -> Result<T, LowdashError>
+where
+    T: Clone,
+{
+    try_sample_with_rng(collection, &mut EntropyRng)
+        .map_err(|_| LowdashError::EmptyCollection { function: "sample" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_sample_ok() {
+        let collection = vec!["apple", "banana", "cherry"];
+        let result = try_sample(&collection).unwrap();
+        assert!(collection.contains(&result));
+    }
+
+    #[test]
+    fn test_try_sample_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        assert_eq!(
+            try_sample(&collection),
+            Err(LowdashError::EmptyCollection { function: "sample" })
+        );
+    }
+
+    #[test]
+    fn test_try_sample_without_default() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct NoDefault(i32);
+
+        let collection = vec![NoDefault(1)];
+        assert_eq!(try_sample(&collection), Ok(NoDefault(1)));
+    }
+}
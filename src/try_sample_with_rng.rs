@@ -0,0 +1,67 @@
+use crate::common::Rng;
+use crate::LowdashError;
+
+/// Returns a pseudo-random element from the collection using the provided random number generator,
+/// or an error if the collection is empty instead of the default value of T.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `rng` - The random number generator to draw from
+///
+/// # Returns
+/// * `Ok(T)` - A pseudo-randomly selected item from the collection
+/// * `Err(LowdashError::EmptyCollection)` - If the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::common::SeededRng;
+/// use lowdash::try_sample_with_rng;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// let result = try_sample_with_rng(&numbers, &mut SeededRng::new(42)).unwrap();
+/// assert!(numbers.contains(&result));
+///
+/// let empty: Vec<i32> = vec![];
+/// assert!(try_sample_with_rng(&empty, &mut SeededRng::new(42)).is_err());
+/// ```
+pub fn try_sample_with_rng<T, R>(collection: &[T], rng: &mut R) -> Result<T, LowdashError>
+where
+    T: Clone,
+    R: Rng + ?Sized,
+{
+    if collection.is_empty() {
+        return Err(LowdashError::EmptyCollection {
+            function: "sample_with_rng",
+        });
+    }
+
+    let index = rng.next_usize(collection.len());
+
+    Ok(collection[index].clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::SeededRng;
+    use crate::sample_with_rng;
+
+    #[test]
+    fn test_try_sample_with_rng_matches_sample_with_rng() {
+        let collection: Vec<i32> = (0..100).collect();
+        let result = try_sample_with_rng(&collection, &mut SeededRng::new(4));
+        let expected = sample_with_rng(&collection, &mut SeededRng::new(4));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn test_try_sample_with_rng_empty_collection() {
+        let collection: Vec<i32> = vec![];
+        assert_eq!(
+            try_sample_with_rng(&collection, &mut SeededRng::new(4)),
+            Err(LowdashError::EmptyCollection {
+                function: "sample_with_rng"
+            })
+        );
+    }
+}
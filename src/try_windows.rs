@@ -0,0 +1,79 @@
+use crate::{windows, LowdashError};
+
+/// Collects the windows of a collection like `windows`, returning an error instead of panicking
+/// when `size` or `step` is 0.
+///
+/// # Arguments
+/// * `collection` - A slice of items to slide over.
+/// * `size` - The number of elements in each window.
+/// * `step` - How many elements each window starts after the previous one.
+///
+/// # Returns
+/// * `Ok(Vec<Vec<T>>)` - The windows, as returned by `windows`.
+/// * `Err(LowdashError::InvalidArgument)` - If `size` or `step` is 0.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::try_windows;
+///
+/// let numbers = vec![1, 2, 3, 4];
+/// assert_eq!(try_windows(&numbers, 2, 2), Ok(vec![vec![1, 2], vec![3, 4]]));
+/// assert!(try_windows(&numbers, 0, 1).is_err());
+/// assert!(try_windows(&numbers, 2, 0).is_err());
+/// ```
+pub fn try_windows<T>(
+    collection: &[T],
+    size: usize,
+    step: usize,
+) -> Result<Vec<Vec<T>>, LowdashError>
+where
+    T: Clone,
+{
+    if size == 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "windows",
+            reason: "Size parameter must be greater than 0",
+        });
+    }
+    if step == 0 {
+        return Err(LowdashError::InvalidArgument {
+            function: "windows",
+            reason: "Step parameter must be greater than 0",
+        });
+    }
+
+    Ok(windows(collection, size, step))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_windows_ok() {
+        let numbers = vec![1, 2, 3, 4, 5];
+        assert_eq!(try_windows(&numbers, 2, 2), Ok(windows(&numbers, 2, 2)));
+    }
+
+    #[test]
+    fn test_try_windows_zero_size() {
+        assert_eq!(
+            try_windows(&[1, 2, 3], 0, 1),
+            Err(LowdashError::InvalidArgument {
+                function: "windows",
+                reason: "Size parameter must be greater than 0",
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_windows_zero_step() {
+        assert_eq!(
+            try_windows(&[1, 2, 3], 1, 0),
+            Err(LowdashError::InvalidArgument {
+                function: "windows",
+                reason: "Step parameter must be greater than 0",
+            })
+        );
+    }
+}
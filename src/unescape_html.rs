@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+/// Converts HTML entities back to the characters they stand for, like lodash's `unescape`.
+///
+/// The entities produced by [`escape_html`](crate::escape_html), `&amp;`, `&lt;`, `&gt;`,
+/// `&quot;` and `&#39;`, are decoded, as well as `&apos;` and any decimal (`&#169;`) or
+/// hexadecimal (`&#xA9;`) character reference. Other named entities and invalid references are
+/// kept as they are. The input is borrowed when it contains no `&`.
+///
+/// # Arguments
+/// * `str_input` - The string to unescape
+///
+/// # Returns
+/// * `Cow<str>` - The unescaped string, borrowed from the input when it contains no entity
+///
+/// # Examples
+/// ```rust
+/// use lowdash::unescape_html;
+/// use std::borrow::Cow;
+///
+/// assert_eq!(
+///     unescape_html("&lt;b&gt;Tom&#39;s &amp; Jerry&#x27;s&lt;/b&gt;"),
+///     "<b>Tom's & Jerry's</b>"
+/// );
+/// assert_eq!(unescape_html("&copy; &#169;"), "&copy; ©");
+/// assert!(matches!(unescape_html("plain text"), Cow::Borrowed("plain text")));
+/// ```
+pub fn unescape_html(str_input: &str) -> Cow<'_, str> {
+    let Some(first) = str_input.find('&') else {
+        return Cow::Borrowed(str_input);
+    };
+
+    let mut result = String::with_capacity(str_input.len());
+    result.push_str(&str_input[..first]);
+    let mut rest = &str_input[first..];
+
+    while let Some(ampersand) = rest.find('&') {
+        result.push_str(&rest[..ampersand]);
+        rest = &rest[ampersand..];
+
+        match decode_entity(rest) {
+            Some((decoded, len)) => {
+                result.push(decoded);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+
+    Cow::Owned(result)
+}
+
+/// Decodes the entity at the start of `text`, returning the character and the entity's length.
+fn decode_entity(text: &str) -> Option<(char, usize)> {
+    // The longest entity handled is a hexadecimal reference such as `&#x10FFFF;`.
+    let end = text.bytes().take(11).position(|b| b == b';')?;
+    let name = &text[1..end];
+
+    let decoded = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) if !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                    u32::from_str_radix(hex, 16).ok()?
+                }
+                None if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) => {
+                    number.parse().ok()?
+                }
+                _ => return None,
+            };
+            if code == 0 {
+                return None;
+            }
+            char::from_u32(code)?
+        }
+    };
+
+    Some((decoded, end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::escape_html;
+
+    #[test]
+    fn test_lodash_unescape() {
+        assert_eq!(unescape_html("&amp;&lt;&gt;&quot;&#39;/"), "&<>\"'/");
+        assert_eq!(
+            unescape_html("fred, barney, &amp; pebbles"),
+            "fred, barney, & pebbles"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in [
+            "&<>\"'/",
+            "<a href='x'>&amp;</a>",
+            "no entities",
+            "日本 & 中国",
+        ] {
+            assert_eq!(unescape_html(&escape_html(text)), text);
+        }
+    }
+
+    #[test]
+    fn test_unescapes_only_once() {
+        assert_eq!(unescape_html("&amp;lt;"), "&lt;");
+    }
+
+    #[test]
+    fn test_numeric_references() {
+        assert_eq!(unescape_html("&#65;&#x42;&#X43;"), "ABC");
+        assert_eq!(unescape_html("&#128512;"), "😀");
+        assert_eq!(unescape_html("&#x1F600;"), "😀");
+    }
+
+    #[test]
+    fn test_invalid_references_are_kept() {
+        for text in [
+            "&#;",
+            "&#x;",
+            "&#0;",
+            "&#xD800;",
+            "&#x110000;",
+            "&#12a;",
+            "&#99999999999;",
+            "&nbsp;",
+            "& ;",
+            "AT&T",
+            "&amp",
+            "&",
+        ] {
+            assert_eq!(unescape_html(text), text, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_ampersand_before_entity() {
+        assert_eq!(unescape_html("&&amp;"), "&&");
+        assert_eq!(unescape_html("a & b &lt; c"), "a & b < c");
+    }
+
+    #[test]
+    fn test_borrows_without_ampersand() {
+        assert!(matches!(unescape_html("abc"), Cow::Borrowed("abc")));
+        assert!(matches!(unescape_html(""), Cow::Borrowed("")));
+    }
+}
@@ -0,0 +1,786 @@
+//! Built-in Unicode tables for grapheme clusters and terminal display width.
+//!
+//! The tables follow Unicode 15 (East Asian Width and the grapheme break properties of UAX #29)
+//! for the scripts and emoji in common use. Rare historic scripts may be approximated, which is
+//! the price of keeping the crate free of dependencies.
+
+/// East Asian Wide and Fullwidth characters, plus emoji with default emoji presentation.
+const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x2E99),
+    (0x2E9B, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x2FF0, 0x2FFF),
+    (0x3000, 0x3029),
+    (0x3030, 0x303E),
+    (0x3041, 0x3096),
+    (0x309B, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x318E),
+    (0x3190, 0x31E3),
+    (0x31F0, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0x4DBF),
+    (0x4E00, 0xA48C),
+    (0xA490, 0xA4C6),
+    (0xA960, 0xA97C),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFF01, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x16FE4),
+    (0x16FF0, 0x16FF1),
+    (0x17000, 0x187F7),
+    (0x18800, 0x18CD5),
+    (0x18D00, 0x18D08),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B132, 0x1B132),
+    (0x1B150, 0x1B152),
+    (0x1B155, 0x1B155),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F200, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1F260, 0x1F265),
+    (0x1F300, 0x1F320),
+    (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C),
+    (0x1F37E, 0x1F393),
+    (0x1F3A0, 0x1F3CA),
+    (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0),
+    (0x1F3F4, 0x1F3F4),
+    (0x1F3F8, 0x1F43E),
+    (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC),
+    (0x1F4FF, 0x1F53D),
+    (0x1F54B, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A4),
+    (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F6D0, 0x1F6D2),
+    (0x1F6D5, 0x1F6D7),
+    (0x1F6DC, 0x1F6DF),
+    (0x1F6EB, 0x1F6EC),
+    (0x1F6F4, 0x1F6FC),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C),
+    (0x1FA80, 0x1FA88),
+    (0x1FA90, 0x1FABD),
+    (0x1FABF, 0x1FAC5),
+    (0x1FACE, 0x1FADB),
+    (0x1FAE0, 0x1FAE8),
+    (0x1FAF0, 0x1FAF8),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+/// Nonspacing and enclosing marks, variation selectors and other characters with the
+/// grapheme `Extend` property. They have no width of their own.
+const EXTEND: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DC),
+    (0x06DF, 0x06E4),
+    (0x06E7, 0x06E8),
+    (0x06EA, 0x06ED),
+    (0x0711, 0x0711),
+    (0x0730, 0x074A),
+    (0x07A6, 0x07B0),
+    (0x07EB, 0x07F3),
+    (0x0816, 0x0819),
+    (0x081B, 0x0823),
+    (0x0825, 0x0827),
+    (0x0829, 0x082D),
+    (0x0859, 0x085B),
+    (0x08D3, 0x08E1),
+    (0x08E3, 0x0902),
+    (0x093A, 0x093A),
+    (0x093C, 0x093C),
+    (0x0941, 0x0948),
+    (0x094D, 0x094D),
+    (0x0951, 0x0957),
+    (0x0962, 0x0963),
+    (0x0981, 0x0981),
+    (0x09BC, 0x09BC),
+    (0x09BE, 0x09BE),
+    (0x09C1, 0x09C4),
+    (0x09CD, 0x09CD),
+    (0x09D7, 0x09D7),
+    (0x09E2, 0x09E3),
+    (0x0A01, 0x0A02),
+    (0x0A3C, 0x0A3C),
+    (0x0A41, 0x0A42),
+    (0x0A47, 0x0A48),
+    (0x0A4B, 0x0A4D),
+    (0x0A51, 0x0A51),
+    (0x0A70, 0x0A71),
+    (0x0A75, 0x0A75),
+    (0x0A81, 0x0A82),
+    (0x0ABC, 0x0ABC),
+    (0x0AC1, 0x0AC5),
+    (0x0AC7, 0x0AC8),
+    (0x0ACD, 0x0ACD),
+    (0x0AE2, 0x0AE3),
+    (0x0B01, 0x0B01),
+    (0x0B3C, 0x0B3C),
+    (0x0B3E, 0x0B3F),
+    (0x0B41, 0x0B44),
+    (0x0B4D, 0x0B4D),
+    (0x0B56, 0x0B57),
+    (0x0B62, 0x0B63),
+    (0x0B82, 0x0B82),
+    (0x0BBE, 0x0BBE),
+    (0x0BC0, 0x0BC0),
+    (0x0BCD, 0x0BCD),
+    (0x0BD7, 0x0BD7),
+    (0x0C00, 0x0C00),
+    (0x0C3E, 0x0C40),
+    (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4D),
+    (0x0C55, 0x0C56),
+    (0x0C62, 0x0C63),
+    (0x0CBC, 0x0CBC),
+    (0x0CBF, 0x0CBF),
+    (0x0CC2, 0x0CC2),
+    (0x0CC6, 0x0CC6),
+    (0x0CCC, 0x0CCD),
+    (0x0CD5, 0x0CD6),
+    (0x0CE2, 0x0CE3),
+    (0x0D00, 0x0D01),
+    (0x0D3E, 0x0D3E),
+    (0x0D41, 0x0D44),
+    (0x0D4D, 0x0D4D),
+    (0x0D57, 0x0D57),
+    (0x0D62, 0x0D63),
+    (0x0DCA, 0x0DCA),
+    (0x0DCF, 0x0DCF),
+    (0x0DD2, 0x0DD4),
+    (0x0DD6, 0x0DD6),
+    (0x0DDF, 0x0DDF),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x0EB1, 0x0EB1),
+    (0x0EB4, 0x0EBC),
+    (0x0EC8, 0x0ECD),
+    (0x0F18, 0x0F19),
+    (0x0F35, 0x0F35),
+    (0x0F37, 0x0F37),
+    (0x0F39, 0x0F39),
+    (0x0F71, 0x0F7E),
+    (0x0F80, 0x0F84),
+    (0x0F86, 0x0F87),
+    (0x0F8D, 0x0FBC),
+    (0x0FC6, 0x0FC6),
+    (0x102D, 0x1030),
+    (0x1032, 0x1037),
+    (0x1039, 0x103A),
+    (0x103D, 0x103E),
+    (0x1058, 0x1059),
+    (0x105E, 0x1060),
+    (0x1071, 0x1074),
+    (0x1082, 0x1082),
+    (0x1085, 0x1086),
+    (0x108D, 0x108D),
+    (0x109D, 0x109D),
+    (0x135D, 0x135F),
+    (0x1712, 0x1714),
+    (0x1732, 0x1734),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B4, 0x17B5),
+    (0x17B7, 0x17BD),
+    (0x17C6, 0x17C6),
+    (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD),
+    (0x180B, 0x180D),
+    (0x180F, 0x180F),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x1922),
+    (0x1927, 0x1928),
+    (0x1932, 0x1932),
+    (0x1939, 0x193B),
+    (0x1A17, 0x1A18),
+    (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56),
+    (0x1A58, 0x1A5E),
+    (0x1A60, 0x1A60),
+    (0x1A62, 0x1A62),
+    (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1AFF),
+    (0x1B00, 0x1B03),
+    (0x1B34, 0x1B3A),
+    (0x1B3C, 0x1B3C),
+    (0x1B42, 0x1B42),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5),
+    (0x1BA8, 0x1BA9),
+    (0x1BAB, 0x1BAD),
+    (0x1BE6, 0x1BE6),
+    (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED),
+    (0x1BEF, 0x1BF1),
+    (0x1C2C, 0x1C33),
+    (0x1C36, 0x1C37),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0),
+    (0x1CE2, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF8, 0x1CF9),
+    (0x1DC0, 0x1DFF),
+    (0x200C, 0x200C),
+    (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1),
+    (0x2D7F, 0x2D7F),
+    (0x2DE0, 0x2DFF),
+    (0x302A, 0x302F),
+    (0x3099, 0x309A),
+    (0xA66F, 0xA672),
+    (0xA674, 0xA67D),
+    (0xA69E, 0xA69F),
+    (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802),
+    (0xA806, 0xA806),
+    (0xA80B, 0xA80B),
+    (0xA825, 0xA826),
+    (0xA8C4, 0xA8C5),
+    (0xA8E0, 0xA8F1),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D),
+    (0xA947, 0xA951),
+    (0xA980, 0xA982),
+    (0xA9B3, 0xA9B3),
+    (0xA9B6, 0xA9B9),
+    (0xA9BC, 0xA9BD),
+    (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA2E),
+    (0xAA31, 0xAA32),
+    (0xAA35, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4C),
+    (0xAA7C, 0xAA7C),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1),
+    (0xAAEC, 0xAAED),
+    (0xAAF6, 0xAAF6),
+    (0xABE5, 0xABE5),
+    (0xABE8, 0xABE8),
+    (0xABED, 0xABED),
+    (0xFB1E, 0xFB1E),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFF9E, 0xFF9F),
+    (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27),
+    (0x10EAB, 0x10EAC),
+    (0x10F46, 0x10F50),
+    (0x11001, 0x11001),
+    (0x11038, 0x11046),
+    (0x1107F, 0x11081),
+    (0x110B3, 0x110B6),
+    (0x110B9, 0x110BA),
+    (0x11100, 0x11102),
+    (0x11127, 0x1112B),
+    (0x1112D, 0x11134),
+    (0x11173, 0x11173),
+    (0x11180, 0x11181),
+    (0x111B6, 0x111BE),
+    (0x1D165, 0x1D165),
+    (0x1D167, 0x1D169),
+    (0x1D16E, 0x1D172),
+    (0x1D17B, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1E000, 0x1E02A),
+    (0x1E130, 0x1E136),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94A),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// Spacing combining marks (grapheme `SpacingMark`): vowel signs that take up room next to
+/// their base but never start a cluster.
+const SPACING_MARK: &[(u32, u32)] = &[
+    (0x0903, 0x0903),
+    (0x093B, 0x093B),
+    (0x093E, 0x0940),
+    (0x0949, 0x094C),
+    (0x094E, 0x094F),
+    (0x0982, 0x0983),
+    (0x09BF, 0x09C0),
+    (0x09C7, 0x09C8),
+    (0x09CB, 0x09CC),
+    (0x0A03, 0x0A03),
+    (0x0A3E, 0x0A40),
+    (0x0A83, 0x0A83),
+    (0x0ABE, 0x0AC0),
+    (0x0AC9, 0x0AC9),
+    (0x0ACB, 0x0ACC),
+    (0x0B02, 0x0B03),
+    (0x0B40, 0x0B40),
+    (0x0B47, 0x0B48),
+    (0x0B4B, 0x0B4C),
+    (0x0BBF, 0x0BBF),
+    (0x0BC1, 0x0BC2),
+    (0x0BC6, 0x0BC8),
+    (0x0BCA, 0x0BCC),
+    (0x0C01, 0x0C03),
+    (0x0C41, 0x0C44),
+    (0x0C82, 0x0C83),
+    (0x0CBE, 0x0CBE),
+    (0x0CC0, 0x0CC1),
+    (0x0CC3, 0x0CC4),
+    (0x0CC7, 0x0CC8),
+    (0x0CCA, 0x0CCB),
+    (0x0D02, 0x0D03),
+    (0x0D3F, 0x0D40),
+    (0x0D46, 0x0D48),
+    (0x0D4A, 0x0D4C),
+    (0x0D82, 0x0D83),
+    (0x0DD0, 0x0DD1),
+    (0x0DD8, 0x0DDE),
+    (0x0DF2, 0x0DF3),
+    (0x0E33, 0x0E33),
+    (0x0EB3, 0x0EB3),
+    (0x0F3E, 0x0F3F),
+    (0x0F7F, 0x0F7F),
+    (0x1031, 0x1031),
+    (0x103B, 0x103C),
+    (0x1056, 0x1057),
+    (0x1084, 0x1084),
+    (0x17B6, 0x17B6),
+    (0x17BE, 0x17C5),
+    (0x17C7, 0x17C8),
+    (0x1923, 0x1926),
+    (0x1929, 0x192B),
+    (0x1930, 0x1931),
+    (0x1933, 0x1938),
+    (0x1A19, 0x1A1A),
+    (0x1A55, 0x1A55),
+    (0x1A57, 0x1A57),
+    (0x1A6D, 0x1A72),
+    (0x1B04, 0x1B04),
+    (0x1B3B, 0x1B3B),
+    (0x1B3D, 0x1B41),
+    (0x1B43, 0x1B44),
+    (0x1B82, 0x1B82),
+    (0x1BA1, 0x1BA1),
+    (0x1BA6, 0x1BA7),
+    (0x1BAA, 0x1BAA),
+    (0xA823, 0xA824),
+    (0xA827, 0xA827),
+    (0xA880, 0xA881),
+    (0xA8B4, 0xA8C3),
+    (0xA952, 0xA953),
+    (0xA983, 0xA983),
+    (0xA9B4, 0xA9B5),
+    (0xA9BA, 0xA9BB),
+    (0xA9BE, 0xA9C0),
+    (0xAA2F, 0xAA30),
+    (0xAA33, 0xAA34),
+    (0xAA4D, 0xAA4D),
+    (0xAAEB, 0xAAEB),
+    (0xAAEE, 0xAAEF),
+    (0xAAF5, 0xAAF5),
+    (0xABE3, 0xABE4),
+    (0xABE6, 0xABE7),
+    (0xABE9, 0xABEA),
+    (0xABEC, 0xABEC),
+];
+
+/// Invisible format characters that are neither `Extend` nor `ZWJ`; they break clusters like
+/// control characters and have no width.
+const FORMAT: &[(u32, u32)] = &[
+    (0x00AD, 0x00AD),
+    (0x0600, 0x0605),
+    (0x061C, 0x061C),
+    (0x06DD, 0x06DD),
+    (0x070F, 0x070F),
+    (0x180E, 0x180E),
+    (0x200B, 0x200B),
+    (0x200E, 0x200F),
+    (0x2028, 0x202E),
+    (0x2060, 0x2064),
+    (0x2066, 0x206F),
+    (0xFEFF, 0xFEFF),
+    (0xFFF9, 0xFFFB),
+    (0xE0001, 0xE0001),
+];
+
+/// Characters with the `Extended_Pictographic` property, which a zero width joiner glues into
+/// emoji sequences.
+const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x2388, 0x2388),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x27BF),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF),
+    (0x1F10D, 0x1F10F),
+    (0x1F12F, 0x1F12F),
+    (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1AD, 0x1F1E5),
+    (0x1F201, 0x1F20F),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F),
+    (0x1F249, 0x1F3FA),
+    (0x1F400, 0x1F53D),
+    (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F774, 0x1F77F),
+    (0x1F7D5, 0x1F7FF),
+    (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F),
+    (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F),
+    (0x1F8AE, 0x1F8FF),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+];
+
+const ZWJ: char = '\u{200D}';
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+fn in_table(c: char, table: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn is_control(c: char) -> bool {
+    c.is_control() || in_table(c, FORMAT)
+}
+
+fn is_extend(c: char) -> bool {
+    in_table(c, EXTEND)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+fn is_extended_pictographic(c: char) -> bool {
+    in_table(c, EXTENDED_PICTOGRAPHIC)
+}
+
+/// The Hangul syllable type of a character, for the conjoining jamo rules GB6 to GB8.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Hangul {
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+}
+
+fn hangul(c: char) -> Option<Hangul> {
+    match c as u32 {
+        0x1100..=0x115F | 0xA960..=0xA97C => Some(Hangul::L),
+        0x1160..=0x11A7 | 0xD7B0..=0xD7C6 => Some(Hangul::V),
+        0x11A8..=0x11FF | 0xD7CB..=0xD7FB => Some(Hangul::T),
+        code @ 0xAC00..=0xD7A3 if (code - 0xAC00) % 28 == 0 => Some(Hangul::Lv),
+        0xAC00..=0xD7A3 => Some(Hangul::Lvt),
+        _ => None,
+    }
+}
+
+/// Whether a cluster boundary separates `prev` and `c`.
+///
+/// `pictographic_zwj` is true when the cluster so far ends in an extended pictographic
+/// character followed by extends and a ZWJ, and `odd_regional_indicators` when it ends in an odd
+/// number of regional indicators.
+fn is_break(prev: char, c: char, pictographic_zwj: bool, odd_regional_indicators: bool) -> bool {
+    if prev == '\r' && c == '\n' {
+        return false;
+    }
+    if is_control(prev) || is_control(c) {
+        return true;
+    }
+    match (hangul(prev), hangul(c)) {
+        (Some(Hangul::L), Some(_)) => return false,
+        (Some(Hangul::Lv | Hangul::V), Some(Hangul::V | Hangul::T)) => return false,
+        (Some(Hangul::Lvt | Hangul::T), Some(Hangul::T)) => return false,
+        _ => {}
+    }
+    if is_extend(c) || c == ZWJ || in_table(c, SPACING_MARK) {
+        return false;
+    }
+    if prev == ZWJ && pictographic_zwj && is_extended_pictographic(c) {
+        return false;
+    }
+    if is_regional_indicator(prev) && is_regional_indicator(c) && odd_regional_indicators {
+        return false;
+    }
+    true
+}
+
+/// Splits a string into extended grapheme clusters, the units a reader sees as one character.
+pub(crate) fn graphemes(str_input: &str) -> impl Iterator<Item = &str> + '_ {
+    let mut rest = str_input;
+    std::iter::from_fn(move || {
+        let mut chars = rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut prev = first;
+        let mut end = rest.len();
+        let mut pictographic = is_extended_pictographic(first);
+        let mut pictographic_zwj = false;
+        let mut odd_regional_indicators = is_regional_indicator(first);
+
+        for (i, c) in chars {
+            if is_break(prev, c, pictographic_zwj, odd_regional_indicators) {
+                end = i;
+                break;
+            }
+            pictographic_zwj = pictographic && c == ZWJ;
+            if !is_extend(c) && c != ZWJ {
+                pictographic = is_extended_pictographic(c);
+            }
+            odd_regional_indicators = is_regional_indicator(c) && !odd_regional_indicators;
+            prev = c;
+        }
+
+        let (cluster, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some(cluster)
+    })
+}
+
+/// The number of terminal columns a single character takes: 0 for control, format and
+/// combining characters, 2 for wide East Asian characters and emoji, 1 otherwise.
+pub(crate) fn char_width(c: char) -> usize {
+    if is_control(c) {
+        0
+    } else if in_table(c, WIDE) {
+        2
+    } else if is_extend(c) || c == ZWJ || matches!(hangul(c), Some(Hangul::V | Hangul::T)) {
+        0
+    } else {
+        1
+    }
+}
+
+/// The number of terminal columns a grapheme cluster takes.
+///
+/// A cluster is as wide as its widest character, except that a pair of regional indicators (a
+/// flag) and any cluster with the emoji presentation selector U+FE0F are two columns wide.
+pub(crate) fn grapheme_width(cluster: &str) -> usize {
+    let mut chars = cluster.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    if is_regional_indicator(first) && chars.next().is_some_and(is_regional_indicator) {
+        return 2;
+    }
+    if cluster.contains(VARIATION_SELECTOR_16) {
+        return 2;
+    }
+    cluster.chars().map(char_width).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clusters(input: &str) -> Vec<&str> {
+        graphemes(input).collect()
+    }
+
+    #[test]
+    fn test_tables_are_sorted_and_disjoint() {
+        for table in [WIDE, EXTEND, SPACING_MARK, FORMAT, EXTENDED_PICTOGRAPHIC] {
+            for window in table.windows(2) {
+                assert!(window[0].0 <= window[0].1);
+                assert!(window[0].1 < window[1].0, "{:X?}", window);
+            }
+        }
+    }
+
+    #[test]
+    fn test_graphemes_ascii_and_crlf() {
+        assert_eq!(clusters("abc"), vec!["a", "b", "c"]);
+        assert_eq!(clusters("a\r\nb"), vec!["a", "\r\n", "b"]);
+        assert_eq!(clusters("\n\r"), vec!["\n", "\r"]);
+        assert!(clusters("").is_empty());
+    }
+
+    #[test]
+    fn test_graphemes_combining_marks() {
+        assert_eq!(
+            clusters("e\u{301}le\u{300}ve"),
+            vec!["e\u{301}", "l", "e\u{300}", "v", "e"]
+        );
+        assert_eq!(clusters("\u{301}a"), vec!["\u{301}", "a"]);
+        assert_eq!(clusters("नमस्ते"), vec!["न", "म", "स्", "ते"]);
+    }
+
+    #[test]
+    fn test_graphemes_emoji_sequences() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        assert_eq!(clusters(family), vec![family]);
+        assert_eq!(clusters("👍🏽👍"), vec!["👍🏽", "👍"]);
+        assert_eq!(clusters("❤\u{FE0F}x"), vec!["❤\u{FE0F}", "x"]);
+        assert_eq!(clusters("a\u{200D}b"), vec!["a\u{200D}", "b"]);
+    }
+
+    #[test]
+    fn test_graphemes_regional_indicators() {
+        assert_eq!(clusters("🇯🇵🇺🇸"), vec!["🇯🇵", "🇺🇸"]);
+        assert_eq!(clusters("🇯🇵🇺"), vec!["🇯🇵", "🇺"]);
+    }
+
+    #[test]
+    fn test_graphemes_hangul_jamo() {
+        assert_eq!(
+            clusters("\u{1100}\u{1161}\u{11A8}한"),
+            vec!["\u{1100}\u{1161}\u{11A8}", "한"]
+        );
+    }
+
+    #[test]
+    fn test_char_width() {
+        assert_eq!(char_width('a'), 1);
+        assert_eq!(char_width('世'), 2);
+        assert_eq!(char_width('Ａ'), 2);
+        assert_eq!(char_width('😀'), 2);
+        assert_eq!(char_width('\u{301}'), 0);
+        assert_eq!(char_width('\u{200B}'), 0);
+        assert_eq!(char_width('\t'), 0);
+        assert_eq!(char_width('é'), 1);
+        assert_eq!(char_width('\u{1F3FD}'), 2);
+    }
+
+    #[test]
+    fn test_grapheme_width() {
+        assert_eq!(grapheme_width("e\u{301}"), 1);
+        assert_eq!(grapheme_width("👨\u{200D}👩\u{200D}👧"), 2);
+        assert_eq!(grapheme_width("🇯🇵"), 2);
+        assert_eq!(grapheme_width("❤\u{FE0F}"), 2);
+        assert_eq!(grapheme_width("❤"), 1);
+        assert_eq!(grapheme_width("\u{1100}\u{1161}"), 2);
+        assert_eq!(grapheme_width(""), 0);
+    }
+}
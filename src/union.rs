@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the distinct elements of two collections.
+/// Elements keep the order in which they first appear, in `collection` and then in `other`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - The items to add.
+///
+/// # Returns
+/// * `Vec<T>` - The distinct items of both slices.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::union;
+///
+/// let result = union(&[2, 1, 2], &[3, 1, 4]);
+/// assert_eq!(result, vec![2, 1, 3, 4]);
+/// ```
+pub fn union<T>(collection: &[T], other: &[T]) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    let mut seen = HashSet::new();
+
+    collection
+        .iter()
+        .chain(other)
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_order() {
+        assert_eq!(union(&[3, 1], &[2, 3, 0]), vec![3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_union_removes_duplicates_within_each_slice() {
+        assert_eq!(union(&[1, 1, 1], &[2, 2]), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_union_empty() {
+        let empty: Vec<i32> = vec![];
+        assert_eq!(union(&empty, &[1, 2]), vec![1, 2]);
+        assert_eq!(union(&[1, 2], &empty), vec![1, 2]);
+        assert!(union(&empty, &empty).is_empty());
+    }
+
+    #[test]
+    fn test_union_strings() {
+        assert_eq!(union(&["a", "b"], &["b", "c"]), vec!["a", "b", "c"]);
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns the elements of two collections with distinct keys, generated by the iteratee function.
+/// The first element with each key is kept, looking at `collection` and then at `other`.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `other` - The items to add.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `Vec<T>` - One item per distinct key, in order of first appearance.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::union_by;
+///
+/// let result = union_by(&[2.1], &[1.2, 2.3], |x: &f64| x.floor() as i64);
+/// assert_eq!(result, vec![2.1, 1.2]);
+/// ```
+pub fn union_by<T, U, F>(collection: &[T], other: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let mut seen = HashSet::new();
+
+    collection
+        .iter()
+        .chain(other)
+        .filter(|item| seen.insert(iteratee(item)))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Record {
+        id: u32,
+        version: u32,
+    }
+
+    #[test]
+    fn test_union_by_keeps_first_record() {
+        let local = vec![Record { id: 1, version: 2 }, Record { id: 2, version: 1 }];
+        let remote = vec![Record { id: 2, version: 5 }, Record { id: 3, version: 1 }];
+        let result = union_by(&local, &remote, |record| record.id);
+        assert_eq!(
+            result,
+            vec![
+                Record { id: 1, version: 2 },
+                Record { id: 2, version: 1 },
+                Record { id: 3, version: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_union_by_case_insensitive() {
+        let result = union_by(&["Rust", "rust"], &["RUST", "Go"], |s| s.to_lowercase());
+        assert_eq!(result, vec!["Rust", "Go"]);
+    }
+
+    #[test]
+    fn test_union_by_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(union_by(&empty, &empty, |x| *x).is_empty());
+    }
+}
@@ -8,7 +8,7 @@
 /// due to the presence of `NaN` (Not a Number) values.
 ///
 /// However, this approach has a time complexity of O(n²) because it performs a linear search for each element to check for duplicates.
-/// Use it with caution on large collections.
+/// Use it with caution on large collections, and prefer `uniq_hashed` when `T` implements `Hash` and `Eq`.
 ///
 /// # Arguments
 /// * `collection` - A slice of items from which to extract unique elements.
@@ -54,12 +54,10 @@ pub fn uniq<T>(collection: &[T]) -> Vec<T>
 where
     T: PartialEq + Clone,
 {
-    let mut seen = Vec::with_capacity(collection.len());
-    let mut result = Vec::with_capacity(collection.len());
+    let mut result: Vec<T> = Vec::with_capacity(collection.len());
 
     for item in collection {
-        if !seen.contains(item) {
-            seen.push(item.clone());
+        if !result.contains(item) {
             result.push(item.clone());
         }
     }
@@ -8,6 +8,8 @@
 /// **Note:** This implementation requires `U` to implement `PartialEq` and `Clone`.
 /// While it doesn't leverage hashing for efficiency, it ensures compatibility with all types,
 /// including those like floating-point numbers (`f32`, `f64`) that do not implement `Eq`.
+/// Each key is compared with every key kept so far, so this is O(n²); prefer `uniq_by_hashed`
+/// when `U` implements `Hash` and `Eq`.
 ///
 /// # Arguments
 ///
@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Remove duplicate elements from a collection based on a key extracted by a provided function,
+/// preserving the order of their first occurrence, using a hash set to remember the keys already
+/// seen.
+///
+/// This returns the same result as `uniq_by`, but runs in O(n) instead of O(n²) when the key
+/// implements `Hash` and `Eq`. The iteratee is called once per element.
+///
+/// # Arguments
+/// * `collection` - A slice of items from which to extract unique elements.
+/// * `iteratee` - A function that takes a reference to an item and returns a key of type `U`.
+///
+/// # Type Parameters
+/// * `T` - The type of elements in the collection. Must implement `Clone`.
+/// * `U` - The type of the key used to determine uniqueness. Must implement `Hash` and `Eq`.
+/// * `F` - The type of the iteratee function. Must implement `Fn(&T) -> U`.
+///
+/// # Returns
+/// * `Vec<T>` - A vector containing the unique elements from the input collection, in the order they first appear.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::uniq_by_hashed;
+///
+/// #[derive(Debug, PartialEq, Clone)]
+/// struct Row {
+///     email: String,
+///     score: f64,
+/// }
+///
+/// let rows = vec![
+///     Row { email: "a@example.com".to_string(), score: 1.5 },
+///     Row { email: "b@example.com".to_string(), score: 2.0 },
+///     Row { email: "A@example.com".to_string(), score: 0.5 },
+/// ];
+///
+/// let unique = uniq_by_hashed(&rows, |row| row.email.to_lowercase());
+/// assert_eq!(unique, rows[..2].to_vec());
+/// ```
+pub fn uniq_by_hashed<T, U, F>(collection: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    U: Hash + Eq,
+    F: Fn(&T) -> U,
+{
+    let mut seen = HashSet::with_capacity(collection.len());
+
+    collection
+        .iter()
+        .filter(|item| seen.insert(iteratee(item)))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uniq_by;
+
+    #[test]
+    fn test_uniq_by_hashed_modulo() {
+        let numbers = vec![1, 2, 3, 4, 5, 6];
+        assert_eq!(uniq_by_hashed(&numbers, |x| x % 3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_uniq_by_hashed_float_items() {
+        let floats = vec![1.2, 1.8, 2.5, 3.1, 2.9];
+        assert_eq!(
+            uniq_by_hashed(&floats, |x: &f64| x.floor() as i64),
+            vec![1.2, 2.5, 3.1]
+        );
+    }
+
+    #[test]
+    fn test_uniq_by_hashed_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(uniq_by_hashed(&empty, |x| *x).is_empty());
+    }
+
+    #[test]
+    fn test_uniq_by_hashed_calls_iteratee_once_per_item() {
+        use std::cell::Cell;
+        let calls = Cell::new(0);
+        uniq_by_hashed(&[1, 1, 2, 3, 3], |x| {
+            calls.set(calls.get() + 1);
+            *x
+        });
+        assert_eq!(calls.get(), 5);
+    }
+
+    #[test]
+    fn test_uniq_by_hashed_matches_uniq_by() {
+        let collection: Vec<u32> = (0..1_000).collect();
+        assert_eq!(
+            uniq_by_hashed(&collection, |x| x % 61),
+            uniq_by(&collection, |x| x % 61)
+        );
+    }
+}
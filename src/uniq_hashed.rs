@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Remove duplicate elements from a collection, preserving the order of their first occurrence,
+/// using a hash set to remember the elements already seen.
+///
+/// This returns the same result as `uniq`, but runs in O(n) instead of O(n²), so it is the one to
+/// use on large collections whose elements implement `Hash` and `Eq`. `uniq` remains available for
+/// types that only implement `PartialEq`, such as floating-point numbers.
+///
+/// # Arguments
+/// * `collection` - A slice of items from which to extract unique elements.
+///
+/// # Type Parameters
+/// * `T` - The type of elements in the collection. Must implement `Hash`, `Eq` and `Clone`.
+///
+/// # Returns
+/// * `Vec<T>` - A vector containing the unique elements from the input collection, in the order they first appear.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::uniq_hashed;
+/// let numbers = vec![3, 1, 3, 2, 1];
+/// assert_eq!(uniq_hashed(&numbers), vec![3, 1, 2]);
+///
+/// let rows: Vec<String> = (0..100_000).map(|i| format!("row-{}", i % 10)).collect();
+/// assert_eq!(uniq_hashed(&rows).len(), 10);
+/// ```
+pub fn uniq_hashed<T>(collection: &[T]) -> Vec<T>
+where
+    T: Hash + Eq + Clone,
+{
+    let mut seen = HashSet::with_capacity(collection.len());
+
+    collection
+        .iter()
+        .filter(|item| seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uniq;
+
+    #[test]
+    fn test_uniq_hashed_preserves_order() {
+        let numbers = vec![3, 1, 2, 3, 2, 4, 1, 5];
+        assert_eq!(uniq_hashed(&numbers), vec![3, 1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn test_uniq_hashed_strings() {
+        let strings = vec!["apple", "banana", "apple", "cherry", "banana"];
+        assert_eq!(uniq_hashed(&strings), vec!["apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn test_uniq_hashed_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(uniq_hashed(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_uniq_hashed_all_duplicates() {
+        assert_eq!(uniq_hashed(&[7, 7, 7]), vec![7]);
+    }
+
+    #[test]
+    fn test_uniq_hashed_matches_uniq() {
+        let collection: Vec<u32> = (0..1_000).map(|i| (i * 7919) % 97).collect();
+        assert_eq!(uniq_hashed(&collection), uniq(&collection));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Collects all unique keys from one or more maps into a single vector.
 ///
 /// Iterates over each map and collects all unique keys into a single vector.
@@ -27,15 +29,16 @@
 /// assert!(result.contains(&"b"));
 /// assert!(result.contains(&"c"));
 /// ```
-pub fn uniq_keys<K, V>(maps: &[&std::collections::HashMap<K, V>]) -> Vec<K>
+pub fn uniq_keys<M, K>(maps: &[&M]) -> Vec<K>
 where
+    M: MapLike<Key = K>,
     K: Clone + std::cmp::Eq + std::hash::Hash,
 {
     let mut seen = std::collections::HashSet::new();
     let mut result = Vec::new();
 
     for map in maps {
-        for key in map.keys() {
+        for (key, _) in map.iter() {
             if seen.insert(key.clone()) {
                 result.push(key.clone());
             }
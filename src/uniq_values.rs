@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Collects all unique values from one or more maps into a single vector.
 ///
 /// Iterates over each map and collects all unique values into a single vector.
@@ -27,15 +29,16 @@
 /// assert!(result.contains(&2));
 /// assert!(result.contains(&3));
 /// ```
-pub fn uniq_values<K, V>(maps: &[&std::collections::HashMap<K, V>]) -> Vec<V>
+pub fn uniq_values<M, V>(maps: &[&M]) -> Vec<V>
 where
+    M: MapLike<Value = V>,
     V: Clone + std::cmp::Eq + std::hash::Hash,
 {
     let mut seen = std::collections::HashSet::new();
     let mut result = Vec::new();
 
     for map in maps {
-        for value in map.values() {
+        for (_, value) in map.iter() {
             if seen.insert(value.clone()) {
                 result.push(value.clone());
             }
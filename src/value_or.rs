@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Returns a value from a map for a given key, or a fallback value if the key doesn't exist.
 ///
 /// # Arguments
@@ -19,9 +21,9 @@
 /// assert_eq!(value_or(&map, &"a", 42), 1);
 /// assert_eq!(value_or(&map, &"b", 42), 42);
 /// ```
-pub fn value_or<K, V>(map: &std::collections::HashMap<K, V>, key: &K, fallback: V) -> V
+pub fn value_or<M, K, V>(map: &M, key: &K, fallback: V) -> V
 where
-    K: std::cmp::Eq + std::hash::Hash,
+    M: MapLike<Key = K, Value = V>,
     V: Clone,
 {
     map.get(key).cloned().unwrap_or(fallback)
@@ -0,0 +1,53 @@
+use crate::value_range_by;
+
+/// Calculate the range (the maximum minus the minimum) of a collection.
+/// NaN values are ignored.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The range, or None if the collection has no comparable values
+///
+/// # Examples
+/// ```rust
+/// use lowdash::value_range;
+///
+/// let numbers = vec![3, 9, 1, 4];
+/// assert_eq!(value_range(&numbers), Some(8.0));
+///
+/// let empty: Vec<i32> = vec![];
+/// assert_eq!(value_range(&empty), None);
+/// ```
+pub fn value_range<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    value_range_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_range_integers() {
+        assert_eq!(value_range(&[-5, 10, 0]), Some(15.0));
+    }
+
+    #[test]
+    fn test_value_range_floats() {
+        assert_eq!(value_range(&[0.5, 2.0, 1.25]), Some(1.5));
+    }
+
+    #[test]
+    fn test_value_range_all_nan() {
+        assert_eq!(value_range(&[f64::NAN, f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_value_range_empty() {
+        let empty: Vec<u8> = vec![];
+        assert_eq!(value_range(&empty), None);
+    }
+}
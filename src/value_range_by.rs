@@ -0,0 +1,69 @@
+/// Calculate the range (the maximum minus the minimum) of the values produced by applying an
+/// iteratee to each element of a collection.
+/// NaN values are ignored.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The range, or None if the collection has no comparable values
+///
+/// # Examples
+/// ```rust
+/// use lowdash::value_range_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people = vec![Person { age: 30 }, Person { age: 18 }, Person { age: 45 }];
+/// assert_eq!(value_range_by(&people, |p| p.age as f64), Some(27.0));
+/// ```
+pub fn value_range_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    let mut bounds: Option<(f64, f64)> = None;
+    for value in collection.iter().map(iteratee) {
+        if value.is_nan() {
+            continue;
+        }
+        bounds = Some(match bounds {
+            Some((min, max)) => (min.min(value), max.max(value)),
+            None => (value, value),
+        });
+    }
+
+    bounds.map(|(min, max)| max - min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_range_by_selects_field() {
+        let points = vec![(2.5, 0), (-1.0, 40), (7.0, 15)];
+        assert_eq!(value_range_by(&points, |&(x, _)| x), Some(8.0));
+        assert_eq!(value_range_by(&points, |&(_, y)| y as f64), Some(40.0));
+    }
+
+    #[test]
+    fn test_value_range_by_ignores_nan() {
+        let readings = vec![f64::NAN, 1.0, 4.0];
+        assert_eq!(value_range_by(&readings, |&r| r), Some(3.0));
+    }
+
+    #[test]
+    fn test_value_range_by_all_nan() {
+        let readings = vec![f64::NAN, f64::NAN];
+        assert_eq!(value_range_by(&readings, |&r| r), None);
+    }
+
+    #[test]
+    fn test_value_range_by_empty() {
+        let empty: Vec<(f64, i32)> = vec![];
+        assert_eq!(value_range_by(&empty, |&(x, _)| x), None);
+    }
+}
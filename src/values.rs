@@ -1,3 +1,5 @@
+use crate::MapLike;
+
 /// Collects all values from one or more maps into a single vector.
 ///
 /// Iterates over each map and collects all values into a single vector.
@@ -28,13 +30,14 @@
 /// assert!(result.contains(&3));
 /// assert!(result.contains(&4));
 /// ```
-pub fn values<K, V>(maps: &[&std::collections::HashMap<K, V>]) -> Vec<V>
+pub fn values<M, V>(maps: &[&M]) -> Vec<V>
 where
+    M: MapLike<Value = V>,
     V: Clone,
 {
     let mut result = Vec::new();
     for map in maps {
-        for value in map.values() {
+        for (_, value) in map.iter() {
             result.push(value.clone());
         }
     }
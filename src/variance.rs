@@ -0,0 +1,67 @@
+use crate::variance_by;
+
+/// Calculate the population variance of a collection.
+/// The population variance is the mean of the squared differences from the mean.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of numeric values
+///
+/// # Returns
+/// * `Option<f64>` - The population variance, or None if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::variance;
+///
+/// let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+/// let result = variance(&numbers);
+/// assert!((result.unwrap() - 4.0).abs() < 1e-12);
+/// ```
+pub fn variance<T>(collection: &[T]) -> Option<f64>
+where
+    T: Copy + Into<f64>,
+{
+    variance_by(collection, |x| (*x).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variance_integers() {
+        let numbers = vec![2, 4, 4, 4, 5, 5, 7, 9];
+        let result = variance(&numbers);
+        assert!((result.unwrap() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_variance_floats() {
+        let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let result = variance(&numbers);
+        assert!((result.unwrap() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_variance_is_shift_invariant() {
+        let numbers: Vec<f64> = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .iter()
+            .map(|x| x + 1e9)
+            .collect();
+        let result = variance(&numbers);
+        assert!((result.unwrap() - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_variance_empty() {
+        let numbers: Vec<f64> = vec![];
+        assert_eq!(variance(&numbers), None);
+    }
+
+    #[test]
+    fn test_variance_single() {
+        assert_eq!(variance(&[5]), Some(0.0));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::moments::Moments;
+
+/// Calculate the population variance of the values produced by applying an iteratee to each element
+/// of a collection.
+/// The population variance is the mean of the squared differences from the mean.
+/// The values are accumulated in a single pass with Welford's algorithm, which stays accurate when
+/// the values are large and close together.
+///
+/// # Arguments
+/// * `collection` - A slice of items
+/// * `iteratee` - A function that takes a reference to an item and returns the value to measure
+///
+/// # Returns
+/// * `Option<f64>` - The population variance, or None if the collection is empty
+///
+/// # Examples
+/// ```rust
+/// use lowdash::variance_by;
+///
+/// struct Person {
+///     age: u32,
+/// }
+///
+/// let people: Vec<Person> = [2, 4, 4, 4, 5, 5, 7, 9]
+///     .iter()
+///     .map(|&age| Person { age })
+///     .collect();
+/// let result = variance_by(&people, |p| p.age as f64);
+/// assert!((result.unwrap() - 4.0).abs() < 1e-12);
+/// ```
+pub fn variance_by<T, F>(collection: &[T], iteratee: F) -> Option<f64>
+where
+    F: Fn(&T) -> f64,
+{
+    Moments::from_values(collection.iter().map(iteratee)).variance()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variance_by_selects_field() {
+        let points = vec![(1, 10), (2, 20), (3, 30), (4, 40)];
+
+        let variance_x = variance_by(&points, |&(x, _)| x as f64).unwrap();
+        assert!((variance_x - 1.25).abs() < 1e-12);
+
+        // Scaling the values by 10 scales the variance by 100
+        let variance_y = variance_by(&points, |&(_, y)| y as f64).unwrap();
+        assert!((variance_y - 125.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_variance_by_derived_value() {
+        let readings = vec![(10.0, 9.0), (10.0, 11.0), (20.0, 19.0), (20.0, 21.0)];
+        let errors = variance_by(&readings, |&(expected, actual)| actual - expected).unwrap();
+        assert!((errors - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_variance_by_empty() {
+        let empty: Vec<(i32, i32)> = vec![];
+        assert_eq!(variance_by(&empty, |&(x, _)| x as f64), None);
+    }
+
+    #[test]
+    fn test_variance_by_single_element() {
+        let points = vec![(5, 10)];
+        assert_eq!(variance_by(&points, |&(x, _)| x as f64), Some(0.0));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::common::ToF64;
+use crate::moments::compensated_sum;
+
+/// Calculates the weighted arithmetic mean of a collection of numbers.
+/// Each value is multiplied by the weight at the same position, and the sum of the products is
+/// divided by the sum of the weights.
+///
+/// # Arguments
+/// * `collection` - A slice of numbers.
+/// * `weights` - A slice of weights, one per value.
+///
+/// # Returns
+/// * `Option<f64>` - The weighted mean, or None if the collection is empty, the slices have
+///   different lengths, or the weights sum to zero.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::weighted_mean;
+///
+/// let grades = vec![90, 80, 70];
+/// let credits = vec![3, 2, 1];
+/// let result = weighted_mean(&grades, &credits).unwrap();
+/// assert!((result - 83.333333333).abs() < 1e-6);
+///
+/// assert_eq!(weighted_mean(&[1, 2], &[1]), None);
+/// ```
+pub fn weighted_mean<T, W>(collection: &[T], weights: &[W]) -> Option<f64>
+where
+    T: ToF64,
+    W: ToF64,
+{
+    if collection.is_empty() || collection.len() != weights.len() {
+        return None;
+    }
+
+    let total_weight = compensated_sum(weights.iter().map(|&w| w.to_f64()));
+    if total_weight == 0.0 {
+        return None;
+    }
+
+    let weighted_sum = compensated_sum(
+        collection
+            .iter()
+            .zip(weights)
+            .map(|(&x, &w)| x.to_f64() * w.to_f64()),
+    );
+
+    Some(weighted_sum / total_weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_mean_basic() {
+        assert_eq!(weighted_mean(&[1.0, 3.0], &[3.0, 1.0]), Some(1.5));
+    }
+
+    #[test]
+    fn test_weighted_mean_equal_weights_is_mean() {
+        let numbers: Vec<i32> = (1..=500).collect();
+        let weights = vec![2u8; 500];
+        assert_eq!(weighted_mean(&numbers, &weights), Some(250.5));
+    }
+
+    #[test]
+    fn test_weighted_mean_zero_weight_ignores_value() {
+        assert_eq!(weighted_mean(&[10, 1000], &[1, 0]), Some(10.0));
+    }
+
+    #[test]
+    fn test_weighted_mean_empty() {
+        let empty: Vec<f64> = vec![];
+        assert_eq!(weighted_mean(&empty, &empty), None);
+    }
+
+    #[test]
+    fn test_weighted_mean_mismatched_lengths() {
+        assert_eq!(weighted_mean(&[1, 2, 3], &[1, 1]), None);
+    }
+
+    #[test]
+    fn test_weighted_mean_zero_total_weight() {
+        assert_eq!(weighted_mean(&[1, 2], &[0, 0]), None);
+        assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, -1.0]), None);
+    }
+}
@@ -0,0 +1,101 @@
+/// Collects the windows of `size` consecutive elements of a collection, starting a new window
+/// every `step` elements.
+///
+/// Only full windows are returned: a collection shorter than `size` has none. With `step` equal
+/// to `size` the windows are the full chunks of `chunk`; with a smaller step they overlap, and
+/// with a larger one elements between windows are skipped. Use `windows_partial` to also keep
+/// the shorter windows at the end.
+///
+/// **Panics:**
+/// Panics if `size` or `step` is 0.
+///
+/// # Arguments
+/// * `collection` - A slice of items to slide over.
+/// * `size` - The number of elements in each window.
+/// * `step` - How many elements each window starts after the previous one.
+///
+/// # Returns
+/// * `Vec<Vec<T>>` - The windows, in order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::windows;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// assert_eq!(windows(&numbers, 3, 1), vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+/// assert_eq!(windows(&numbers, 2, 2), vec![vec![1, 2], vec![3, 4]]);
+/// assert_eq!(windows(&numbers, 1, 3), vec![vec![1], vec![4]]);
+/// assert!(windows(&numbers, 6, 1).is_empty());
+/// ```
+pub fn windows<T>(collection: &[T], size: usize, step: usize) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    if size == 0 {
+        panic!("Window size must be greater than 0");
+    }
+    if step == 0 {
+        panic!("Window step must be greater than 0");
+    }
+
+    if collection.len() < size {
+        return Vec::new();
+    }
+    (0..=collection.len() - size)
+        .step_by(step)
+        .map(|start| collection[start..start + size].to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_overlapping() {
+        let letters = vec!['a', 'b', 'c', 'd'];
+        assert_eq!(
+            windows(&letters, 2, 1),
+            vec![vec!['a', 'b'], vec!['b', 'c'], vec!['c', 'd']]
+        );
+    }
+
+    #[test]
+    fn test_windows_step_equals_size_drops_tail() {
+        let numbers = vec![1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(windows(&numbers, 3, 3), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_windows_step_larger_than_size() {
+        let numbers: Vec<i32> = (0..10).collect();
+        assert_eq!(
+            windows(&numbers, 2, 4),
+            vec![vec![0, 1], vec![4, 5], vec![8, 9]]
+        );
+    }
+
+    #[test]
+    fn test_windows_exact_fit() {
+        assert_eq!(windows(&[1, 2, 3], 3, 5), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_windows_empty_and_short() {
+        let empty: Vec<i32> = vec![];
+        assert!(windows(&empty, 1, 1).is_empty());
+        assert!(windows(&[1, 2], 3, 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be greater than 0")]
+    fn test_windows_zero_size() {
+        windows(&[1, 2, 3], 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window step must be greater than 0")]
+    fn test_windows_zero_step() {
+        windows(&[1, 2, 3], 1, 0);
+    }
+}
@@ -0,0 +1,92 @@
+/// Collects the windows of up to `size` consecutive elements of a collection, starting a new
+/// window every `step` elements, including the shorter windows at the end.
+///
+/// Unlike `windows`, a window is returned for every start position inside the collection, so the
+/// last windows may hold fewer than `size` elements and no element is lost when `step` is at most
+/// `size`.
+///
+/// **Panics:**
+/// Panics if `size` or `step` is 0.
+///
+/// # Arguments
+/// * `collection` - A slice of items to slide over.
+/// * `size` - The largest number of elements in a window.
+/// * `step` - How many elements each window starts after the previous one.
+///
+/// # Returns
+/// * `Vec<Vec<T>>` - The windows, in order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::windows_partial;
+///
+/// let numbers = vec![1, 2, 3, 4, 5];
+/// assert_eq!(
+///     windows_partial(&numbers, 3, 2),
+///     vec![vec![1, 2, 3], vec![3, 4, 5], vec![5]]
+/// );
+/// assert_eq!(
+///     windows_partial(&numbers, 3, 1),
+///     vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5], vec![4, 5], vec![5]]
+/// );
+/// ```
+pub fn windows_partial<T>(collection: &[T], size: usize, step: usize) -> Vec<Vec<T>>
+where
+    T: Clone,
+{
+    if size == 0 {
+        panic!("Window size must be greater than 0");
+    }
+    if step == 0 {
+        panic!("Window step must be greater than 0");
+    }
+
+    (0..collection.len())
+        .step_by(step)
+        .map(|start| collection[start..collection.len().min(start + size)].to_vec())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk;
+
+    #[test]
+    fn test_windows_partial_step_equals_size_matches_chunk() {
+        let numbers: Vec<i32> = (0..11).collect();
+        assert_eq!(windows_partial(&numbers, 4, 4), chunk(&numbers, 4));
+    }
+
+    #[test]
+    fn test_windows_partial_short_collection() {
+        assert_eq!(windows_partial(&[1, 2], 5, 1), vec![vec![1, 2], vec![2]]);
+    }
+
+    #[test]
+    fn test_windows_partial_large_step() {
+        let numbers: Vec<i32> = (0..7).collect();
+        assert_eq!(
+            windows_partial(&numbers, 2, 3),
+            vec![vec![0, 1], vec![3, 4], vec![6]]
+        );
+    }
+
+    #[test]
+    fn test_windows_partial_empty() {
+        let empty: Vec<i32> = vec![];
+        assert!(windows_partial(&empty, 2, 1).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be greater than 0")]
+    fn test_windows_partial_zero_size() {
+        windows_partial(&[1], 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Window step must be greater than 0")]
+    fn test_windows_partial_zero_step() {
+        windows_partial(&[1], 1, 0);
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns a collection without the given values.
+/// Unlike `difference`, duplicates of the remaining elements are kept, so this is a filter that
+/// preserves the collection as it is apart from the removed values.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `values` - The values to remove.
+///
+/// # Returns
+/// * `Vec<T>` - The items of `collection` that are not in `values`, in their original order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::without;
+///
+/// let result = without(&[2, 1, 2, 3], &[1, 2]);
+/// assert_eq!(result, vec![3]);
+///
+/// let result = without(&["a", "b", "a", "c"], &["c"]);
+/// assert_eq!(result, vec!["a", "b", "a"]);
+/// ```
+pub fn without<T>(collection: &[T], values: &[T]) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    let values: HashSet<&T> = values.iter().collect();
+
+    collection
+        .iter()
+        .filter(|item| !values.contains(item))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_keeps_duplicates() {
+        assert_eq!(without(&[1, 1, 2, 3, 3], &[2]), vec![1, 1, 3, 3]);
+    }
+
+    #[test]
+    fn test_without_no_values() {
+        assert_eq!(without(&[1, 2, 1], &[]), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn test_without_all_values() {
+        assert!(without(&[1, 2, 1], &[1, 2]).is_empty());
+    }
+
+    #[test]
+    fn test_without_strings() {
+        let words = vec!["the".to_string(), "cat".to_string(), "the".to_string()];
+        assert_eq!(without(&words, &["the".to_string()]), vec!["cat"]);
+    }
+}
@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Returns a collection without the elements whose key, generated by the iteratee function, is the
+/// key of one of the given values.
+/// Duplicates of the remaining elements are kept.
+///
+/// # Arguments
+/// * `collection` - A slice of items.
+/// * `values` - The values whose keys are removed.
+/// * `iteratee` - A function that takes an item and returns the key used for comparison.
+///
+/// # Returns
+/// * `Vec<T>` - The items of `collection` whose keys are not keys of `values`, in their original order.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::without_by;
+///
+/// let result = without_by(&["Apple", "banana", "APPLE", "cherry"], &["apple"], |s| s.to_lowercase());
+/// assert_eq!(result, vec!["banana", "cherry"]);
+/// ```
+pub fn without_by<T, U, F>(collection: &[T], values: &[T], iteratee: F) -> Vec<T>
+where
+    T: Clone,
+    U: Eq + Hash,
+    F: Fn(&T) -> U,
+{
+    let values: HashSet<U> = values.iter().map(&iteratee).collect();
+
+    collection
+        .iter()
+        .filter(|item| !values.contains(&iteratee(item)))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_without_by_keeps_duplicates() {
+        assert_eq!(without_by(&[1, 2, 3, 4, 5], &[0], |x| x % 2), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn test_without_by_field() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Task {
+            id: u32,
+            done: bool,
+        }
+
+        let tasks = vec![
+            Task { id: 1, done: false },
+            Task { id: 2, done: true },
+            Task { id: 3, done: false },
+        ];
+        let finished = vec![Task { id: 2, done: true }];
+        assert_eq!(
+            without_by(&tasks, &finished, |task| task.id),
+            vec![Task { id: 1, done: false }, Task { id: 3, done: false }]
+        );
+    }
+
+    #[test]
+    fn test_without_by_empty() {
+        let empty: Vec<i32> = vec![];
+        assert_eq!(without_by(&[1, 1], &empty, |x| *x), vec![1, 1]);
+        assert!(without_by(&empty, &[1], |x| *x).is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+/// How digits are grouped with the letters around them when splitting words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitGrouping {
+    /// Runs of digits form words of their own: `"HTTP2Server"` splits into
+    /// `HTTP`, `2`, `Server`.
+    #[default]
+    Separate,
+    /// Digits stay attached to the word they follow: `"HTTP2Server"` splits into
+    /// `HTTP2`, `Server`.
+    Attached,
+}
+
+/// What happens to an apostrophe between two letters, as in `"don't"` or `"it’s"`.
+///
+/// Both the ASCII apostrophe `'` and the typographic `’` are recognised. Apostrophes that are not
+/// surrounded by letters always separate words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Apostrophes {
+    /// The apostrophe is kept inside the word: `"don't"`.
+    #[default]
+    Keep,
+    /// The apostrophe is dropped and the word continues: `"dont"`.
+    Remove,
+    /// The apostrophe separates words: `"don"`, `"t"`.
+    Split,
+}
+
+/// Options controlling how [`words_with`](crate::words_with) and the `_with` case converters
+/// split a string into words.
+///
+/// The default options reproduce [`words`](crate::words): words break at separators, at
+/// lower-to-upper case changes, before the last capital of an acronym followed by a lowercase
+/// letter (`"XMLHttp"`), and between letters and digits.
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{words_with, DigitGrouping, WordOptions};
+///
+/// let options = WordOptions {
+///     acronyms: vec!["OAuth".to_string()],
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(words_with("getOAuthToken", &options), vec!["get", "OAuth", "Token"]);
+/// assert_eq!(words_with("HTTP2Server", &options), vec!["HTTP2", "Server"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WordOptions {
+    /// Tokens that are always kept as a single word when one starts at a word boundary, such as
+    /// `"OAuth"` or `"iOS"`. Matching is case-sensitive, and a token is not used if a lowercase
+    /// letter follows it.
+    pub acronyms: Vec<String>,
+    /// How digits are grouped with neighbouring letters.
+    pub digits: DigitGrouping,
+    /// What happens to apostrophes inside words.
+    pub apostrophes: Apostrophes,
+    /// The characters that separate words. `None` treats every character that is neither
+    /// alphanumeric nor an apostrophe as a separator; with `Some`, only the listed characters
+    /// separate words and other symbols are kept inside them.
+    pub separators: Option<Vec<char>>,
+}
+
+impl WordOptions {
+    /// The options used by the plain case converters: the defaults, with apostrophes removed so
+    /// that `"don't"` becomes one word without punctuation.
+    pub(crate) fn for_case() -> Self {
+        WordOptions {
+            apostrophes: Apostrophes::Remove,
+            ..WordOptions::default()
+        }
+    }
+}
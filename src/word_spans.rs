@@ -0,0 +1,74 @@
+use crate::{word_spans_with, Apostrophes, DigitGrouping, WordOptions};
+
+static DEFAULT_OPTIONS: WordOptions = WordOptions {
+    acronyms: Vec::new(),
+    digits: DigitGrouping::Separate,
+    apostrophes: Apostrophes::Keep,
+    separators: None,
+};
+
+/// Splits a string into words, yielding slices of the input instead of allocating a `String` per
+/// word.
+///
+/// Words are split exactly as by [`words`](crate::words), so collecting the iterator gives the
+/// same words without copying them. Use [`word_spans_with`] to configure the splitting.
+///
+/// # Arguments
+/// * `str_input` - The input string to split into words
+///
+/// # Returns
+/// * `impl Iterator<Item = &str>` - An iterator over the words, as slices of `str_input`
+///
+/// # Examples
+/// ```rust
+/// use lowdash::word_spans;
+///
+/// let spans: Vec<&str> = word_spans("Int8Value").collect();
+/// assert_eq!(spans, vec!["Int", "8", "Value"]);
+///
+/// assert_eq!(word_spans("fooBarBazHello").count(), 4);
+/// assert_eq!(word_spans("__FOO_BAR__").last(), Some("BAR"));
+/// ```
+pub fn word_spans(str_input: &str) -> impl Iterator<Item = &str> {
+    word_spans_with(str_input, &DEFAULT_OPTIONS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options_match() {
+        assert_eq!(DEFAULT_OPTIONS, WordOptions::default());
+    }
+
+    #[test]
+    fn test_matches_words() {
+        for input in [
+            "",
+            "hello world",
+            "XMLHttpRequest",
+            "hello@world#2023",
+            "version2Release10",
+            "_startMiddle_end_",
+            "don't stop",
+            "こんにちは世界",
+        ] {
+            assert_eq!(
+                word_spans(input).collect::<Vec<_>>(),
+                crate::words(input),
+                "input: {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_lazy() {
+        let mut spans = word_spans("alpha beta gamma");
+        assert_eq!(spans.next(), Some("alpha"));
+        assert_eq!(spans.next(), Some("beta"));
+        assert_eq!(spans.next(), Some("gamma"));
+        assert_eq!(spans.next(), None);
+    }
+}
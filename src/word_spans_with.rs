@@ -0,0 +1,225 @@
+use crate::{Apostrophes, DigitGrouping, WordOptions};
+
+/// Splits a string into words using the given [`WordOptions`], yielding slices of the input
+/// instead of allocating a `String` per word.
+///
+/// Words are split exactly as by [`words_with`](crate::words_with). Because the words borrow from
+/// the input, an apostrophe inside a word is part of the slice even with
+/// [`Apostrophes::Remove`]; callers that write the words somewhere can skip it, as the case
+/// converters do.
+///
+/// # Arguments
+/// * `str_input` - The input string to split into words
+/// * `options` - How acronyms, digits, apostrophes and separators are handled
+///
+/// # Returns
+/// * `impl Iterator<Item = &str>` - An iterator over the words, as slices of `str_input`
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{word_spans_with, DigitGrouping, WordOptions};
+///
+/// let options = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// let spans: Vec<&str> = word_spans_with("parseHTTP2Response", &options).collect();
+/// assert_eq!(spans, vec!["parse", "HTTP2", "Response"]);
+/// ```
+pub fn word_spans_with<'a>(
+    str_input: &'a str,
+    options: &'a WordOptions,
+) -> impl Iterator<Item = &'a str> + 'a {
+    WordSpans {
+        input: str_input,
+        position: 0,
+        options,
+    }
+}
+
+/// The iterator behind [`word_spans_with`]. It scans the input once, keeping only the byte
+/// position where the next word may start.
+struct WordSpans<'a> {
+    input: &'a str,
+    position: usize,
+    options: &'a WordOptions,
+}
+
+impl<'a> Iterator for WordSpans<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let input = self.input;
+        let mut start: Option<usize> = None;
+        let mut prev = '\0';
+
+        while let Some(c) = input[self.position..].chars().next() {
+            let i = self.position;
+            let after = i + c.len_utf8();
+            let next = input[after..].chars().next();
+
+            let separates = if is_separator(c, self.options) {
+                true
+            } else if is_apostrophe(c) {
+                let inside_word = start.is_some()
+                    && prev.is_alphabetic()
+                    && next.is_some_and(|next| next.is_alphabetic());
+                !inside_word || self.options.apostrophes == Apostrophes::Split
+            } else {
+                false
+            };
+
+            if separates {
+                self.position = after;
+                if let Some(start) = start {
+                    return Some(&input[start..i]);
+                }
+                continue;
+            }
+
+            match start {
+                None => {
+                    if let Some(len) = match_acronym(&input[i..], self.options) {
+                        self.position = i + len;
+                        return Some(&input[i..i + len]);
+                    }
+                    start = Some(i);
+                }
+                Some(start) if !is_apostrophe(c) && is_boundary(prev, c, next, self.options) => {
+                    return Some(&input[start..i]);
+                }
+                Some(_) => {}
+            }
+
+            prev = c;
+            self.position = after;
+        }
+
+        start.map(|start| &input[start..])
+    }
+}
+
+pub(crate) fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+fn is_separator(c: char, options: &WordOptions) -> bool {
+    match &options.separators {
+        Some(separators) => separators.contains(&c),
+        None => !c.is_alphanumeric() && !is_apostrophe(c),
+    }
+}
+
+/// Whether a new word starts at `c`, given the character before it in the current word and the
+/// one after it in the input.
+fn is_boundary(prev: char, c: char, next: Option<char>, options: &WordOptions) -> bool {
+    if is_apostrophe(prev) {
+        return false;
+    }
+
+    if options.digits == DigitGrouping::Separate
+        && ((c.is_numeric() && prev.is_alphabetic()) || (c.is_alphabetic() && prev.is_numeric()))
+    {
+        return true;
+    }
+
+    if c.is_uppercase() {
+        if prev.is_alphanumeric() && !prev.is_uppercase() {
+            return true;
+        }
+        if prev.is_uppercase() {
+            return next.is_some_and(|next| next.is_lowercase());
+        }
+    }
+
+    false
+}
+
+/// The byte length of the longest known acronym at the start of `rest`, if it is not followed by
+/// a lowercase letter.
+fn match_acronym(rest: &str, options: &WordOptions) -> Option<usize> {
+    options
+        .acronyms
+        .iter()
+        .filter(|acronym| {
+            !acronym.is_empty()
+                && rest.starts_with(acronym.as_str())
+                && !rest[acronym.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(|next| next.is_lowercase())
+        })
+        .map(|acronym| acronym.len())
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans<'a>(input: &'a str, options: &'a WordOptions) -> Vec<&'a str> {
+        word_spans_with(input, options).collect()
+    }
+
+    #[test]
+    fn test_spans_borrow_from_input() {
+        let input = "fooBar baz";
+        let options = WordOptions::default();
+        for span in word_spans_with(input, &options) {
+            let offset = span.as_ptr() as usize - input.as_ptr() as usize;
+            assert_eq!(&input[offset..offset + span.len()], span);
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let options = WordOptions {
+            acronyms: vec!["OAuth".to_string()],
+            digits: DigitGrouping::Attached,
+            apostrophes: Apostrophes::Split,
+            separators: Some(vec![' ', '_']),
+        };
+        assert_eq!(
+            spans("getOAuthToken v2", &options),
+            vec!["get", "OAuth", "Token", "v2"]
+        );
+        assert_eq!(
+            spans("don't stop_me-now", &options),
+            vec!["don", "t", "stop", "me-now"]
+        );
+        assert_eq!(spans("HTTP2Server", &options), vec!["HTTP2", "Server"]);
+    }
+
+    #[test]
+    fn test_default_options() {
+        let options = WordOptions::default();
+        assert_eq!(
+            spans("XMLHttpRequest", &options),
+            vec!["XML", "Http", "Request"]
+        );
+        assert_eq!(spans("'quoted' it’s", &options), vec!["quoted", "it’s"]);
+        assert_eq!(
+            spans("こんにちは世界Hello", &options),
+            vec!["こんにちは世界", "Hello"]
+        );
+        assert!(spans("", &options).is_empty());
+    }
+
+    #[test]
+    fn test_remove_keeps_apostrophe_in_span() {
+        let options = WordOptions {
+            apostrophes: Apostrophes::Remove,
+            ..WordOptions::default()
+        };
+        assert_eq!(spans("don't stop", &options), vec!["don't", "stop"]);
+    }
+
+    #[test]
+    fn test_multibyte_acronym() {
+        let options = WordOptions {
+            acronyms: vec!["ÆON".to_string()],
+            ..WordOptions::default()
+        };
+        assert_eq!(spans("theÆONFlux", &options), vec!["the", "ÆON", "Flux"]);
+    }
+}
@@ -0,0 +1,226 @@
+/// Wraps text so that no line is longer than `width` characters.
+///
+/// Lines break between words, and inside a word only at hyphenation points: after a hyphen that
+/// joins two parts of a word (`"well-known"`) or at a soft hyphen (`U+00AD`), which is shown as
+/// `-` when the line breaks there and removed otherwise. Words longer than `width` that cannot be
+/// hyphenated are split at `width` characters. Existing newlines are kept, including blank lines,
+/// and runs of whitespace between words become a single space. Widths are counted in `char`s and a
+/// `width` of 0 is treated as 1.
+///
+/// # Arguments
+/// * `text` - The text to wrap
+/// * `width` - The maximum number of characters per line
+///
+/// # Returns
+/// * `String` - The wrapped text, with lines separated by `\n`
+///
+/// # Examples
+/// ```rust
+/// use lowdash::word_wrap;
+///
+/// assert_eq!(
+///     word_wrap("The quick brown fox jumps over the lazy dog", 10),
+///     "The quick\nbrown fox\njumps over\nthe lazy\ndog"
+/// );
+/// assert_eq!(word_wrap("a well-known fact", 8), "a well-\nknown\nfact");
+/// assert_eq!(word_wrap("extra\u{AD}ordinary", 8), "extra-\nordinary");
+/// assert_eq!(word_wrap("abcdefghij", 4), "abcd\nefgh\nij");
+/// ```
+pub fn word_wrap(text: &str, width: usize) -> String {
+    let width = width.max(1);
+    let mut lines: Vec<String> = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let first_line = lines.len();
+        let mut current = String::new();
+        let mut current_len = 0;
+
+        for word in paragraph.split_whitespace() {
+            let mut segments = hyphenation_segments(word);
+            let mut i = 0;
+
+            while i < segments.len() {
+                let separator = usize::from(current_len > 0);
+                let available = width.saturating_sub(current_len + separator);
+
+                // The most segments that fit, breaking after the last one unless the word ends.
+                let mut fitting = None;
+                let mut text_len = 0;
+                for j in i..segments.len() {
+                    text_len += segments[j].0.chars().count();
+                    let is_last = j + 1 == segments.len();
+                    let rendered = text_len + usize::from(!is_last && segments[j].1);
+                    if rendered > available {
+                        break;
+                    }
+                    fitting = Some(j);
+                }
+
+                match fitting {
+                    Some(j) => {
+                        if separator == 1 {
+                            current.push(' ');
+                        }
+                        for segment in &segments[i..=j] {
+                            current.push_str(segment.0);
+                        }
+                        current_len += separator + text_len_of(&segments[i..=j]);
+                        i = j + 1;
+                        if i < segments.len() {
+                            if segments[j].1 {
+                                current.push('-');
+                            }
+                            lines.push(std::mem::take(&mut current));
+                            current_len = 0;
+                        }
+                    }
+                    None if current_len > 0 => {
+                        lines.push(std::mem::take(&mut current));
+                        current_len = 0;
+                    }
+                    None => {
+                        let (segment, soft) = segments[i];
+                        match segment.char_indices().nth(width) {
+                            Some((split, _)) => {
+                                lines.push(segment[..split].to_string());
+                                segments[i] = (&segment[split..], soft);
+                            }
+                            // The segment fits on its own, only its soft hyphen does not.
+                            None => segments[i].1 = false,
+                        }
+                    }
+                }
+            }
+        }
+
+        if current_len > 0 || lines.len() == first_line {
+            lines.push(current);
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Splits a word at its hyphenation points. Each segment is paired with whether a `-` has to be
+/// added when the line breaks after it, which is the case for soft hyphens only.
+fn hyphenation_segments(word: &str) -> Vec<(&str, bool)> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut prev: Option<char> = None;
+    let mut chars = word.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, next)| next);
+        if c == '\u{AD}' {
+            if start < i {
+                segments.push((&word[start..i], true));
+            }
+            start = i + c.len_utf8();
+        } else if c == '-'
+            && prev.is_some_and(|prev| prev != '-' && prev != '\u{AD}')
+            && next.is_some_and(|next| next != '-' && next != '\u{AD}')
+        {
+            let end = i + c.len_utf8();
+            segments.push((&word[start..end], false));
+            start = end;
+        }
+        prev = Some(c);
+    }
+
+    if start < word.len() {
+        segments.push((&word[start..], false));
+    }
+    segments
+}
+
+fn text_len_of(segments: &[(&str, bool)]) -> usize {
+    segments
+        .iter()
+        .map(|segment| segment.0.chars().count())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wraps_at_word_boundaries() {
+        assert_eq!(
+            word_wrap("The quick brown fox jumps over the lazy dog", 10),
+            "The quick\nbrown fox\njumps over\nthe lazy\ndog"
+        );
+    }
+
+    #[test]
+    fn test_text_shorter_than_width() {
+        assert_eq!(word_wrap("hello world", 20), "hello world");
+        assert_eq!(word_wrap("hello world", 11), "hello world");
+    }
+
+    #[test]
+    fn test_collapses_whitespace() {
+        assert_eq!(word_wrap("  hello \t  world  ", 20), "hello world");
+    }
+
+    #[test]
+    fn test_breaks_after_hyphen() {
+        assert_eq!(word_wrap("a well-known fact", 8), "a well-\nknown\nfact");
+        assert_eq!(
+            word_wrap("state-of-the-art design", 10),
+            "state-of-\nthe-art\ndesign"
+        );
+    }
+
+    #[test]
+    fn test_does_not_break_at_leading_or_double_hyphens() {
+        assert_eq!(word_wrap("use --verbose", 9), "use\n--verbose");
+        assert_eq!(word_wrap("a--b", 2), "a-\n-b");
+    }
+
+    #[test]
+    fn test_soft_hyphens() {
+        assert_eq!(word_wrap("extra\u{AD}ordinary", 8), "extra-\nordinary");
+        assert_eq!(word_wrap("extra\u{AD}ordinary", 20), "extraordinary");
+        assert_eq!(
+            word_wrap("in\u{AD}com\u{AD}pre\u{AD}hen\u{AD}si\u{AD}ble", 8),
+            "incom-\nprehen-\nsible"
+        );
+    }
+
+    #[test]
+    fn test_soft_hyphen_that_does_not_fit() {
+        assert_eq!(word_wrap("abcd\u{AD}efgh", 4), "abcd\nefgh");
+    }
+
+    #[test]
+    fn test_splits_long_words() {
+        assert_eq!(word_wrap("abcdefghij", 4), "abcd\nefgh\nij");
+        assert_eq!(word_wrap("a abcdefghij b", 4), "a\nabcd\nefgh\nij b");
+    }
+
+    #[test]
+    fn test_keeps_existing_newlines() {
+        assert_eq!(
+            word_wrap("one two\n\nthree four five", 9),
+            "one two\n\nthree\nfour five"
+        );
+        assert_eq!(word_wrap("a\nb\n", 5), "a\nb\n");
+    }
+
+    #[test]
+    fn test_counts_chars_not_bytes() {
+        assert_eq!(word_wrap("こんにちは 世界", 5), "こんにちは\n世界");
+        assert_eq!(word_wrap("ééé ééé", 3), "ééé\nééé");
+    }
+
+    #[test]
+    fn test_zero_width() {
+        assert_eq!(word_wrap("ab c", 0), "a\nb\nc");
+    }
+
+    #[test]
+    fn test_empty_text() {
+        assert_eq!(word_wrap("", 10), "");
+    }
+}
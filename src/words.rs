@@ -1,7 +1,11 @@
+use crate::{words_with, WordOptions};
+
 /// Splits a string into words based on casing, digits, and separators.
 ///
-/// Processes the input string by inserting spaces between words based on uppercase letters,
-/// digits, and other non-alphanumeric characters, then splits on spaces to extract words.
+/// Words break at non-alphanumeric characters, at lower-to-upper case changes, before the last
+/// capital of an acronym followed by a lowercase letter, and between letters and digits.
+/// Apostrophes inside words are kept, as in `"don't"`. Use [`words_with`] to configure the
+/// splitting.
 ///
 /// # Arguments
 /// * `str_input` - The input string to split into words
@@ -23,58 +27,7 @@
 /// assert_eq!(result, vec!["foo", "Bar", "Baz", "Hello"]);
 /// ```
 pub fn words(str_input: &str) -> Vec<String> {
-    if str_input.is_empty() {
-        return Vec::new();
-    }
-
-    let mut words = Vec::new();
-    let mut current_word = String::new();
-    let mut prev_char = '\0';
-
-    let mut chars = str_input.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        let next_char = chars.peek().copied();
-
-        if c.is_uppercase() {
-            if prev_char.is_lowercase()
-                || prev_char.is_ascii_digit()
-                || prev_char == ' '
-                || prev_char == '-'
-                || prev_char == '_'
-            {
-                if !current_word.is_empty() {
-                    words.push(std::mem::take(&mut current_word));
-                }
-            } else if let Some(next) = next_char {
-                if next.is_lowercase() && !current_word.is_empty() {
-                    words.push(std::mem::take(&mut current_word));
-                }
-            }
-        } else if c.is_ascii_digit() && !prev_char.is_ascii_digit() {
-            if !current_word.is_empty() {
-                words.push(std::mem::take(&mut current_word));
-            }
-        } else if !c.is_alphanumeric() {
-            if !current_word.is_empty() {
-                words.push(std::mem::take(&mut current_word));
-            }
-            prev_char = c;
-            continue;
-        }
-
-        if c.is_alphanumeric() {
-            current_word.push(c);
-        }
-
-        prev_char = c;
-    }
-
-    if !current_word.is_empty() {
-        words.push(current_word);
-    }
-
-    words
+    words_with(str_input, &WordOptions::default())
 }
 
 #[cfg(test)]
@@ -0,0 +1,199 @@
+use crate::word_spans_with::is_apostrophe;
+use crate::{word_spans_with, Apostrophes, WordOptions};
+
+/// Splits a string into words using the given [`WordOptions`].
+///
+/// Words break at separators, at lower-to-upper case changes, before the last capital of an
+/// acronym that is followed by a lowercase letter, and between letters and digits unless
+/// `digits` is [`DigitGrouping::Attached`](crate::DigitGrouping::Attached). Letters of scripts
+/// without case, such as CJK, join the word around them. Known `acronyms` are always kept whole.
+///
+/// # Arguments
+/// * `str_input` - The input string to split into words
+/// * `options` - How acronyms, digits, apostrophes and separators are handled
+///
+/// # Returns
+/// * `Vec<String>` - A vector of words extracted from the input string
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{words_with, Apostrophes, DigitGrouping, WordOptions};
+///
+/// let options = WordOptions::default();
+/// assert_eq!(words_with("XMLHttpRequest", &options), vec!["XML", "Http", "Request"]);
+/// assert_eq!(words_with("don't stop", &options), vec!["don't", "stop"]);
+///
+/// let options = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     apostrophes: Apostrophes::Remove,
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(words_with("HTTP2Server", &options), vec!["HTTP2", "Server"]);
+/// assert_eq!(words_with("don't stop", &options), vec!["dont", "stop"]);
+///
+/// let options = WordOptions {
+///     separators: Some(vec!['/']),
+///     ..WordOptions::default()
+/// };
+/// assert_eq!(words_with("v1.2/beta-3", &options), vec!["v", "1.2", "beta-3"]);
+/// ```
+pub fn words_with(str_input: &str, options: &WordOptions) -> Vec<String> {
+    word_spans_with(str_input, options)
+        .map(|word| {
+            if options.apostrophes == Apostrophes::Remove {
+                word.chars().filter(|&c| !is_apostrophe(c)).collect()
+            } else {
+                word.to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DigitGrouping;
+
+    fn split(input: &str, options: &WordOptions) -> Vec<String> {
+        words_with(input, options)
+    }
+
+    #[test]
+    fn test_lodash_words_table() {
+        let cases: &[(&str, &[&str])] = &[
+            ("", &[]),
+            ("fred, barney, & pebbles", &["fred", "barney", "pebbles"]),
+            ("fooBar", &["foo", "Bar"]),
+            ("__FOO_BAR__", &["FOO", "BAR"]),
+            ("--foo-bar--", &["foo", "bar"]),
+            ("Foo Bar", &["Foo", "Bar"]),
+            ("XMLHttpRequest", &["XML", "Http", "Request"]),
+            ("aeiouAreVowels", &["aeiou", "Are", "Vowels"]),
+            (
+                "LETTERSAeiouAreVowels",
+                &["LETTERS", "Aeiou", "Are", "Vowels"],
+            ),
+            (
+                "LETTERSÆiouAreVowels",
+                &["LETTERS", "Æiou", "Are", "Vowels"],
+            ),
+            ("tooLegit2Quit", &["too", "Legit", "2", "Quit"]),
+            ("walk500Miles", &["walk", "500", "Miles"]),
+            ("xhr2Request", &["xhr", "2", "Request"]),
+            ("isISO8601", &["is", "ISO", "8601"]),
+            ("HTTP2Server", &["HTTP", "2", "Server"]),
+            ("enable 6h format", &["enable", "6", "h", "format"]),
+            ("enable 24H format", &["enable", "24", "H", "format"]),
+            ("don't", &["don't"]),
+            ("isn't it", &["isn't", "it"]),
+            ("it’s", &["it’s"]),
+            ("'quoted'", &["quoted"]),
+            ("Äpfel und Birnen", &["Äpfel", "und", "Birnen"]),
+            ("ÀÈÌÒÙ", &["ÀÈÌÒÙ"]),
+            ("hello世界", &["hello世界"]),
+            ("世界Hello", &["世界", "Hello"]),
+            ("hello 😀 world", &["hello", "world"]),
+        ];
+        let options = WordOptions::default();
+        for (input, expected) in cases {
+            assert_eq!(split(input, &options), *expected, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_digits_attached() {
+        let options = WordOptions {
+            digits: DigitGrouping::Attached,
+            ..WordOptions::default()
+        };
+        let cases: &[(&str, &[&str])] = &[
+            ("HTTP2Server", &["HTTP2", "Server"]),
+            ("Int8Value", &["Int8", "Value"]),
+            ("version2Release10", &["version2", "Release10"]),
+            ("abc123DEF", &["abc123", "DEF"]),
+            ("2fast", &["2fast"]),
+            ("isISO8601", &["is", "ISO8601"]),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(split(input, &options), *expected, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_apostrophes() {
+        let remove = WordOptions {
+            apostrophes: Apostrophes::Remove,
+            ..WordOptions::default()
+        };
+        assert_eq!(split("don't stop", &remove), vec!["dont", "stop"]);
+        assert_eq!(split("it’s", &remove), vec!["its"]);
+
+        let split_options = WordOptions {
+            apostrophes: Apostrophes::Split,
+            ..WordOptions::default()
+        };
+        assert_eq!(
+            split("don't stop", &split_options),
+            vec!["don", "t", "stop"]
+        );
+        assert_eq!(
+            split("rock'n'roll", &split_options),
+            vec!["rock", "n", "roll"]
+        );
+    }
+
+    #[test]
+    fn test_acronyms() {
+        let options = WordOptions {
+            acronyms: vec!["OAuth".to_string(), "iOS".to_string(), "HTTP2".to_string()],
+            ..WordOptions::default()
+        };
+        let cases: &[(&str, &[&str])] = &[
+            ("getOAuthToken", &["get", "OAuth", "Token"]),
+            ("OAuth", &["OAuth"]),
+            ("iOSApp", &["iOS", "App"]),
+            ("HTTP2Server", &["HTTP2", "Server"]),
+            ("OAuthority", &["O", "Authority"]),
+            ("use iOS", &["use", "iOS"]),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(split(input, &options), *expected, "input: {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_longest_acronym_wins() {
+        let options = WordOptions {
+            acronyms: vec!["HTTP".to_string(), "HTTP2".to_string()],
+            ..WordOptions::default()
+        };
+        assert_eq!(split("HTTP2Server", &options), vec!["HTTP2", "Server"]);
+    }
+
+    #[test]
+    fn test_custom_separators() {
+        let options = WordOptions {
+            separators: Some(vec![' ', '-', '_']),
+            ..WordOptions::default()
+        };
+        assert_eq!(split("hello!world", &options), vec!["hello!world"]);
+        assert_eq!(
+            split("foo-bar_baz qux", &options),
+            vec!["foo", "bar", "baz", "qux"]
+        );
+        assert_eq!(split("fooBar.baz", &options), vec!["foo", "Bar.baz"]);
+
+        let options = WordOptions {
+            separators: Some(vec![',']),
+            ..WordOptions::default()
+        };
+        assert_eq!(split("a b,c", &options), vec!["a b", "c"]);
+    }
+
+    #[test]
+    fn test_only_separators() {
+        let options = WordOptions::default();
+        assert!(split("   ", &options).is_empty());
+        assert!(split("-_-", &options).is_empty());
+    }
+}
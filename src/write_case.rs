@@ -0,0 +1,113 @@
+use std::fmt;
+
+use crate::case_writer::{default_case_options, write_words};
+use crate::common::TITLE_CASE_SMALL_WORDS;
+use crate::Case;
+
+/// Writes a string converted to the given case into a caller-provided buffer.
+///
+/// Produces the same text as [`convert_case`](crate::convert_case), but borrows the words from
+/// the input and writes them straight into `out`, so converting many strings into a reused
+/// `String` does not allocate once the buffer is large enough. The output is appended to what
+/// `out` already holds.
+///
+/// # Arguments
+/// * `out` - The buffer to write into, such as a `String` or a `fmt::Formatter`
+/// * `str_input` - The input string to convert
+/// * `case` - The target case
+///
+/// # Returns
+/// * `fmt::Result` - An error only if writing to `out` fails
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{write_case, Case};
+///
+/// let mut buffer = String::new();
+/// let mut converted = Vec::new();
+/// for identifier in ["parseHttpResponse", "user_id", "XMLHttpRequest"] {
+///     buffer.clear();
+///     write_case(&mut buffer, identifier, Case::Constant).unwrap();
+///     converted.push(buffer.clone());
+/// }
+/// assert_eq!(converted, vec!["PARSE_HTTP_RESPONSE", "USER_ID", "XML_HTTP_REQUEST"]);
+/// ```
+pub fn write_case<W: fmt::Write>(out: &mut W, str_input: &str, case: Case) -> fmt::Result {
+    write_words(
+        out,
+        str_input,
+        case,
+        &default_case_options(case),
+        TITLE_CASE_SMALL_WORDS,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert_case;
+
+    const ALL_CASES: [Case; 11] = [
+        Case::Camel,
+        Case::Pascal,
+        Case::Snake,
+        Case::Kebab,
+        Case::Constant,
+        Case::Title,
+        Case::Sentence,
+        Case::Dot,
+        Case::Path,
+        Case::Train,
+        Case::Start,
+    ];
+
+    #[test]
+    fn test_matches_convert_case() {
+        for input in [
+            "the quick_brownFox",
+            "XMLHttpRequest",
+            "don't stop",
+            "version2Release10",
+            "hello_世界",
+            "",
+        ] {
+            for case in ALL_CASES {
+                let mut out = String::new();
+                write_case(&mut out, input, case).unwrap();
+                assert_eq!(out, convert_case(input, case), "{:?} {:?}", input, case);
+            }
+        }
+    }
+
+    #[test]
+    fn test_appends_to_buffer() {
+        let mut out = String::from("const ");
+        write_case(&mut out, "maxRetries", Case::Constant).unwrap();
+        assert_eq!(out, "const MAX_RETRIES");
+    }
+
+    #[test]
+    fn test_reused_buffer_keeps_capacity() {
+        let mut out = String::with_capacity(64);
+        let capacity = out.capacity();
+        for input in ["fooBar", "bazQux", "a_much_longer_identifier_name"] {
+            out.clear();
+            write_case(&mut out, input, Case::Kebab).unwrap();
+        }
+        assert_eq!(out, "a-much-longer-identifier-name");
+        assert_eq!(out.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_writes_into_formatter() {
+        struct Snake<'a>(&'a str);
+
+        impl fmt::Display for Snake<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_case(f, self.0, Case::Snake)
+            }
+        }
+
+        assert_eq!(format!("{}", Snake("helloWorld")), "hello_world");
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::case_writer::write_words;
+use crate::common::TITLE_CASE_SMALL_WORDS;
+use crate::{Case, WordOptions};
+
+/// Writes a string converted to the given case into a caller-provided buffer, splitting it into
+/// words with the given [`WordOptions`].
+///
+/// Produces the same text as [`convert_case_with`](crate::convert_case_with) without allocating
+/// the words. The output is appended to what `out` already holds.
+///
+/// # Arguments
+/// * `out` - The buffer to write into, such as a `String` or a `fmt::Formatter`
+/// * `str_input` - The input string to convert
+/// * `case` - The target case
+/// * `options` - How the input is split into words
+///
+/// # Returns
+/// * `fmt::Result` - An error only if writing to `out` fails
+///
+/// # Examples
+/// ```rust
+/// use lowdash::{write_case_with, Case, DigitGrouping, WordOptions};
+///
+/// let options = WordOptions {
+///     digits: DigitGrouping::Attached,
+///     ..WordOptions::default()
+/// };
+/// let mut buffer = String::new();
+/// write_case_with(&mut buffer, "HTTP2Server", Case::Kebab, &options).unwrap();
+/// assert_eq!(buffer, "http2-server");
+/// ```
+pub fn write_case_with<W: fmt::Write>(
+    out: &mut W,
+    str_input: &str,
+    case: Case,
+    options: &WordOptions,
+) -> fmt::Result {
+    write_words(out, str_input, case, options, TITLE_CASE_SMALL_WORDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{convert_case_with, Apostrophes, DigitGrouping};
+
+    #[test]
+    fn test_matches_convert_case_with() {
+        let options = WordOptions {
+            acronyms: vec!["OAuth".to_string()],
+            digits: DigitGrouping::Attached,
+            apostrophes: Apostrophes::Remove,
+            separators: None,
+        };
+        for case in [Case::Camel, Case::Constant, Case::Title, Case::Start] {
+            let mut out = String::new();
+            write_case_with(&mut out, "don't getOAuth2Token", case, &options).unwrap();
+            assert_eq!(
+                out,
+                convert_case_with("don't getOAuth2Token", case, &options)
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_error_is_propagated() {
+        struct Failing;
+
+        impl fmt::Write for Failing {
+            fn write_str(&mut self, _: &str) -> fmt::Result {
+                Err(fmt::Error)
+            }
+        }
+
+        let options = WordOptions::default();
+        assert!(write_case_with(&mut Failing, "foo bar", Case::Snake, &options).is_err());
+        assert!(write_case_with(&mut Failing, "", Case::Snake, &options).is_ok());
+    }
+}